@@ -0,0 +1,61 @@
+//! Compares copying strided slices of primitives with the scalar loop and with
+//! the architecture specific kernels.
+//!
+//! Run using `cargo bench --features std`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use stride::Stride;
+
+const LEN: usize = 1 << 16;
+const ITERS: u32 = 1000;
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut(),
+{
+    // Warm up the caches before timing.
+    for _ in 0..ITERS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed() / ITERS;
+    println!("{:<40} {:>10.2?}/iter", name, elapsed);
+}
+
+macro_rules! bench_copy {
+    ($ty:ty, $s:literal) => {{
+        let mut data = vec![1 as $ty; LEN * $s];
+        let mut buf = vec![0 as $ty; LEN];
+        let name = concat!("Stride<", stringify!($ty), ", ", $s, ">");
+        let stride = Stride::<_, $s>::new_mut(&mut data);
+        bench(&format!("{}::copy_to_slice()", name), || {
+            black_box(&*stride).copy_to_slice(black_box(&mut buf))
+        });
+        bench(&format!("{}::gather_to_slice()", name), || {
+            black_box(&*stride).gather_to_slice(black_box(&mut buf))
+        });
+        bench(&format!("{}::copy_from_slice()", name), || {
+            black_box(&mut *stride).copy_from_slice(black_box(&buf))
+        });
+        bench(&format!("{}::scatter_from_slice()", name), || {
+            black_box(&mut *stride).scatter_from_slice(black_box(&buf))
+        });
+    }};
+}
+
+fn main() {
+    bench_copy!(u8, 2);
+    bench_copy!(u8, 3);
+    bench_copy!(u8, 4);
+    bench_copy!(f32, 2);
+    bench_copy!(f32, 3);
+    bench_copy!(f32, 4);
+    bench_copy!(f64, 2);
+    bench_copy!(f64, 3);
+    bench_copy!(f64, 4);
+}
@@ -0,0 +1,96 @@
+//! Compares iterating over a strided slice with indexing each element and with
+//! the equivalent `step_by()` on the underlying slice.
+//!
+//! Run using `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use stride::Stride;
+
+const LEN: usize = 1 << 16;
+const ITERS: u32 = 1000;
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut() -> u64,
+{
+    // Warm up the caches before timing.
+    for _ in 0..ITERS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        black_box(f());
+    }
+    let elapsed: Duration = start.elapsed() / ITERS;
+    println!("{:<32} {:>10.2?}/iter", name, elapsed);
+}
+
+/// Sums a strided slice by indexing each element, which bounds checks every
+/// access.
+fn sum_indexed<const S: usize>(stride: &Stride<u64, S>) -> u64 {
+    let mut sum = 0;
+    for i in 0..stride.len() {
+        sum += stride[i];
+    }
+    sum
+}
+
+/// Increments each element of a strided slice by indexing it.
+fn mutate_indexed<const S: usize>(stride: &mut Stride<u64, S>) {
+    for i in 0..stride.len() {
+        stride[i] = stride[i].wrapping_add(1);
+    }
+}
+
+fn bench_stride<const S: usize>(data: &mut [u64]) {
+    let stride = Stride::<_, S>::new(&*data);
+    bench(&format!("Stride<_, {}> indexed sum", S), || {
+        sum_indexed(black_box(stride))
+    });
+    bench(&format!("step_by({}).sum()", S), || {
+        black_box(&*data).iter().step_by(S).sum()
+    });
+    bench(&format!("Stride<_, {}>::iter().sum()", S), || {
+        black_box(stride).iter().sum()
+    });
+    bench(&format!("step_by({}) for loop", S), || {
+        let mut sum = 0;
+        for x in black_box(&*data).iter().step_by(S) {
+            sum += x;
+        }
+        sum
+    });
+    bench(&format!("Stride<_, {}> for loop", S), || {
+        let mut sum = 0;
+        for x in black_box(stride) {
+            sum += x;
+        }
+        sum
+    });
+    bench(&format!("Stride<_, {}> indexed mutate", S), || {
+        mutate_indexed(Stride::<_, S>::new_mut(black_box(&mut *data)));
+        0
+    });
+    bench(&format!("step_by({}) mutate", S), || {
+        for x in black_box(&mut *data).iter_mut().step_by(S) {
+            *x = x.wrapping_add(1);
+        }
+        0
+    });
+    bench(&format!("Stride<_, {}>::iter_mut() mutate", S), || {
+        for x in Stride::<_, S>::new_mut(black_box(&mut *data)) {
+            *x = x.wrapping_add(1);
+        }
+        0
+    });
+}
+
+fn main() {
+    let mut data: Vec<u64> = (0..LEN as u64).collect();
+    bench_stride::<1>(&mut data);
+    bench_stride::<2>(&mut data);
+    bench_stride::<3>(&mut data);
+    bench_stride::<4>(&mut data);
+}
@@ -0,0 +1,57 @@
+//! Compares the numeric reductions with folding the iterator.
+//!
+//! Run using `cargo bench --features math`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use stride::Stride;
+
+const LEN: usize = 1 << 12;
+const ITERS: u32 = 10000;
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut(),
+{
+    // Warm up the caches before timing.
+    for _ in 0..ITERS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed() / ITERS;
+    println!("{:<40} {:>10.2?}/iter", name, elapsed);
+}
+
+macro_rules! bench_reduce {
+    ($ty:ty, $s:literal) => {{
+        let data: Vec<$ty> = (0..LEN * $s).map(|x| (x % 100) as $ty).collect();
+        let name = concat!("Stride<", stringify!($ty), ", ", $s, ">");
+        let stride = Stride::<_, $s>::new(&data);
+        bench(&format!("{}::iter().sum()", name), || {
+            black_box(black_box(stride).iter().fold(0 as $ty, |a, &b| a + b));
+        });
+        bench(&format!("{}::sum()", name), || {
+            black_box(black_box(stride).sum());
+        });
+        bench(&format!("{}::iter().max()", name), || {
+            black_box(black_box(stride).iter().copied().reduce(<$ty>::max));
+        });
+        bench(&format!("{}::max_elem()", name), || {
+            black_box(black_box(stride).max_elem());
+        });
+    }};
+}
+
+fn main() {
+    bench_reduce!(i32, 1);
+    bench_reduce!(i32, 2);
+    bench_reduce!(i32, 4);
+    bench_reduce!(f32, 1);
+    bench_reduce!(f32, 2);
+    bench_reduce!(f32, 4);
+    bench_reduce!(f64, 2);
+}
@@ -0,0 +1,63 @@
+//! Compares the lexicographic comparison of strided slices with comparing by
+//! indexing each element.
+//!
+//! Run using `cargo bench`.
+
+use std::cmp::Ordering;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use stride::Stride;
+
+const LEN: usize = 1 << 16;
+const ITERS: u32 = 1000;
+
+fn bench<F>(name: &str, mut f: F)
+where
+    F: FnMut() -> Ordering,
+{
+    // Warm up the caches before timing.
+    for _ in 0..ITERS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        black_box(f());
+    }
+    let elapsed: Duration = start.elapsed() / ITERS;
+    println!("{:<32} {:>10.2?}/iter", name, elapsed);
+}
+
+/// Compares two strided slices by indexing each element.
+fn cmp_indexed<T: Ord, const S: usize>(a: &Stride<T, S>, b: &Stride<T, S>) -> Ordering {
+    let len = std::cmp::min(a.len(), b.len());
+    for i in 0..len {
+        match a[i].cmp(&b[i]) {
+            Ordering::Equal => continue,
+            non_eq => return non_eq,
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+fn bench_cmp<const S: usize>(a: &[u32], b: &[u32]) {
+    let a = Stride::<_, S>::new(a);
+    let b = Stride::<_, S>::new(b);
+    bench(&format!("Stride<_, {}> indexed cmp", S), || {
+        cmp_indexed(black_box(a), black_box(b))
+    });
+    bench(&format!("Stride<_, {}>::cmp()", S), || {
+        black_box(a).cmp(black_box(b))
+    });
+}
+
+fn main() {
+    // Only the last element differs so the whole key is compared.
+    let a: Vec<u32> = (0..LEN as u32).collect();
+    let mut b = a.clone();
+    *b.last_mut().unwrap() += 1;
+    bench_cmp::<1>(&a, &b);
+    bench_cmp::<2>(&a, &b);
+    bench_cmp::<3>(&a, &b);
+    bench_cmp::<4>(&a, &b);
+}
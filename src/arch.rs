@@ -0,0 +1,403 @@
+//! Architecture specific kernels for copying strided slices of primitives.
+//!
+//! The scalar loop in [`copy_to_slice()`][`Stride::copy_to_slice()`] moves
+//! one element at a time, which leaves a lot of memory bandwidth unused for
+//! small strides. The kernels here move a whole vector register at a time:
+//!
+//! - on `x86` and `x86_64`, AVX2 gathers for 32 and 64-bit elements and SSSE3
+//!   shuffles for bytes, and SSE4.1 shuffles and blends to scatter,
+//! - on `aarch64`, the NEON interleaved loads and stores `ld2`/`ld3`/`ld4` and
+//!   `st2`/`st3`/`st4`.
+//!
+//! The kernel is selected with runtime feature detection, so this is only
+//! available with the `std` feature. Strides other than 2, 3 and 4, and any
+//! elements at the end that don't fill a register, use the scalar loop.
+//!
+//! Bytes benefit the most, since the compiler can't vectorize the scalar loop
+//! for them. For 32 and 64-bit elements the gain depends on the CPU, and with
+//! slow gathers the kernels are on par with the scalar loop; `benches/arch.rs`
+//! compares both.
+
+use core::slice;
+
+use crate::{len_mismatch_fail, Stride};
+
+/// Reinterprets a slice of primitives as a slice of unsigned integers of the
+/// same size.
+///
+/// # Safety
+///
+/// `T` and `U` must have the same size and alignment, and every bit pattern
+/// must be valid for both.
+unsafe fn cast<T, U>(data: &[T]) -> &[U] {
+    unsafe { slice::from_raw_parts(data.as_ptr().cast(), data.len()) }
+}
+
+/// Reinterprets a mutable slice of primitives as a mutable slice of unsigned
+/// integers of the same size.
+///
+/// # Safety
+///
+/// `T` and `U` must have the same size and alignment, and every bit pattern
+/// must be valid for both.
+unsafe fn cast_mut<T, U>(data: &mut [T]) -> &mut [U] {
+    unsafe { slice::from_raw_parts_mut(data.as_mut_ptr().cast(), data.len()) }
+}
+
+macro_rules! impl_dispatch {
+    ($gather:ident, $scatter:ident, $int:ty, $x86_gather:tt, $x86_scatter:tt) => {
+        /// Gathers the first elements of the strided slice over `src` into
+        /// `dst`, returning the number of elements that were copied.
+        fn $gather<const S: usize>(src: &[$int], dst: &mut [$int]) -> usize {
+            if !(2..=4).contains(&S) {
+                return 0;
+            }
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if std::is_x86_feature_detected!($x86_gather) {
+                return unsafe { x86::$gather::<S>(src, dst) };
+            }
+            #[cfg(target_arch = "aarch64")]
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return unsafe { neon::$gather::<S>(src, dst) };
+            }
+            let _ = (src, dst);
+            0
+        }
+
+        /// Scatters the first elements of `src` into the strided slice over
+        /// `dst`, returning the number of elements that were copied.
+        fn $scatter<const S: usize>(src: &[$int], dst: &mut [$int]) -> usize {
+            if !(2..=4).contains(&S) {
+                return 0;
+            }
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if std::is_x86_feature_detected!($x86_scatter) {
+                return unsafe { x86::$scatter::<S>(src, dst) };
+            }
+            #[cfg(target_arch = "aarch64")]
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return unsafe { neon::$scatter::<S>(src, dst) };
+            }
+            let _ = (src, dst);
+            0
+        }
+    };
+}
+
+impl_dispatch! { gather8, scatter8, u8, "ssse3", "sse4.1" }
+impl_dispatch! { gather32, scatter32, u32, "avx2", "sse4.1" }
+impl_dispatch! { gather64, scatter64, u64, "avx2", "sse4.1" }
+
+macro_rules! impl_gather_scatter {
+    ($ty:ty, $int:ty, $gather:ident, $scatter:ident) => {
+        impl<const S: usize> Stride<$ty, S> {
+            #[doc = concat!("Copies all elements from the strided slice into `dst`, using vector instructions for `", stringify!($ty), "`s.")]
+            ///
+            /// This gives the same result as
+            /// [`copy_to_slice()`][`Stride::copy_to_slice()`], but uses a
+            /// vector kernel for strides of 2, 3 and 4 on CPUs that support
+            /// one, which is detected at runtime.
+            ///
+            /// Only available with the `std` feature.
+            ///
+            /// # Panics
+            ///
+            /// If the two lengths are not equal.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: Vec<", stringify!($ty), "> = (0..100).map(|x| x as ", stringify!($ty), ").collect();")]
+            /// let stride = Stride::<_, 3>::new(&data[1..]);
+            #[doc = concat!("let mut dst = vec![0 as ", stringify!($ty), "; stride.len()];")]
+            /// stride.gather_to_slice(&mut dst);
+            /// assert!(dst.iter().eq(stride));
+            /// ```
+            #[track_caller]
+            pub fn gather_to_slice(&self, dst: &mut [$ty]) {
+                if self.len() != dst.len() {
+                    len_mismatch_fail(dst.len(), self.len());
+                }
+                // SAFETY: the primitive has the same layout as the integer and
+                // any bits are valid for both.
+                let n = $gather::<S>(unsafe { cast::<$ty, $int>(&self.data) }, unsafe { cast_mut(dst) });
+                self[n..].copy_to_slice(&mut dst[n..]);
+            }
+
+            #[doc = concat!("Copies all elements from `src` into the strided slice, using vector instructions for `", stringify!($ty), "`s.")]
+            ///
+            /// This gives the same result as
+            /// [`copy_from_slice()`][`Stride::copy_from_slice()`], but uses a
+            /// vector kernel for strides of 2, 3 and 4 on CPUs that support
+            /// one, which is detected at runtime. Elements in the
+            /// underlying slice that are not part of the strided slice keep
+            /// their values, although they might be written.
+            ///
+            /// Only available with the `std` feature.
+            ///
+            /// # Panics
+            ///
+            /// If the two lengths are not equal.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data = &mut [1 as ", stringify!($ty), "; 64];")]
+            /// let stride = Stride::<_, 2>::new_mut(data);
+            #[doc = concat!("stride.scatter_from_slice(&[0 as ", stringify!($ty), "; 32]);")]
+            #[doc = concat!("assert!(data.chunks(2).all(|pair| pair == [0 as ", stringify!($ty), ", 1 as ", stringify!($ty), "]));")]
+            /// ```
+            #[track_caller]
+            pub fn scatter_from_slice(&mut self, src: &[$ty]) {
+                if self.len() != src.len() {
+                    len_mismatch_fail(self.len(), src.len());
+                }
+                // SAFETY: the primitive has the same layout as the integer and
+                // any bits are valid for both.
+                let n = $scatter::<S>(unsafe { cast::<$ty, $int>(src) }, unsafe { cast_mut(&mut self.data) });
+                self[n..].copy_from_slice(&src[n..]);
+            }
+        }
+    };
+}
+
+impl_gather_scatter! { u8, u8, gather8, scatter8 }
+impl_gather_scatter! { f32, u32, gather32, scatter32 }
+impl_gather_scatter! { f64, u64, gather64, scatter64 }
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use core::{array, mem, slice};
+
+    /// Returns the shuffle masks that gather `16 / W` elements of `W` bytes
+    /// from `S` 16-byte vectors into one vector.
+    ///
+    /// Output byte `j` is byte `j % W` of element `j / W`, which is in vector
+    /// `k` at the position given by mask `k`. Every other mask has the high
+    /// bit set, which zeroes the byte.
+    fn gather_masks<const W: usize, const S: usize>() -> [[u8; 16]; S] {
+        array::from_fn(|k| {
+            array::from_fn(|j| {
+                let src = (j / W) * S * W + j % W;
+                if src / 16 == k {
+                    (src % 16) as u8
+                } else {
+                    0x80
+                }
+            })
+        })
+    }
+
+    /// Returns the shuffle and blend masks that scatter the `16 / W` elements
+    /// of `W` bytes in one vector into `S` 16-byte vectors.
+    ///
+    /// Byte `p` of vector `k` is byte `(16 * k + p) % (S * W)` of a stride, so
+    /// it only belongs to an element if that is less than `W`.
+    fn scatter_masks<const W: usize, const S: usize>() -> [([u8; 16], [u8; 16]); S] {
+        array::from_fn(|k| {
+            let mut shuffle = [0x80; 16];
+            let mut blend = [0; 16];
+            for p in 0..16 {
+                let dst = 16 * k + p;
+                if dst % (S * W) < W {
+                    shuffle[p] = ((dst / (S * W)) * W + dst % (S * W)) as u8;
+                    blend[p] = 0xff;
+                }
+            }
+            (shuffle, blend)
+        })
+    }
+
+    unsafe fn load(bytes: &[u8; 16]) -> __m128i {
+        unsafe { _mm_loadu_si128(bytes.as_ptr().cast()) }
+    }
+
+    /// Gathers elements of `W` bytes with SSSE3 shuffles, 16 bytes at a time.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn gather_shuffle<const W: usize, const S: usize>(src: &[u8], dst: &mut [u8]) -> usize {
+        let masks = gather_masks::<W, S>().map(|mask| unsafe { load(&mask) });
+        // Each chunk reads whole strides, which might not all be in `src`.
+        let chunks = (dst.len() / 16).min(src.len() / (16 * S));
+        for i in 0..chunks {
+            let src = unsafe { src.as_ptr().add(16 * S * i) };
+            let mut out = _mm_setzero_si128();
+            for (k, mask) in masks.iter().enumerate() {
+                let v = unsafe { _mm_loadu_si128(src.add(16 * k).cast()) };
+                out = _mm_or_si128(out, _mm_shuffle_epi8(v, *mask));
+            }
+            unsafe { _mm_storeu_si128(dst.as_mut_ptr().add(16 * i).cast(), out) };
+        }
+        16 * chunks
+    }
+
+    /// Scatters elements of `W` bytes with SSE4.1 shuffles and blends, 16
+    /// bytes at a time.
+    ///
+    /// The bytes in between are read and written back unchanged.
+    #[target_feature(enable = "ssse3,sse4.1")]
+    unsafe fn scatter_shuffle<const W: usize, const S: usize>(src: &[u8], dst: &mut [u8]) -> usize {
+        let masks = scatter_masks::<W, S>()
+            .map(|(shuffle, blend)| unsafe { (load(&shuffle), load(&blend)) });
+        // Each chunk writes whole strides, which might not all be in `dst`.
+        let chunks = (src.len() / 16).min(dst.len() / (16 * S));
+        for i in 0..chunks {
+            let v = unsafe { _mm_loadu_si128(src.as_ptr().add(16 * i).cast()) };
+            let dst = unsafe { dst.as_mut_ptr().add(16 * S * i) };
+            for (k, (shuffle, blend)) in masks.iter().enumerate() {
+                let dst = unsafe { dst.add(16 * k) };
+                let old = unsafe { _mm_loadu_si128(dst.cast()) };
+                let new = _mm_blendv_epi8(old, _mm_shuffle_epi8(v, *shuffle), *blend);
+                unsafe { _mm_storeu_si128(dst.cast(), new) };
+            }
+        }
+        16 * chunks
+    }
+
+    fn bytes<T: Copy>(data: &[T]) -> &[u8] {
+        // SAFETY: the integers have no padding, so every byte is initialized.
+        unsafe { slice::from_raw_parts(data.as_ptr().cast(), mem::size_of_val(data)) }
+    }
+
+    fn bytes_mut<T: Copy>(data: &mut [T]) -> &mut [u8] {
+        // SAFETY: the integers have no padding and any bits are valid for
+        // them.
+        unsafe { slice::from_raw_parts_mut(data.as_mut_ptr().cast(), mem::size_of_val(data)) }
+    }
+
+    /// Gathers bytes with SSSE3 shuffles.
+    pub(super) unsafe fn gather8<const S: usize>(src: &[u8], dst: &mut [u8]) -> usize {
+        unsafe { gather_shuffle::<1, S>(src, dst) }
+    }
+
+    /// Scatters bytes with SSE4.1 shuffles and blends.
+    pub(super) unsafe fn scatter8<const S: usize>(src: &[u8], dst: &mut [u8]) -> usize {
+        unsafe { scatter_shuffle::<1, S>(src, dst) }
+    }
+
+    /// Gathers 32-bit elements with AVX2, 8 at a time.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather32<const S: usize>(src: &[u32], dst: &mut [u32]) -> usize {
+        let s = S as i32;
+        let offsets = _mm256_setr_epi32(0, s, 2 * s, 3 * s, 4 * s, 5 * s, 6 * s, 7 * s);
+        // The lengths are equal, so each chunk only reads elements in `src`.
+        let chunks = dst.len() / 8;
+        for i in 0..chunks {
+            let src = unsafe { src.as_ptr().add(8 * S * i) };
+            let v = unsafe { _mm256_i32gather_epi32::<4>(src.cast(), offsets) };
+            unsafe { _mm256_storeu_si256(dst.as_mut_ptr().add(8 * i).cast(), v) };
+        }
+        8 * chunks
+    }
+
+    /// Scatters 32-bit elements with SSE4.1 shuffles and blends.
+    pub(super) unsafe fn scatter32<const S: usize>(src: &[u32], dst: &mut [u32]) -> usize {
+        unsafe { scatter_shuffle::<4, S>(bytes(src), bytes_mut(dst)) / 4 }
+    }
+
+    /// Gathers 64-bit elements with AVX2, 4 at a time.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather64<const S: usize>(src: &[u64], dst: &mut [u64]) -> usize {
+        let s = S as i32;
+        let offsets = _mm_setr_epi32(0, s, 2 * s, 3 * s);
+        // The lengths are equal, so each chunk only reads elements in `src`.
+        let chunks = dst.len() / 4;
+        for i in 0..chunks {
+            let src = unsafe { src.as_ptr().add(4 * S * i) };
+            let v = unsafe { _mm256_i32gather_epi64::<8>(src.cast(), offsets) };
+            unsafe { _mm256_storeu_si256(dst.as_mut_ptr().add(4 * i).cast(), v) };
+        }
+        4 * chunks
+    }
+
+    /// Scatters 64-bit elements with SSE4.1 shuffles and blends.
+    pub(super) unsafe fn scatter64<const S: usize>(src: &[u64], dst: &mut [u64]) -> usize {
+        unsafe { scatter_shuffle::<8, S>(bytes(src), bytes_mut(dst)) / 8 }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::*;
+
+    macro_rules! impl_neon {
+        (
+            $gather:ident, $scatter:ident, $int:ty, $lanes:literal,
+            $ld1:ident, $st1:ident, $ld2:ident, $st2:ident, $ld3:ident, $st3:ident, $ld4:ident, $st4:ident
+        ) => {
+            /// Gathers elements with NEON interleaved loads, a register at a
+            /// time.
+            #[target_feature(enable = "neon")]
+            pub(super) unsafe fn $gather<const S: usize>(src: &[$int], dst: &mut [$int]) -> usize {
+                // Each chunk reads whole strides, which might not all be in
+                // `src`.
+                let chunks = (dst.len() / $lanes).min(src.len() / ($lanes * S));
+                for i in 0..chunks {
+                    let src = unsafe { src.as_ptr().add($lanes * S * i) };
+                    let v = match S {
+                        2 => unsafe { $ld2(src) }.0,
+                        3 => unsafe { $ld3(src) }.0,
+                        4 => unsafe { $ld4(src) }.0,
+                        _ => unreachable!(),
+                    };
+                    unsafe { $st1(dst.as_mut_ptr().add($lanes * i), v) };
+                }
+                $lanes * chunks
+            }
+
+            /// Scatters elements with NEON interleaved loads and stores, a
+            /// register at a time.
+            ///
+            /// The elements in between are read and written back unchanged.
+            #[target_feature(enable = "neon")]
+            pub(super) unsafe fn $scatter<const S: usize>(src: &[$int], dst: &mut [$int]) -> usize {
+                // Each chunk writes whole strides, which might not all be in
+                // `dst`.
+                let chunks = (src.len() / $lanes).min(dst.len() / ($lanes * S));
+                for i in 0..chunks {
+                    let v = unsafe { $ld1(src.as_ptr().add($lanes * i)) };
+                    let dst = unsafe { dst.as_mut_ptr().add($lanes * S * i) };
+                    match S {
+                        2 => unsafe {
+                            let mut x = $ld2(dst);
+                            x.0 = v;
+                            $st2(dst, x)
+                        },
+                        3 => unsafe {
+                            let mut x = $ld3(dst);
+                            x.0 = v;
+                            $st3(dst, x)
+                        },
+                        4 => unsafe {
+                            let mut x = $ld4(dst);
+                            x.0 = v;
+                            $st4(dst, x)
+                        },
+                        _ => unreachable!(),
+                    }
+                }
+                $lanes * chunks
+            }
+        };
+    }
+
+    impl_neon! {
+        gather8, scatter8, u8, 16,
+        vld1q_u8, vst1q_u8, vld2q_u8, vst2q_u8, vld3q_u8, vst3q_u8, vld4q_u8, vst4q_u8
+    }
+    impl_neon! {
+        gather32, scatter32, u32, 4,
+        vld1q_u32, vst1q_u32, vld2q_u32, vst2q_u32, vld3q_u32, vst3q_u32, vld4q_u32, vst4q_u32
+    }
+    impl_neon! {
+        gather64, scatter64, u64, 2,
+        vld1q_u64, vst1q_u64, vld2q_u64, vst2q_u64, vld3q_u64, vst3q_u64, vld4q_u64, vst4q_u64
+    }
+}
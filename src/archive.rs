@@ -0,0 +1,113 @@
+//! Zero-copy archiving of owned strided slices with `rkyv`.
+//!
+//! Only available with the `rkyv` feature.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::{Stride, StrideBuf};
+
+/// An archived [`StrideBuf<T, S>`][`StrideBuf`].
+///
+/// This stores the whole underlying slice, so it can be accessed as a
+/// [`Stride<T, S>`][`Stride`] directly in the archived bytes.
+///
+/// Only available with the `rkyv` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use rkyv::rancor::Error;
+/// # use stride::{ArchivedStrideBuf, StrideBuf};
+/// #
+/// let buf = StrideBuf::<u8, 2>::from_vec(vec![1, 2, 3, 4, 5]);
+/// let bytes = rkyv::to_bytes::<Error>(&buf).unwrap();
+/// let archived = rkyv::access::<ArchivedStrideBuf<u8, 2>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.as_stride(), &[1, 3, 5]);
+/// ```
+#[repr(transparent)]
+pub struct ArchivedStrideBuf<T, const S: usize> {
+    data: ArchivedVec<T>,
+}
+
+impl<T, const S: usize> ArchivedStrideBuf<T, S> {
+    /// Returns the archived strided slice.
+    pub fn as_stride(&self) -> &Stride<T, S> {
+        Stride::new(self.data.as_slice())
+    }
+}
+
+// SAFETY: the archived type is a transparent wrapper around an `ArchivedVec`.
+unsafe impl<T, const S: usize> Portable for ArchivedStrideBuf<T, S> where ArchivedVec<T>: Portable {}
+
+// SAFETY: the archived type is a transparent wrapper around an `ArchivedVec`,
+// so it is valid exactly when the vec is.
+unsafe impl<T, C, const S: usize> CheckBytes<C> for ArchivedStrideBuf<T, S>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C: Fallible + ?Sized,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        unsafe { ArchivedVec::<T>::check_bytes(value.cast(), context) }
+    }
+}
+
+impl<T, const S: usize> Archive for StrideBuf<T, S>
+where
+    T: Archive,
+{
+    type Archived = ArchivedStrideBuf<T::Archived, S>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: the archived type is a transparent wrapper around an
+        // `ArchivedVec`.
+        let out = unsafe { out.cast_unchecked() };
+        ArchivedVec::resolve_from_slice(self.underlying(), resolver, out);
+    }
+}
+
+impl<T, Z, const S: usize> Serialize<Z> for StrideBuf<T, S>
+where
+    T: Serialize<Z>,
+    Z: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut Z) -> Result<Self::Resolver, Z::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.underlying(), serializer)
+    }
+}
+
+impl<T, D, const S: usize> Deserialize<StrideBuf<T, S>, D> for ArchivedStrideBuf<T::Archived, S>
+where
+    T: Archive,
+    ArchivedVec<T::Archived>: Deserialize<Vec<T>, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<StrideBuf<T, S>, D::Error> {
+        self.data.deserialize(deserializer).map(StrideBuf::from_vec)
+    }
+}
+
+impl<T, const S: usize> Deref for ArchivedStrideBuf<T, S> {
+    type Target = Stride<T, S>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_stride()
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for ArchivedStrideBuf<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_stride(), f)
+    }
+}
@@ -0,0 +1,79 @@
+//! Views of strided slices of integers as atomics.
+//!
+//! A `&mut` reference guarantees exclusive access, so for its lifetime the
+//! elements can be shared between threads as the atomic type with the same
+//! size. This is the strided version of the unstable `from_mut_slice()`
+//! methods on the atomic types.
+
+use core::mem;
+use core::sync::atomic;
+
+use crate::Stride;
+
+macro_rules! impl_as_atomic {
+    ($width:literal, $int:ty, $atomic:ident) => {
+        #[cfg(target_has_atomic = $width)]
+        impl<const S: usize> Stride<$int, S> {
+            #[doc = concat!("Returns a view of the strided slice as [`", stringify!($atomic), "`][`atomic::", stringify!($atomic), "`]s.")]
+            ///
+            /// The whole underlying slice is converted, so the returned strided
+            /// slice can be shared between threads, and each thread can update
+            /// the elements or create strided slices of different channels from
+            /// its [`underlying()`][`Stride::underlying()`] slice.
+            ///
+            /// # Panics
+            ///
+            /// If the underlying slice is not empty and not aligned for the
+            /// atomic type, which can only happen on targets where the atomic
+            /// type has a greater alignment than the integer type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use core::sync::atomic::Ordering;
+            /// # use std::thread;
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &mut [", stringify!($int), "] = &mut [1, 2, 3, 4, 5, 6];")]
+            /// let atomics = Stride::<_, 2>::new_mut(data).as_atomic();
+            /// thread::scope(|s| {
+            ///     s.spawn(|| atomics[0].fetch_add(10, Ordering::Relaxed));
+            ///     s.spawn(|| atomics[2].fetch_add(10, Ordering::Relaxed));
+            /// });
+            /// assert_eq!(data, &[11, 2, 3, 4, 15, 6]);
+            /// ```
+            #[track_caller]
+            pub fn as_atomic(&mut self) -> &Stride<atomic::$atomic, S> {
+                if self.data.is_empty() {
+                    // The dangling pointer of an empty slice is only aligned
+                    // for the integer type.
+                    return Stride::new(&[]);
+                }
+                let ptr = self.data.as_mut_ptr();
+                if mem::align_of::<$int>() < mem::align_of::<atomic::$atomic>() {
+                    assert!(
+                        ptr.align_offset(mem::align_of::<atomic::$atomic>()) == 0,
+                        "strided slice is not aligned for atomic access"
+                    );
+                }
+                let len = self.data.len();
+                // SAFETY: the atomic type has the same size and bit validity
+                // as the integer type, the pointer is aligned as checked above,
+                // and the `&mut` reference guarantees that the elements are
+                // only accessed through the returned view for its lifetime.
+                unsafe { Stride::from_raw_parts(ptr.cast(), len) }
+            }
+        }
+    };
+}
+
+impl_as_atomic! { "8", u8, AtomicU8 }
+impl_as_atomic! { "8", i8, AtomicI8 }
+impl_as_atomic! { "16", u16, AtomicU16 }
+impl_as_atomic! { "16", i16, AtomicI16 }
+impl_as_atomic! { "32", u32, AtomicU32 }
+impl_as_atomic! { "32", i32, AtomicI32 }
+impl_as_atomic! { "64", u64, AtomicU64 }
+impl_as_atomic! { "64", i64, AtomicI64 }
+impl_as_atomic! { "ptr", usize, AtomicUsize }
+impl_as_atomic! { "ptr", isize, AtomicIsize }
@@ -0,0 +1,849 @@
+//! Owned strided slices.
+//!
+//! Only available with the `alloc` feature.
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::ops::eq_by;
+use crate::{Iter, IterMut, Stride};
+
+/// An owned strided slice.
+///
+/// This is to [`Stride<T, S>`][`Stride`] what [`Vec<T>`] is to `[T]`. It owns
+/// the underlying data, including the elements in between those in the
+/// strided slice, and dereferences to a `Stride<T, S>` so that all of its
+/// methods can be used.
+///
+/// Only available with the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use stride::{Stride, StrideBuf};
+/// #
+/// fn doubled(stride: &Stride<i32, 2>) -> Cow<'_, Stride<i32, 2>> {
+///     if stride.iter().all(|&x| x == 0) {
+///         Cow::Borrowed(stride)
+///     } else {
+///         let mut buf = stride.to_owned();
+///         buf.iter_mut().for_each(|x| *x *= 2);
+///         Cow::Owned(buf)
+///     }
+/// }
+///
+/// let data = &[1, 2, 3, 4, 5, 6];
+/// let stride = Stride::<_, 2>::new(data);
+/// assert_eq!(*doubled(stride), [2, 6, 10]);
+/// assert!(matches!(doubled(Stride::new(&[0, 1])), Cow::Borrowed(_)));
+/// ```
+#[derive(Clone)]
+pub struct StrideBuf<T, const S: usize> {
+    data: Vec<T>,
+}
+
+impl<T, const S: usize> StrideBuf<T, S> {
+    /// Constructs a new, empty owned strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let buf = StrideBuf::<i32, 2>::new();
+    /// assert!(buf.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    /// Constructs an owned strided slice that takes ownership of the given
+    /// underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let buf = StrideBuf::<_, 3>::from_vec(vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(buf, [1, 4]);
+    /// ```
+    pub const fn from_vec(data: Vec<T>) -> Self {
+        Self { data }
+    }
+
+    /// Consumes the owned strided slice and returns the underlying data,
+    /// including the elements in between those in the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let buf = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(buf.into_underlying(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_underlying(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns a strided slice containing the entire owned strided slice.
+    pub fn as_stride(&self) -> &Stride<T, S> {
+        Stride::new(&self.data)
+    }
+
+    /// Returns a mutable strided slice containing the entire owned strided
+    /// slice.
+    pub fn as_mut_stride(&mut self) -> &mut Stride<T, S> {
+        Stride::new_mut(&mut self.data)
+    }
+
+    /// Constructs an owned strided slice from the elements of an iterator,
+    /// filling the positions in between them with clones of `filler`.
+    ///
+    /// Use [`collect()`][`Iterator::collect()`] instead to fill them with the
+    /// default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let buf = StrideBuf::<_, 3>::from_iter_with_filler(1..=3, -1);
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// assert_eq!(buf.underlying(), &[1, -1, -1, 2, -1, -1, 3]);
+    /// ```
+    pub fn from_iter_with_filler<I>(iter: I, filler: T) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Clone,
+    {
+        let mut buf = Self::new();
+        buf.extend_with_filler(iter, filler);
+        buf
+    }
+
+    /// Appends an element to the back of the owned strided slice, filling the
+    /// positions before it with the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let mut buf = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]);
+    /// buf.push(4);
+    /// assert_eq!(buf, [1, 3, 4]);
+    /// assert_eq!(buf.underlying(), &[1, 2, 3, 0, 4]);
+    /// ```
+    pub fn push(&mut self, value: T)
+    where
+        T: Default,
+    {
+        self.push_with(value, T::default);
+    }
+
+    /// Appends an element to the back of the owned strided slice, filling the
+    /// positions before it with clones of `filler`.
+    pub fn push_with_filler(&mut self, value: T, filler: T)
+    where
+        T: Clone,
+    {
+        self.push_with(value, || filler.clone());
+    }
+
+    /// Appends the elements of an iterator to the back of the owned strided
+    /// slice, filling the positions in between them with clones of `filler`.
+    ///
+    /// Use [`extend()`][`Extend::extend()`] instead to fill them with the
+    /// default value.
+    pub fn extend_with_filler<I>(&mut self, iter: I, filler: T)
+    where
+        I: IntoIterator<Item = T>,
+        T: Clone,
+    {
+        self.extend_with(iter, || filler.clone());
+    }
+
+    fn push_with<F>(&mut self, value: T, filler: F)
+    where
+        F: FnMut() -> T,
+    {
+        // This never truncates, since the length is rounded up to a multiple
+        // of `S`.
+        let len = self.len() * S;
+        self.data.resize_with(len, filler);
+        self.data.push(value);
+    }
+
+    fn extend_with<I, F>(&mut self, iter: I, mut filler: F)
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut() -> T,
+    {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.data.reserve(lower.saturating_mul(S));
+        for value in iter {
+            self.push_with(value, &mut filler);
+        }
+    }
+
+    /// Converts the owned strided slice into a boxed strided slice, dropping
+    /// any excess capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::StrideBuf;
+    /// #
+    /// let buf = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]);
+    /// let boxed = buf.into_boxed_stride();
+    /// assert_eq!(boxed.underlying(), &[1, 2, 3]);
+    /// ```
+    pub fn into_boxed_stride(self) -> Box<Stride<T, S>> {
+        Stride::from_boxed_slice(self.data.into_boxed_slice())
+    }
+}
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Copies the elements of the strided slice into a new `Vec`.
+    ///
+    /// With the `portable_simd` feature, primitive numbers are copied several
+    /// elements at a time, like in [`copy_to_slice()`][`Stride::copy_to_slice()`].
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(stride.to_vec(), vec![1, 3, 5]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        if S == 1 {
+            // The underlying slice is exactly the strided elements, so use the
+            // slice version which copies in bulk where possible.
+            return self.data.to_vec();
+        }
+        #[cfg(feature = "portable_simd")]
+        return SpecToVec::to_vec(self);
+        #[cfg(not(feature = "portable_simd"))]
+        self.to_vec_cloned()
+    }
+
+    /// Clones the elements of the strided slice into a new `Vec` one at a
+    /// time.
+    fn to_vec_cloned(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        let mut vec = Vec::with_capacity(len);
+        let src = self.as_ptr();
+        for i in 0..len {
+            // SAFETY: `i` is in bounds, and can't overflow.
+            vec.push(unsafe { &*src.add(i * S) }.clone());
+        }
+        vec
+    }
+
+    /// Concatenates the elements of the given strided slices into a new
+    /// `Vec`.
+    ///
+    /// This is the [`Stride`] version of [`concat()`][`slice::concat()`]. Any
+    /// iterator of strided slices, or of types like [`StrideBuf<T, S>`] that
+    /// can be referenced as one, can be concatenated.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let a = Stride::<_, 2>::new(&[1, 2, 3, 4]);
+    /// let b = Stride::<_, 2>::new(&[5, 6, 7]);
+    /// assert_eq!(Stride::concat(&[a, b]), vec![1, 3, 5, 7]);
+    /// ```
+    pub fn concat<I>(strides: I) -> Vec<T>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Self>,
+        T: Clone,
+    {
+        let mut vec = Vec::new();
+        for stride in strides {
+            let stride = stride.as_ref();
+            if S == 1 {
+                vec.extend_from_slice(&stride.data);
+            } else {
+                vec.reserve(stride.len());
+                vec.extend(stride.iter().cloned());
+            }
+        }
+        vec
+    }
+
+    /// Creates a `Vec` by repeating the elements of the strided slice `n`
+    /// times.
+    ///
+    /// This is the [`Stride`] version of [`repeat()`][`slice::repeat()`].
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the capacity would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4]);
+    /// assert_eq!(stride.repeat(3), vec![1, 3, 1, 3, 1, 3]);
+    /// ```
+    #[track_caller]
+    pub fn repeat(&self, n: usize) -> Vec<T>
+    where
+        T: Copy,
+    {
+        let len = match self.len().checked_mul(n) {
+            Some(len) => len,
+            None => panic!("capacity overflow"),
+        };
+        let mut vec = Vec::with_capacity(len);
+        if len == 0 {
+            return vec;
+        }
+        vec.extend(self.iter().copied());
+        // Double the repeated elements until at least half are filled in, and
+        // then copy the rest, so that only the first copy gathers.
+        while vec.len() <= len / 2 {
+            vec.extend_from_within(..);
+        }
+        vec.extend_from_within(..len - vec.len());
+        vec
+    }
+
+    /// Constructs a new boxed strided slice that takes ownership of the given
+    /// underlying boxed slice.
+    ///
+    /// This allows a strided slice to be stored by value without a lifetime.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// struct Channel {
+    ///     samples: Box<Stride<i16, 2>>,
+    /// }
+    ///
+    /// let data = vec![1, -1, 2, -2].into_boxed_slice();
+    /// let channel = Channel { samples: Stride::from_boxed_slice(data) };
+    /// assert_eq!(*channel.samples, [1, 2]);
+    /// ```
+    pub fn from_boxed_slice(data: Box<[T]>) -> Box<Self> {
+        let () = Self::ASSERT_NONZERO;
+        // SAFETY: `Stride<T, S>` is a transparent wrapper around `[T]`, so the
+        // allocation has the same layout and is dropped the same way.
+        unsafe { Box::from_raw(Box::into_raw(data) as *mut Self) }
+    }
+
+    /// Converts a boxed strided slice back into the underlying boxed slice,
+    /// including the elements in between those in the strided slice.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::from_boxed_slice(Box::new([1, 2, 3]));
+    /// assert_eq!(*stride.into_boxed_underlying(), [1, 2, 3]);
+    /// ```
+    pub fn into_boxed_underlying(self: Box<Self>) -> Box<[T]> {
+        // SAFETY: see `from_boxed_slice()`.
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut [T]) }
+    }
+
+    /// Constructs a new shared strided slice from the given underlying shared
+    /// slice, without copying it.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use std::thread;
+    /// # use stride::Stride;
+    /// #
+    /// let data: Arc<[i32]> = Arc::from([1, 10, 2, 20, 3, 30]);
+    /// let left = Stride::<_, 2>::from_arc_slice(data.clone());
+    /// let sum = thread::spawn(move || left.iter().sum::<i32>()).join().unwrap();
+    /// assert_eq!(sum, 6);
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn from_arc_slice(data: Arc<[T]>) -> Arc<Self> {
+        let () = Self::ASSERT_NONZERO;
+        // SAFETY: see `from_boxed_slice()`.
+        unsafe { Arc::from_raw(Arc::into_raw(data) as *const Self) }
+    }
+
+    /// Converts a shared strided slice back into the underlying shared slice,
+    /// including the elements in between those in the strided slice.
+    ///
+    /// Only available with the `alloc` feature.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn into_arc_underlying(this: Arc<Self>) -> Arc<[T]> {
+        // SAFETY: see `from_boxed_slice()`.
+        unsafe { Arc::from_raw(Arc::into_raw(this) as *const [T]) }
+    }
+
+    /// Constructs a new reference-counted strided slice from the given
+    /// underlying reference-counted slice, without copying it.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use stride::Stride;
+    /// #
+    /// let data: Rc<[i32]> = Rc::from([1, 10, 2, 20, 3, 30]);
+    /// let right = Stride::<_, 2>::from_rc_slice(data[1..].into());
+    /// assert_eq!(*right, [10, 20, 30]);
+    /// ```
+    pub fn from_rc_slice(data: Rc<[T]>) -> Rc<Self> {
+        let () = Self::ASSERT_NONZERO;
+        // SAFETY: see `from_boxed_slice()`.
+        unsafe { Rc::from_raw(Rc::into_raw(data) as *const Self) }
+    }
+
+    /// Converts a reference-counted strided slice back into the underlying
+    /// reference-counted slice, including the elements in between those in
+    /// the strided slice.
+    ///
+    /// Only available with the `alloc` feature.
+    pub fn into_rc_underlying(this: Rc<Self>) -> Rc<[T]> {
+        // SAFETY: see `from_boxed_slice()`.
+        unsafe { Rc::from_raw(Rc::into_raw(this) as *const [T]) }
+    }
+}
+
+/// Copies the elements of a strided slice into a new `Vec`, with
+/// [`copy_to_slice()`][`Stride::copy_to_slice()`] for the primitive numbers so
+/// that they are copied with SIMD.
+#[cfg(feature = "portable_simd")]
+trait SpecToVec: Clone {
+    fn to_vec<const S: usize>(stride: &Stride<Self, S>) -> Vec<Self>;
+}
+
+#[cfg(feature = "portable_simd")]
+impl<T> SpecToVec for T
+where
+    T: Clone,
+{
+    default fn to_vec<const S: usize>(stride: &Stride<T, S>) -> Vec<T> {
+        stride.to_vec_cloned()
+    }
+}
+
+#[cfg(feature = "portable_simd")]
+macro_rules! impl_spec_to_vec {
+    ($($ty:ty),*) => {$(
+        impl SpecToVec for $ty {
+            fn to_vec<const S: usize>(stride: &Stride<$ty, S>) -> Vec<$ty> {
+                let len = stride.len();
+                let mut vec = Vec::with_capacity(len);
+                stride.copy_to_uninit(&mut vec.spare_capacity_mut()[..len]);
+                // SAFETY: the first `len` elements were initialized above.
+                unsafe { vec.set_len(len) };
+                vec
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "portable_simd")]
+impl_spec_to_vec! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64 }
+
+impl<T, const S: usize> ToOwned for Stride<T, S>
+where
+    T: Clone,
+{
+    type Owned = StrideBuf<T, S>;
+
+    /// Clones the underlying slice into an owned strided slice, including the
+    /// elements in between those in the strided slice.
+    fn to_owned(&self) -> Self::Owned {
+        StrideBuf {
+            data: self.data.to_vec(),
+        }
+    }
+
+    fn clone_into(&self, target: &mut Self::Owned) {
+        self.data.clone_into(&mut target.data);
+    }
+}
+
+impl<T, const S: usize> Default for Box<Stride<T, S>> {
+    fn default() -> Self {
+        Stride::from_boxed_slice(Box::default())
+    }
+}
+
+impl<T, const S: usize> Clone for Box<Stride<T, S>>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Stride::from_boxed_slice(self.data.into())
+    }
+}
+
+impl<T, const S: usize> Default for StrideBuf<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for StrideBuf<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_stride(), f)
+    }
+}
+
+impl<T, const S: usize> Deref for StrideBuf<T, S> {
+    type Target = Stride<T, S>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_stride()
+    }
+}
+
+impl<T, const S: usize> DerefMut for StrideBuf<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_stride()
+    }
+}
+
+impl<T, const S: usize> AsRef<Stride<T, S>> for StrideBuf<T, S> {
+    fn as_ref(&self) -> &Stride<T, S> {
+        self.as_stride()
+    }
+}
+
+impl<T, const S: usize> AsMut<Stride<T, S>> for StrideBuf<T, S> {
+    fn as_mut(&mut self) -> &mut Stride<T, S> {
+        self.as_mut_stride()
+    }
+}
+
+impl<T, const S: usize> Borrow<Stride<T, S>> for StrideBuf<T, S> {
+    fn borrow(&self) -> &Stride<T, S> {
+        self.as_stride()
+    }
+}
+
+impl<T, const S: usize> BorrowMut<Stride<T, S>> for StrideBuf<T, S> {
+    fn borrow_mut(&mut self) -> &mut Stride<T, S> {
+        self.as_mut_stride()
+    }
+}
+
+impl<T, const S: usize> FromIterator<T> for StrideBuf<T, S>
+where
+    T: Default,
+{
+    /// Collects the elements of an iterator into an owned strided slice,
+    /// filling the positions in between them with the default value.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl<T, const S: usize> Extend<T> for StrideBuf<T, S>
+where
+    T: Default,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.extend_with(iter, T::default);
+    }
+}
+
+impl<'a, T, const S: usize> Extend<&'a T> for StrideBuf<T, S>
+where
+    T: Copy + Default + 'a,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend_with(iter.into_iter().copied(), T::default);
+    }
+}
+
+impl<T, const S: usize> From<Vec<T>> for StrideBuf<T, S> {
+    fn from(data: Vec<T>) -> Self {
+        Self::from_vec(data)
+    }
+}
+
+impl<T, const S: usize> From<Box<Stride<T, S>>> for StrideBuf<T, S> {
+    fn from(stride: Box<Stride<T, S>>) -> Self {
+        Self::from_vec(stride.into_boxed_underlying().into_vec())
+    }
+}
+
+impl<T, const S: usize> From<StrideBuf<T, S>> for Box<Stride<T, S>> {
+    fn from(buf: StrideBuf<T, S>) -> Self {
+        buf.into_boxed_stride()
+    }
+}
+
+impl<T, const S: usize> From<Box<[T]>> for Box<Stride<T, S>> {
+    fn from(data: Box<[T]>) -> Self {
+        Stride::from_boxed_slice(data)
+    }
+}
+
+impl<T, const S: usize> From<Vec<T>> for Box<Stride<T, S>> {
+    fn from(data: Vec<T>) -> Self {
+        Stride::from_boxed_slice(data.into_boxed_slice())
+    }
+}
+
+impl<T, const S: usize> From<Box<Stride<T, S>>> for Box<[T]> {
+    fn from(stride: Box<Stride<T, S>>) -> Self {
+        stride.into_boxed_underlying()
+    }
+}
+
+impl<T, const S: usize> From<Box<Stride<T, S>>> for Vec<T> {
+    fn from(stride: Box<Stride<T, S>>) -> Self {
+        stride.into_boxed_underlying().into_vec()
+    }
+}
+
+impl<T, const S: usize> From<&Stride<T, S>> for StrideBuf<T, S>
+where
+    T: Clone,
+{
+    fn from(stride: &Stride<T, S>) -> Self {
+        stride.to_owned()
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a StrideBuf<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a mut StrideBuf<T, S> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+macro_rules! impl_partial_eq {
+    ($Rhs:ty, [$($params:tt)*]) => {
+        impl<T, U, const S: usize, $($params)*> PartialEq<$Rhs> for StrideBuf<T, S>
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$Rhs) -> bool {
+                eq_by(self.iter(), other.iter())
+            }
+        }
+    };
+}
+
+impl_partial_eq! { Stride<U, R>, [const R: usize] }
+impl_partial_eq! { &Stride<U, R>, [const R: usize] }
+impl_partial_eq! { [U], [] }
+impl_partial_eq! { &[U], [] }
+impl_partial_eq! { [U; N], [const N: usize] }
+impl_partial_eq! { &[U; N], [const N: usize] }
+impl_partial_eq! { Vec<U>, [] }
+
+impl<T, U, const S: usize, const R: usize> PartialEq<StrideBuf<U, R>> for StrideBuf<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &StrideBuf<U, R>) -> bool {
+        *self.as_stride() == *other.as_stride()
+    }
+}
+
+impl<T, const S: usize> Eq for StrideBuf<T, S> where T: Eq {}
+
+impl<T, const S: usize> PartialOrd for StrideBuf<T, S>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_stride().partial_cmp(other.as_stride())
+    }
+}
+
+impl<T, const S: usize> Ord for StrideBuf<T, S>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_stride().cmp(other.as_stride())
+    }
+}
+
+impl<T, const S: usize> Hash for StrideBuf<T, S>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_stride().hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, const S: usize> Serialize for StrideBuf<T, S>
+where
+    T: Serialize,
+{
+    fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        self.as_stride().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const S: usize> Deserialize<'de> for StrideBuf<T, S>
+where
+    T: Deserialize<'de> + Default,
+{
+    /// Deserializes a sequence of elements, filling the positions in between
+    /// them with the default value.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BufVisitor<T, const S: usize>(PhantomData<T>);
+
+        impl<'de, T, const S: usize> Visitor<'de> for BufVisitor<T, S>
+        where
+            T: Deserialize<'de> + Default,
+        {
+            type Value = StrideBuf<T, S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut buf = StrideBuf::new();
+                // Don't trust the size hint too much, like serde does for `Vec`.
+                let hint = seq.size_hint().unwrap_or(0).min(4096);
+                buf.data.reserve(hint.saturating_mul(S));
+                while let Some(elem) = seq.next_element()? {
+                    buf.push(elem);
+                }
+                Ok(buf)
+            }
+        }
+
+        deserializer.deserialize_seq(BufVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const S: usize> Arbitrary<'a> for StrideBuf<T, S>
+where
+    T: Arbitrary<'a>,
+{
+    /// Generates an arbitrary underlying slice, including the elements in
+    /// between those in the strided slice.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Vec::arbitrary(u).map(Self::from_vec)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        Vec::arbitrary_take_rest(u).map(Self::from_vec)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Vec::<T>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const S: usize> Arbitrary<'a> for Box<Stride<T, S>>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        StrideBuf::arbitrary(u).map(StrideBuf::into_boxed_stride)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        StrideBuf::arbitrary_take_rest(u).map(StrideBuf::into_boxed_stride)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        StrideBuf::<T, S>::size_hint(depth)
+    }
+}
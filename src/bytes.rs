@@ -0,0 +1,72 @@
+//! Strided views over raw bytes with `zerocopy`.
+//!
+//! Only available with the `zerocopy` feature.
+
+use zerocopy::{CastError, FromBytes, Immutable, IntoBytes, KnownLayout};
+
+use crate::Stride;
+
+impl<T, const S: usize> Stride<T, S>
+where
+    T: FromBytes,
+{
+    /// Constructs a new strided slice from raw bytes.
+    ///
+    /// The bytes are interpreted as the underlying slice, so their length must
+    /// be a multiple of the size of `T` and they must be aligned for `T`, but
+    /// they don't need to fill the last stride. Any bytes with the right size
+    /// are a valid `T`, which `zerocopy` checks at compile time, and `T` must
+    /// not be zero-sized.
+    ///
+    /// Only available with the `zerocopy` feature.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes are misaligned or their length is not a multiple of the
+    /// size of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// # use zerocopy::little_endian::U16;
+    /// #
+    /// // Records of a little endian `u16` id followed by a `u16` value.
+    /// let bytes = &[1, 0, 10, 0, 2, 0, 20, 0, 3, 0, 30, 0];
+    /// let values = Stride::<U16, 2>::from_bytes(&bytes[2..]).unwrap();
+    /// assert_eq!(values, &[10, 20, 30]);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, CastError<&[u8], [T]>>
+    where
+        T: Immutable + KnownLayout,
+    {
+        <[T]>::ref_from_bytes(bytes).map(Self::new)
+    }
+
+    /// Constructs a new mutable strided slice from raw bytes.
+    ///
+    /// Writing to the strided slice writes to the bytes, so `T` must also not
+    /// have any padding, which `zerocopy` checks at compile time.
+    ///
+    /// Only available with the `zerocopy` feature.
+    ///
+    /// *See [`from_bytes()`][`Stride::from_bytes()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// # use zerocopy::little_endian::U16;
+    /// #
+    /// let bytes = &mut [1, 0, 10, 0, 2, 0, 20, 0];
+    /// let values = Stride::<U16, 2>::from_bytes_mut(&mut bytes[2..]).unwrap();
+    /// values[1] = U16::new(0x0102);
+    /// assert_eq!(bytes, &[1, 0, 10, 0, 2, 0, 2, 1]);
+    /// ```
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, CastError<&mut [u8], [T]>>
+    where
+        T: IntoBytes + KnownLayout,
+    {
+        <[T]>::mut_from_bytes(bytes).map(Self::new_mut)
+    }
+}
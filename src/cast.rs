@@ -0,0 +1,159 @@
+//! Reinterpreting strided slices of plain data with `bytemuck`.
+//!
+//! Only available with the `bytemuck` feature.
+
+use core::mem;
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::Stride;
+
+impl<T, const S: usize> Stride<T, S>
+where
+    T: Pod,
+{
+    /// Reinterprets the strided slice as a strided slice of another plain
+    /// data type with a stride of `R`.
+    ///
+    /// Each element of the new strided slice starts at the same byte as the
+    /// corresponding element of this one, so `R` must be chosen such that
+    /// the strides are the same number of bytes, i.e.
+    /// `S * size_of::<T>() == R * size_of::<U>()`. For zero-sized types `R`
+    /// must be equal to `S`.
+    ///
+    /// Only available with the `bytemuck` feature.
+    ///
+    /// # Errors
+    ///
+    /// - [`SizeMismatch`][`PodCastError::SizeMismatch`] if the strides are a
+    ///   different number of bytes, or different strides of zero-sized types.
+    /// - [`OutputSliceWouldHaveSlop`][`PodCastError::OutputSliceWouldHaveSlop`]
+    ///   if the underlying slice ends before the bytes of the last element.
+    /// - [`TargetAlignmentGreaterAndInputNotAligned`][`PodCastError::TargetAlignmentGreaterAndInputNotAligned`]
+    ///   if the underlying slice is not aligned for `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data: &[u32] = &[0x0101_0101, 0, 0x0202_0202, 0, 0x0303_0303];
+    /// let stride = Stride::<_, 2>::new(data);
+    /// assert_eq!(stride.try_cast::<u8, 8>().unwrap(), &[1, 2, 3]);
+    /// assert_eq!(stride.try_cast::<[u8; 2], 4>().unwrap(), &[[1, 1], [2, 2], [3, 3]]);
+    /// assert!(stride.try_cast::<u8, 2>().is_err());
+    /// ```
+    pub fn try_cast<U, const R: usize>(&self) -> Result<&Stride<U, R>, PodCastError>
+    where
+        U: Pod,
+    {
+        let end = cast_len::<T, U, S, R>(self.len(), self.data.len())?;
+        if mem::size_of::<T>() == mem::size_of::<U>() {
+            return bytemuck::try_cast_slice(&self.data).map(Stride::new);
+        }
+        let bytes: &[u8] = bytemuck::cast_slice(&self.data);
+        bytemuck::try_cast_slice(&bytes[..end]).map(Stride::new)
+    }
+
+    /// Reinterprets the mutable strided slice as a mutable strided slice of
+    /// another plain data type with a stride of `R`.
+    ///
+    /// Only available with the `bytemuck` feature.
+    ///
+    /// *See [`try_cast()`][`Stride::try_cast()`].*
+    pub fn try_cast_mut<U, const R: usize>(&mut self) -> Result<&mut Stride<U, R>, PodCastError>
+    where
+        U: Pod,
+    {
+        let end = cast_len::<T, U, S, R>(self.len(), self.data.len())?;
+        if mem::size_of::<T>() == mem::size_of::<U>() {
+            return bytemuck::try_cast_slice_mut(&mut self.data).map(Stride::new_mut);
+        }
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut self.data);
+        bytemuck::try_cast_slice_mut(&mut bytes[..end]).map(Stride::new_mut)
+    }
+
+    /// Reinterprets the strided slice as a strided slice of another plain
+    /// data type with a stride of `R`.
+    ///
+    /// Only available with the `bytemuck` feature.
+    ///
+    /// *See [`try_cast()`][`Stride::try_cast()`].*
+    ///
+    /// # Panics
+    ///
+    /// If the conversion would fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0u32; 5];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.cast_mut::<[u8; 4], 2>().fill([0xff; 4]);
+    /// assert_eq!(stride.cast::<u16, 4>(), &[0xffff, 0xffff, 0xffff]);
+    /// assert_eq!(data, &[u32::MAX, 0, u32::MAX, 0, u32::MAX]);
+    /// ```
+    #[track_caller]
+    pub fn cast<U, const R: usize>(&self) -> &Stride<U, R>
+    where
+        U: Pod,
+    {
+        match self.try_cast() {
+            Ok(stride) => stride,
+            Err(err) => cast_fail(err),
+        }
+    }
+
+    /// Reinterprets the mutable strided slice as a mutable strided slice of
+    /// another plain data type with a stride of `R`.
+    ///
+    /// Only available with the `bytemuck` feature.
+    ///
+    /// *See [`try_cast()`][`Stride::try_cast()`].*
+    ///
+    /// # Panics
+    ///
+    /// If the conversion would fail.
+    #[track_caller]
+    pub fn cast_mut<U, const R: usize>(&mut self) -> &mut Stride<U, R>
+    where
+        U: Pod,
+    {
+        match self.try_cast_mut() {
+            Ok(stride) => stride,
+            Err(err) => cast_fail(err),
+        }
+    }
+}
+
+/// Checks that a strided slice of `len` elements of `T` with the underlying
+/// slice length `data_len` can be reinterpreted as elements of `U`, and
+/// returns the number of bytes up to the end of the last element as a `U`.
+fn cast_len<T, U, const S: usize, const R: usize>(
+    len: usize,
+    data_len: usize,
+) -> Result<usize, PodCastError> {
+    let (size_t, size_u) = (mem::size_of::<T>(), mem::size_of::<U>());
+    // The strides of zero-sized types are always zero bytes, but a different
+    // stride would still change the number of elements.
+    if S * size_t != R * size_u || (size_t == 0 && S != R) {
+        return Err(PodCastError::SizeMismatch);
+    }
+    let end = match len {
+        0 => 0,
+        len => (len - 1) * S * size_t + size_u,
+    };
+    if end > data_len * size_t {
+        return Err(PodCastError::OutputSliceWouldHaveSlop);
+    }
+    Ok(end)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn cast_fail(err: PodCastError) -> ! {
+    panic!("failed to cast strided slice: {:?}", err)
+}
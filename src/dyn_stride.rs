@@ -0,0 +1,561 @@
+//! Strided slices whose stride is only known at runtime.
+//!
+//! A [`Stride`] is an unsized type so a reference to it only has room for the
+//! length of the underlying slice. The runtime stride therefore has to be
+//! stored next to the reference, which is why these types are reference-like
+//! structs instead of being used behind a `&` or `&mut` themselves.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::NonZeroUsize;
+use core::ops::{Index, IndexMut};
+
+use crate::index::{self, strided_len};
+use crate::ops::{eq_by, hash_elements};
+use crate::{DynIter, DynIterMut, Stride};
+
+/// A strided slice with a stride chosen at runtime.
+///
+/// This is the runtime counterpart of a [`&Stride<T, S>`][`Stride`], and is
+/// cheap to copy in the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use core::num::NonZeroUsize;
+/// # use stride::DynStride;
+/// #
+/// // The number of channels might come from a file header.
+/// let channels = NonZeroUsize::new(3).unwrap();
+/// let data = &[1, 2, 3, 4, 5, 6];
+/// let stride = DynStride::new(data, channels);
+/// assert_eq!(stride.len(), 2);
+/// assert_eq!(stride, &[1, 4]);
+/// ```
+pub struct DynStride<'a, T> {
+    data: &'a [T],
+    stride: NonZeroUsize,
+}
+
+/// A mutable strided slice with a stride chosen at runtime.
+///
+/// This is the runtime counterpart of a [`&mut Stride<T, S>`][`Stride`].
+///
+/// # Examples
+///
+/// ```
+/// # use core::num::NonZeroUsize;
+/// # use stride::DynStrideMut;
+/// #
+/// let data = &mut [1, 2, 3, 4, 5, 6];
+/// let mut stride = DynStrideMut::new(data, NonZeroUsize::new(2).unwrap());
+/// stride[1] = 7;
+/// assert_eq!(data, &[1, 2, 7, 4, 5, 6]);
+/// ```
+pub struct DynStrideMut<'a, T> {
+    data: &'a mut [T],
+    stride: NonZeroUsize,
+}
+
+/// Returns `S` as a `NonZeroUsize`.
+///
+/// Every `Stride<T, S>` constructor checks that `S` is non-zero so this can
+/// only fail for a stride that can't be constructed anyway.
+fn non_zero<const S: usize>() -> NonZeroUsize {
+    NonZeroUsize::new(S).expect("the stride `S` must be non-zero")
+}
+
+impl<T> Clone for DynStride<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DynStride<'_, T> {}
+
+impl<'a, T> DynStride<'a, T> {
+    /// Constructs a new strided slice with the given stride.
+    pub fn new(data: &'a [T], stride: NonZeroUsize) -> Self {
+        Self { data, stride }
+    }
+
+    /// Returns the stride, i.e. the distance between consecutive elements in
+    /// the underlying slice.
+    pub fn stride(&self) -> usize {
+        self.stride.get()
+    }
+
+    /// Returns the number of elements in the strided slice.
+    pub fn len(&self) -> usize {
+        strided_len(self.data.len(), self.stride())
+    }
+
+    /// Returns `true` if the strided slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the underlying slice, including the elements in between those
+    /// in the strided slice.
+    pub fn underlying(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Returns the index in the underlying slice of the element at `index`, or
+    /// `None` if out of bounds.
+    pub fn raw_index(&self, index: usize) -> Option<usize> {
+        if index < self.len() {
+            Some(index * self.stride())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStride;
+    /// #
+    /// let stride = DynStride::new(&[1, 2, 3, 4, 5], NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(stride.get(2), Some(&5));
+    /// assert_eq!(stride.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        index::get_elem(self.data, index, self.stride())
+    }
+
+    /// Returns a reference to the first element, or `None` if it is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.data.first()
+    }
+
+    /// Returns a reference to the last element, or `None` if it is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Returns an iterator over the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStride;
+    /// #
+    /// let stride = DynStride::new(&[1, 2, 3, 4, 5, 6], NonZeroUsize::new(3).unwrap());
+    /// let mut iter = stride.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> DynIter<'a, T> {
+        DynIter::new(self.data, self.stride)
+    }
+
+    /// Converts to a constant strided slice, or returns `None` if the stride
+    /// is not `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStride;
+    /// #
+    /// let stride = DynStride::new(&[1, 2, 3, 4], NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(stride.to_stride::<2>().unwrap(), &[1, 3]);
+    /// assert!(stride.to_stride::<3>().is_none());
+    /// ```
+    pub fn to_stride<const S: usize>(&self) -> Option<&'a Stride<T, S>> {
+        if self.stride() == S {
+            Some(Stride::new(self.data))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> DynStrideMut<'a, T> {
+    /// Constructs a new mutable strided slice with the given stride.
+    pub fn new(data: &'a mut [T], stride: NonZeroUsize) -> Self {
+        Self { data, stride }
+    }
+
+    /// Returns the stride, i.e. the distance between consecutive elements in
+    /// the underlying slice.
+    pub fn stride(&self) -> usize {
+        self.stride.get()
+    }
+
+    /// Returns the number of elements in the strided slice.
+    pub fn len(&self) -> usize {
+        self.as_dyn_stride().len()
+    }
+
+    /// Returns `true` if the strided slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the underlying slice, including the elements in between those
+    /// in the strided slice.
+    pub fn underlying(&self) -> &[T] {
+        self.data
+    }
+
+    /// Returns the underlying mutable slice, including the elements in between
+    /// those in the strided slice.
+    pub fn underlying_mut(&mut self) -> &mut [T] {
+        self.data
+    }
+
+    /// Converts into the underlying mutable slice with the same lifetime.
+    #[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+    pub(crate) fn into_underlying_mut(self) -> &'a mut [T] {
+        self.data
+    }
+
+    /// Returns a shared strided slice borrowing from this one.
+    pub fn as_dyn_stride(&self) -> DynStride<'_, T> {
+        DynStride::new(self.data, self.stride)
+    }
+
+    /// Returns a mutable strided slice that reborrows this one.
+    pub fn reborrow(&mut self) -> DynStrideMut<'_, T> {
+        DynStrideMut::new(self.data, self.stride)
+    }
+
+    /// Returns the index in the underlying slice of the element at `index`, or
+    /// `None` if out of bounds.
+    pub fn raw_index(&self, index: usize) -> Option<usize> {
+        self.as_dyn_stride().raw_index(index)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_dyn_stride().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if out
+    /// of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let stride = self.stride();
+        index::get_elem_mut(self.data, index, stride)
+    }
+
+    /// Returns a reference to the first element, or `None` if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.as_dyn_stride().first()
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if it is
+    /// empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.data.first_mut()
+    }
+
+    /// Returns a reference to the last element, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.as_dyn_stride().last()
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if it is
+    /// empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Returns an iterator over the strided slice.
+    pub fn iter(&self) -> DynIter<'_, T> {
+        self.as_dyn_stride().iter()
+    }
+
+    /// Returns an iterator over the strided slice that allows modifying each
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStrideMut;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let mut stride = DynStrideMut::new(data, NonZeroUsize::new(3).unwrap());
+    /// for x in stride.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(data, &[10, 2, 3, 40, 5, 6]);
+    /// ```
+    pub fn iter_mut(&mut self) -> DynIterMut<'_, T> {
+        DynIterMut::new(self.data, self.stride)
+    }
+
+    /// Converts to a constant strided slice, or returns `None` if the stride
+    /// is not `S`.
+    pub fn as_stride<const S: usize>(&self) -> Option<&Stride<T, S>> {
+        self.as_dyn_stride().to_stride()
+    }
+
+    /// Converts to a mutable constant strided slice, or returns `None` if the
+    /// stride is not `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStrideMut;
+    /// #
+    /// let data = &mut [3, 2, 1, 4];
+    /// let mut stride = DynStrideMut::new(data, NonZeroUsize::new(2).unwrap());
+    /// stride.as_stride_mut::<2>().unwrap().sort_unstable();
+    /// assert_eq!(data, &[1, 2, 3, 4]);
+    /// ```
+    pub fn as_stride_mut<const S: usize>(&mut self) -> Option<&mut Stride<T, S>> {
+        if self.stride() == S {
+            Some(Stride::new_mut(self.data))
+        } else {
+            None
+        }
+    }
+
+    /// Converts to a mutable constant strided slice with the same lifetime,
+    /// or gives back `self` if the stride is not `S`.
+    pub fn into_stride<const S: usize>(self) -> Result<&'a mut Stride<T, S>, Self> {
+        if self.stride() == S {
+            Ok(Stride::new_mut(self.data))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Returns a strided slice with the same elements whose stride is stored
+    /// at runtime.
+    ///
+    /// This is useful for passing strided slices with different strides to
+    /// the same non-generic code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::{DynStride, Stride};
+    /// #
+    /// fn sum(stride: DynStride<'_, i32>) -> i32 {
+    ///     stride.iter().sum()
+    /// }
+    ///
+    /// let data = &[1, 2, 3, 4, 5, 6];
+    /// assert_eq!(sum(Stride::<_, 2>::new(data).as_dyn()), 9);
+    /// assert_eq!(sum(Stride::<_, 3>::new(data).as_dyn()), 5);
+    /// ```
+    pub fn as_dyn(&self) -> DynStride<'_, T> {
+        DynStride::new(&self.data, non_zero::<S>())
+    }
+
+    /// Returns a mutable strided slice with the same elements whose stride is
+    /// stored at runtime.
+    pub fn as_dyn_mut(&mut self) -> DynStrideMut<'_, T> {
+        DynStrideMut::new(&mut self.data, non_zero::<S>())
+    }
+}
+
+impl<'a, T, const S: usize> From<&'a Stride<T, S>> for DynStride<'a, T> {
+    fn from(stride: &'a Stride<T, S>) -> Self {
+        stride.as_dyn()
+    }
+}
+
+impl<'a, T, const S: usize> From<&'a mut Stride<T, S>> for DynStrideMut<'a, T> {
+    fn from(stride: &'a mut Stride<T, S>) -> Self {
+        stride.as_dyn_mut()
+    }
+}
+
+impl<'a, T> From<DynStrideMut<'a, T>> for DynStride<'a, T> {
+    fn from(stride: DynStrideMut<'a, T>) -> Self {
+        DynStride::new(stride.data, stride.stride)
+    }
+}
+
+impl<T> fmt::Debug for DynStride<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> fmt::Debug for DynStrideMut<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_dyn_stride().fmt(f)
+    }
+}
+
+impl<T> Default for DynStride<'_, T> {
+    /// Creates an empty strided slice with a stride of 1.
+    fn default() -> Self {
+        Self::new(&[], NonZeroUsize::MIN)
+    }
+}
+
+impl<T> Default for DynStrideMut<'_, T> {
+    /// Creates an empty strided slice with a stride of 1.
+    fn default() -> Self {
+        Self::new(&mut [], NonZeroUsize::MIN)
+    }
+}
+
+impl<T> Index<usize> for DynStride<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        index::index_elem(self.data, index, self.stride())
+    }
+}
+
+impl<T> Index<usize> for DynStrideMut<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        index::index_elem(self.data, index, self.stride())
+    }
+}
+
+impl<T> IndexMut<usize> for DynStrideMut<'_, T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let stride = self.stride();
+        index::index_elem_mut(self.data, index, stride)
+    }
+}
+
+impl<'a, T> IntoIterator for DynStride<'a, T> {
+    type Item = &'a T;
+    type IntoIter = DynIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &DynStride<'a, T> {
+    type Item = &'a T;
+    type IntoIter = DynIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for DynStrideMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = DynIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DynIterMut::new(self.data, self.stride)
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'b mut DynStrideMut<'a, T> {
+    type Item = &'b mut T;
+    type IntoIter = DynIterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, U> PartialEq<DynStride<'_, U>> for DynStride<'_, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DynStride<'_, U>) -> bool {
+        eq_by(self.iter(), other.iter())
+    }
+}
+
+impl<T, U> PartialEq<DynStrideMut<'_, U>> for DynStrideMut<'_, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DynStrideMut<'_, U>) -> bool {
+        eq_by(self.iter(), other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DynStride<'_, T> {}
+
+impl<T: Eq> Eq for DynStrideMut<'_, T> {}
+
+macro_rules! impl_partial_eq {
+    ($Dyn:ident, $Rhs:ty, [$($params:tt)*]) => {
+        impl<T, U, $($params)*> PartialEq<$Rhs> for $Dyn<'_, T>
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$Rhs) -> bool {
+                eq_by(self.iter(), other.iter())
+            }
+        }
+    };
+}
+
+impl_partial_eq! { DynStride, Stride<U, R>, [const R: usize] }
+impl_partial_eq! { DynStride, &Stride<U, R>, [const R: usize] }
+impl_partial_eq! { DynStride, [U], [] }
+impl_partial_eq! { DynStride, &[U], [] }
+impl_partial_eq! { DynStride, [U; N], [const N: usize] }
+impl_partial_eq! { DynStride, &[U; N], [const N: usize] }
+impl_partial_eq! { DynStrideMut, Stride<U, R>, [const R: usize] }
+impl_partial_eq! { DynStrideMut, &Stride<U, R>, [const R: usize] }
+impl_partial_eq! { DynStrideMut, [U], [] }
+impl_partial_eq! { DynStrideMut, &[U], [] }
+impl_partial_eq! { DynStrideMut, [U; N], [const N: usize] }
+impl_partial_eq! { DynStrideMut, &[U; N], [const N: usize] }
+
+impl<T, U, const S: usize> PartialEq<DynStride<'_, U>> for Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DynStride<'_, U>) -> bool {
+        eq_by(self.iter(), other.iter())
+    }
+}
+
+impl<T, U, const S: usize> PartialEq<DynStrideMut<'_, U>> for Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DynStrideMut<'_, U>) -> bool {
+        eq_by(self.iter(), other.iter())
+    }
+}
+
+impl<T> Hash for DynStride<'_, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_elements(self.iter(), state)
+    }
+}
+
+impl<T> Hash for DynStrideMut<'_, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_dyn_stride().hash(state)
+    }
+}
@@ -0,0 +1,67 @@
+use core::fmt;
+
+/// The error type returned by [`get_disjoint_mut()`][`Stride::get_disjoint_mut()`].
+///
+/// It indicates one of two possible errors:
+/// - An index is out of bounds.
+/// - The same index appeared multiple times in the array.
+///
+/// [`Stride::get_disjoint_mut()`]: crate::Stride::get_disjoint_mut()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GetDisjointMutError {
+    /// An index provided was out of bounds for the strided slice.
+    IndexOutOfBounds,
+    /// Two indexes provided were overlapping.
+    OverlappingIndices,
+}
+
+impl fmt::Display for GetDisjointMutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            GetDisjointMutError::IndexOutOfBounds => "an index is out of bounds",
+            GetDisjointMutError::OverlappingIndices => "there were overlapping indices",
+        };
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+impl core::error::Error for GetDisjointMutError {}
+
+/// The error type returned when a conversion from a strided slice to an array
+/// fails.
+///
+/// This is the [`Stride`][`crate::Stride`] version of
+/// [`TryFromSliceError`][`core::array::TryFromSliceError`].
+#[derive(Debug, Clone, Copy)]
+pub struct TryFromStrideError(pub(crate) ());
+
+impl fmt::Display for TryFromStrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt("could not convert stride to array", f)
+    }
+}
+
+impl core::error::Error for TryFromStrideError {}
+
+/// The error type returned when constructing a strided slice from a slice whose
+/// length is not a multiple of the stride.
+///
+/// This is returned by [`try_new()`][`crate::Stride::try_new()`] and
+/// [`try_new_mut()`][`crate::Stride::try_new_mut()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrideError {
+    pub(crate) len: usize,
+    pub(crate) stride: usize,
+}
+
+impl fmt::Display for StrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slice length ({}) is not a multiple of the stride ({})",
+            self.len, self.stride
+        )
+    }
+}
+
+impl core::error::Error for StrideError {}
@@ -1,4 +1,6 @@
-use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
 
 use crate::Stride;
 
@@ -15,8 +17,112 @@ mod private {
 }
 
 /// A simple trait to map stride indexes to slice indexes.
-trait Unstride: private::Sealed {
-    fn unstride<const S: usize>(self) -> Self;
+///
+/// `len` is the length of the underlying slice. Returns `None` if the index
+/// overflows, in which case it is out of bounds.
+trait Unstride: private::Sealed + Sized {
+    fn unstride<const S: usize>(self, len: usize) -> Option<Self>;
+}
+
+/// Maps a range bound in the strided slice to a range bound in the underlying
+/// slice.
+///
+/// The length of the strided slice maps to the length of the underlying slice,
+/// which is not a multiple of `S` if the last element has no trailing padding.
+fn unstride_bound<const S: usize>(bound: usize, len: usize) -> Option<usize> {
+    if bound == strided_len(len, S) {
+        Some(len)
+    } else {
+        bound.checked_mul(S)
+    }
+}
+
+/// Converts any range over `usize` into a `Range` checked against `len`.
+///
+/// This is the [`Stride`] version of the unstable `core::slice::range`. Panic
+/// messages are in terms of the strided slice, i.e. logical indexes and
+/// lengths.
+#[track_caller]
+pub(crate) fn range<R, const S: usize>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index stride from after maximum usize")),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index stride up to maximum usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!(
+            "stride index starts at {} but ends at {} (S = {})",
+            start, end, S
+        );
+    }
+    if end > len {
+        panic!(
+            "range end index {} out of range for stride of length {} (S = {})",
+            end, len, S
+        );
+    }
+    Range { start, end }
+}
+
+/// Returns the number of elements in a strided slice with the given stride
+/// over an underlying slice of length `len`.
+pub(crate) const fn strided_len(len: usize, stride: usize) -> usize {
+    len.div_ceil(stride)
+}
+
+/// Returns a reference to the element at `index` of a strided slice with the
+/// given stride over `data`, or `None` if out of bounds.
+pub(crate) fn get_elem<T>(data: &[T], index: usize, stride: usize) -> Option<&T> {
+    data.get(index.checked_mul(stride)?)
+}
+
+/// Returns a mutable reference to the element at `index` of a strided slice
+/// with the given stride over `data`, or `None` if out of bounds.
+pub(crate) fn get_elem_mut<T>(data: &mut [T], index: usize, stride: usize) -> Option<&mut T> {
+    data.get_mut(index.checked_mul(stride)?)
+}
+
+/// Returns a reference to the element at `index` of a strided slice with the
+/// given stride over `data`, panicking if out of bounds.
+#[track_caller]
+pub(crate) fn index_elem<T>(data: &[T], index: usize, stride: usize) -> &T {
+    match get_elem(data, index, stride) {
+        Some(elem) => elem,
+        None => index_fail(index, strided_len(data.len(), stride), stride),
+    }
+}
+
+/// Returns a mutable reference to the element at `index` of a strided slice
+/// with the given stride over `data`, panicking if out of bounds.
+#[track_caller]
+pub(crate) fn index_elem_mut<T>(data: &mut [T], index: usize, stride: usize) -> &mut T {
+    let len = strided_len(data.len(), stride);
+    match get_elem_mut(data, index, stride) {
+        Some(elem) => elem,
+        None => index_fail(index, len, stride),
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn index_fail(index: usize, len: usize, stride: usize) -> ! {
+    panic!(
+        "index {} out of range for stride of length {} (S = {})",
+        index, len, stride
+    );
 }
 
 /// A helper trait used for indexing operations.
@@ -24,6 +130,14 @@ trait Unstride: private::Sealed {
 /// This is the [`Stride`] version of [`SliceIndex`][`core::slice::SliceIndex`].
 /// You should not use or implement this trait directly but instead use the
 /// corresponding methods on [`Stride`].
+///
+/// It is implemented for `usize`, which indexes a single element, and for all
+/// the range types over `usize`, which index a substride.
+///
+/// # Safety
+///
+/// Implementations of the unchecked methods must return pointers that are
+/// within the bounds of the given strided slice for all in-bounds indexes.
 pub unsafe trait StrideIndex<T: ?Sized>: private::Sealed {
     /// The output type returned by methods.
     type Output: ?Sized;
@@ -70,49 +184,56 @@ pub unsafe trait StrideIndex<T: ?Sized>: private::Sealed {
 }
 
 impl Unstride for usize {
-    fn unstride<const S: usize>(self) -> Self {
-        self * S
+    fn unstride<const S: usize>(self, _: usize) -> Option<Self> {
+        self.checked_mul(S)
     }
 }
 
 impl Unstride for Range<usize> {
-    fn unstride<const S: usize>(self) -> Self {
-        Range {
-            start: self.start * S,
-            end: self.end * S,
-        }
+    fn unstride<const S: usize>(self, len: usize) -> Option<Self> {
+        Some(Range {
+            start: unstride_bound::<S>(self.start, len)?,
+            end: unstride_bound::<S>(self.end, len)?,
+        })
     }
 }
 
 impl Unstride for RangeFrom<usize> {
-    fn unstride<const S: usize>(self) -> Self {
-        RangeFrom {
-            start: self.start * S,
-        }
+    fn unstride<const S: usize>(self, len: usize) -> Option<Self> {
+        Some(RangeFrom {
+            start: unstride_bound::<S>(self.start, len)?,
+        })
     }
 }
 
 impl Unstride for RangeFull {
-    fn unstride<const S: usize>(self) -> Self {
-        self
+    fn unstride<const S: usize>(self, _: usize) -> Option<Self> {
+        Some(self)
     }
 }
 
 impl Unstride for RangeInclusive<usize> {
-    fn unstride<const S: usize>(self) -> Self {
-        RangeInclusive::new(self.start() * S, self.end() * S)
+    fn unstride<const S: usize>(self, _: usize) -> Option<Self> {
+        Some(RangeInclusive::new(
+            self.start().checked_mul(S)?,
+            self.end().checked_mul(S)?,
+        ))
     }
 }
 
 impl Unstride for RangeTo<usize> {
-    fn unstride<const S: usize>(self) -> Self {
-        RangeTo { end: self.end * S }
+    fn unstride<const S: usize>(self, len: usize) -> Option<Self> {
+        Some(RangeTo {
+            end: unstride_bound::<S>(self.end, len)?,
+        })
     }
 }
 
 impl Unstride for RangeToInclusive<usize> {
-    fn unstride<const S: usize>(self) -> Self {
-        RangeToInclusive { end: self.end * S }
+    fn unstride<const S: usize>(self, _: usize) -> Option<Self> {
+        Some(RangeToInclusive {
+            end: self.end.checked_mul(S)?,
+        })
     }
 }
 
@@ -120,29 +241,34 @@ unsafe impl<T, const S: usize> StrideIndex<Stride<T, S>> for usize {
     type Output = T;
 
     fn get(self, stride: &Stride<T, S>) -> Option<&Self::Output> {
-        stride.data.get(self.unstride::<S>())
+        get_elem(&stride.data, self, S)
     }
 
     fn get_mut(self, stride: &mut Stride<T, S>) -> Option<&mut Self::Output> {
-        stride.data.get_mut(self.unstride::<S>())
+        get_elem_mut(&mut stride.data, self, S)
     }
 
     unsafe fn get_unchecked(self, stride: *const Stride<T, S>) -> *const Self::Output {
-        (*stride).data.get_unchecked(self.unstride::<S>())
+        let data = &(*stride).data;
+        // An in bounds index can't overflow.
+        let index = self.unstride::<S>(data.len()).unwrap_unchecked();
+        data.get_unchecked(index)
     }
 
     unsafe fn get_unchecked_mut(self, stride: *mut Stride<T, S>) -> *mut Self::Output {
-        (*stride).data.get_unchecked_mut(self.unstride::<S>())
+        let data = &mut (*stride).data;
+        let index = self.unstride::<S>(data.len()).unwrap_unchecked();
+        data.get_unchecked_mut(index)
     }
 
     #[track_caller]
     fn index(self, stride: &Stride<T, S>) -> &Self::Output {
-        &stride.data[self.unstride::<S>()]
+        index_elem(&stride.data, self, S)
     }
 
     #[track_caller]
     fn index_mut(self, stride: &mut Stride<T, S>) -> &mut Self::Output {
-        &mut stride.data[self.unstride::<S>()]
+        index_elem_mut(&mut stride.data, self, S)
     }
 }
 
@@ -152,32 +278,42 @@ macro_rules! impl_stride_index {
             type Output = Stride<T, S>;
 
             fn get(self, stride: &Stride<T, S>) -> Option<&Self::Output> {
-                stride.data.get(self.unstride::<S>()).map(Stride::new)
+                let index = self.unstride::<S>(stride.data.len())?;
+                stride.data.get(index).map(Stride::new)
             }
 
             fn get_mut(self, stride: &mut Stride<T, S>) -> Option<&mut Self::Output> {
-                stride
-                    .data
-                    .get_mut(self.unstride::<S>())
-                    .map(Stride::new_mut)
+                let index = self.unstride::<S>(stride.data.len())?;
+                stride.data.get_mut(index).map(Stride::new_mut)
             }
 
             unsafe fn get_unchecked(self, stride: *const Stride<T, S>) -> *const Self::Output {
-                Stride::new((*stride).data.get_unchecked(self.unstride::<S>()))
+                let data = &(*stride).data;
+                // An in bounds range can't overflow.
+                let index = self.unstride::<S>(data.len()).unwrap_unchecked();
+                Stride::new(data.get_unchecked(index))
             }
 
             unsafe fn get_unchecked_mut(self, stride: *mut Stride<T, S>) -> *mut Self::Output {
-                Stride::new_mut((*stride).data.get_unchecked_mut(self.unstride::<S>()))
+                let data = &mut (*stride).data;
+                let index = self.unstride::<S>(data.len()).unwrap_unchecked();
+                Stride::new_mut(data.get_unchecked_mut(index))
             }
 
             #[track_caller]
             fn index(self, stride: &Stride<T, S>) -> &Self::Output {
-                Stride::new(&stride.data[self.unstride::<S>()])
+                // Check the bounds first so that the panic message is in terms of
+                // the strided slice instead of the underlying slice.
+                let Range { start, end } = range::<_, S>(self, stride.len());
+                // SAFETY: the range was checked to be in bounds.
+                unsafe { &*(start..end).get_unchecked(stride) }
             }
 
             #[track_caller]
             fn index_mut(self, stride: &mut Stride<T, S>) -> &mut Self::Output {
-                Stride::new_mut(&mut stride.data[self.unstride::<S>()])
+                let Range { start, end } = range::<_, S>(self, stride.len());
+                // SAFETY: the range was checked to be in bounds.
+                unsafe { &mut *(start..end).get_unchecked_mut(stride) }
             }
         }
     };
@@ -1,91 +1,2362 @@
+use core::array;
+use core::cmp;
+use core::fmt;
 use core::iter::*;
+use core::marker::PhantomData;
+use core::mem;
+use core::num::NonZeroUsize;
 use core::slice;
 
-use crate::Stride;
+use crate::index::strided_len;
+use crate::{DynStride, DynStrideMut, Stride};
 
 /// Immutable stride iterator.
 ///
 /// This struct is created by the [`iter()`][`Stride::iter()`] method on
 /// strided slices.
-#[derive(Debug, Clone)]
 pub struct Iter<'a, T, const S: usize> {
-    iter: StepBy<slice::Iter<'a, T>>,
+    /// Points to the next element, unless `len` is zero.
+    ptr: *const T,
+    /// The number of elements left to yield.
+    len: usize,
+    _marker: PhantomData<&'a T>,
 }
 
 /// Mutable stride iterator.
 ///
 /// This struct is created by the [`iter_mut()`][`Stride::iter_mut()`] method on
 /// strided slices.
-#[derive(Debug)]
 pub struct IterMut<'a, T, const S: usize> {
-    iter: StepBy<slice::IterMut<'a, T>>,
+    /// Points to the next element, unless `len` is zero.
+    ptr: *mut T,
+    /// The number of elements left to yield.
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Sync, const S: usize> Send for Iter<'_, T, S> {}
+unsafe impl<T: Sync, const S: usize> Sync for Iter<'_, T, S> {}
+unsafe impl<T: Send, const S: usize> Send for IterMut<'_, T, S> {}
+unsafe impl<T: Sync, const S: usize> Sync for IterMut<'_, T, S> {}
+
+/// Returns the length of the shortest underlying slice that contains `len`
+/// strided elements.
+///
+/// `len` must not be zero.
+fn raw_len(len: usize, stride: usize) -> usize {
+    (len - 1) * stride + 1
+}
+
+impl<T, const S: usize> Clone for Iter<'_, T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const S: usize> Default for Iter<'_, T, S> {
+    /// Creates an empty stride iterator.
+    fn default() -> Self {
+        <&Stride<T, S>>::default().iter()
+    }
+}
+
+impl<T, const S: usize> Default for IterMut<'_, T, S> {
+    /// Creates an empty stride iterator.
+    fn default() -> Self {
+        <&mut Stride<T, S>>::default().iter_mut()
+    }
+}
+
+impl<'a, T, const S: usize> Iter<'a, T, S> {
+    /// Views the underlying data as a strided subslice of the original data.
+    ///
+    /// This has the same lifetime as the original strided slice, and so the
+    /// iterator can continue to be used while this exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let mut iter = stride.iter();
+    /// assert_eq!(iter.as_stride(), &[1, 3, 5]);
+    /// iter.next();
+    /// assert_eq!(iter.as_stride(), &[3, 5]);
+    /// ```
+    pub fn as_stride(&self) -> &'a Stride<T, S> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return Default::default();
+        }
+        // SAFETY: the remaining elements are all within the underlying slice.
+        Stride::new(unsafe { slice::from_raw_parts(self.ptr, raw_len(self.len, S)) })
+    }
 }
 
+impl<T, const S: usize> AsRef<Stride<T, S>> for Iter<'_, T, S> {
+    fn as_ref(&self) -> &Stride<T, S> {
+        self.as_stride()
+    }
+}
+
+impl<'a, T, const S: usize> IterMut<'a, T, S> {
+    /// Views the underlying data as a strided subslice of the original data.
+    ///
+    /// To avoid creating `&mut` references that alias, this returns a shared
+    /// reference which borrows the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let mut iter = stride.iter_mut();
+    /// iter.next();
+    /// assert_eq!(iter.as_stride(), &[3, 5]);
+    /// ```
+    pub fn as_stride(&self) -> &Stride<T, S> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return Default::default();
+        }
+        // SAFETY: the remaining elements are all within the underlying slice.
+        Stride::new(unsafe { slice::from_raw_parts(self.ptr, raw_len(self.len, S)) })
+    }
+
+    /// Views the underlying data as a mutable strided subslice of the original
+    /// data.
+    ///
+    /// This consumes the iterator so the returned strided slice has the same
+    /// lifetime as the original strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let mut iter = stride.iter_mut();
+    /// *iter.next().unwrap() = 7;
+    /// let rest = iter.into_stride();
+    /// rest[0] = 8;
+    /// assert_eq!(data, &[7, 2, 8, 4, 5, 6]);
+    /// ```
+    pub fn into_stride(self) -> &'a mut Stride<T, S> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return Default::default();
+        }
+        // SAFETY: the remaining elements are all within the underlying slice
+        // and the iterator has not yielded references to any of them.
+        Stride::new_mut(unsafe { slice::from_raw_parts_mut(self.ptr, raw_len(self.len, S)) })
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for Iter<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.as_stride()).finish()
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for IterMut<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut").field(&self.as_stride()).finish()
+    }
+}
+
+/// Implements the iterator traits for a type with `ptr` and `len` fields that
+/// yields every `step()`th element starting at `ptr`.
+///
+/// This is shared by the iterators over constant and runtime strides.
 macro_rules! impl_iterator {
     (
-        with $stride:ty, $meth:ident
+        with $stride:ty, $meth:ident, $as_ptr:ident, { $($mutability:tt)* }
         impl Iterator<Item = $item:ty> for $iter:ty
     ) => {
         impl<'a, T, const S: usize> $iter {
             pub(crate) fn new(stride: $stride) -> Self {
                 Self {
-                    iter: stride.data.$meth().step_by(S),
+                    len: stride.len(),
+                    ptr: stride.$as_ptr(),
+                    _marker: PhantomData,
                 }
             }
+
+            #[inline(always)]
+            fn step(&self) -> usize {
+                S
+            }
         }
 
-        impl<'a, T, const S: usize> Iterator for $iter {
+        impl_iterator! {
+            [<'a, T, const S: usize>] { $($mutability)* }
+            impl Iterator<Item = $item> for $iter
+        }
+
+        impl<'a, T, const S: usize> IntoIterator for $stride {
+            type Item = $item;
+            type IntoIter = $iter;
+
+            fn into_iter(self) -> $iter {
+                self.$meth()
+            }
+        }
+    };
+    (
+        [$($generics:tt)*] { $($mutability:tt)* }
+        impl Iterator<Item = $item:ty> for $iter:ty
+    ) => {
+        impl$($generics)* Iterator for $iter {
             type Item = $item;
 
+            #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                self.iter.next()
+                if self.len == 0 {
+                    return None;
+                }
+                let elem = self.ptr;
+                self.len -= 1;
+                // The pointer is only dereferenced while `len` is non-zero so
+                // it may step past the end of the underlying slice.
+                self.ptr = self.ptr.wrapping_add(self.step());
+                Some(unsafe { & $($mutability)* *elem })
             }
 
+            #[inline]
             fn size_hint(&self) -> (usize, Option<usize>) {
-                let len = self.len();
-                (len, Some(len))
+                (self.len, Some(self.len))
             }
 
+            #[inline]
             fn count(self) -> usize {
-                self.len()
+                self.len
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                if n >= self.len {
+                    self.len = 0;
+                    return None;
+                }
+                self.ptr = unsafe { self.ptr.add(n * self.step()) };
+                self.len -= n;
+                self.next()
             }
 
+            #[inline]
             fn last(mut self) -> Option<Self::Item> {
                 self.next_back()
             }
+
+            // The internal iteration methods are implemented with a counted
+            // loop instead of repeated calls to `next()` which lets LLVM unroll
+            // and vectorize them. `try_fold()` can't be overridden on stable so
+            // the common short-circuiting consumers are overridden instead.
+
+            #[inline]
+            fn fold<B, F>(self, init: B, mut f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                let mut acc = init;
+                for i in 0..self.len {
+                    acc = f(acc, unsafe { & $($mutability)* *self.ptr.add(i * self.step()) });
+                }
+                acc
+            }
+
+            #[inline]
+            fn for_each<F>(self, mut f: F)
+            where
+                F: FnMut(Self::Item),
+            {
+                self.fold((), move |(), elem| f(elem));
+            }
+
+            #[inline]
+            fn all<F>(&mut self, mut f: F) -> bool
+            where
+                F: FnMut(Self::Item) -> bool,
+            {
+                while let Some(elem) = self.next() {
+                    if !f(elem) {
+                        return false;
+                    }
+                }
+                true
+            }
+
+            #[inline]
+            fn any<F>(&mut self, mut f: F) -> bool
+            where
+                F: FnMut(Self::Item) -> bool,
+            {
+                while let Some(elem) = self.next() {
+                    if f(elem) {
+                        return true;
+                    }
+                }
+                false
+            }
+
+            #[inline]
+            fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+            where
+                P: FnMut(&Self::Item) -> bool,
+            {
+                while let Some(elem) = self.next() {
+                    if predicate(&elem) {
+                        return Some(elem);
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+            where
+                P: FnMut(Self::Item) -> bool,
+            {
+                let mut i = 0;
+                while let Some(elem) = self.next() {
+                    if predicate(elem) {
+                        return Some(i);
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            #[inline]
+            fn rposition<P>(&mut self, mut predicate: P) -> Option<usize>
+            where
+                P: FnMut(Self::Item) -> bool,
+            {
+                while let Some(elem) = self.next_back() {
+                    if predicate(elem) {
+                        return Some(self.len);
+                    }
+                }
+                None
+            }
         }
 
-        impl<'a, T, const S: usize> DoubleEndedIterator for $iter {
+        impl$($generics)* DoubleEndedIterator for $iter {
+            #[inline]
             fn next_back(&mut self) -> Option<Self::Item> {
-                self.iter.next_back()
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                Some(unsafe { & $($mutability)* *self.ptr.add(self.len * self.step()) })
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                if n >= self.len {
+                    self.len = 0;
+                    return None;
+                }
+                self.len -= n;
+                self.next_back()
+            }
+
+            #[inline]
+            fn rfold<B, F>(self, init: B, mut f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                let mut acc = init;
+                for i in (0..self.len).rev() {
+                    acc = f(acc, unsafe { & $($mutability)* *self.ptr.add(i * self.step()) });
+                }
+                acc
             }
         }
 
-        impl<'a, T, const S: usize> ExactSizeIterator for $iter {
+        impl$($generics)* ExactSizeIterator for $iter {
+            #[inline]
             fn len(&self) -> usize {
-                self.iter.len()
+                self.len
             }
         }
 
-        impl<'a, T, const S: usize> FusedIterator for $iter {}
-
-        impl<'a, T, const S: usize> IntoIterator for $stride {
-            type Item = $item;
-            type IntoIter = $iter;
+        impl$($generics)* FusedIterator for $iter {}
 
-            fn into_iter(self) -> $iter {
-                self.$meth()
-            }
-        }
+        #[cfg(feature = "nightly")]
+        unsafe impl$($generics)* TrustedLen for $iter {}
     };
 }
 
 impl_iterator! {
-    with &'a Stride<T, S>, iter
+    with &'a Stride<T, S>, iter, as_ptr, {}
     impl Iterator<Item = &'a T> for Iter<'a, T, S>
 }
 
 impl_iterator! {
-    with &'a mut Stride<T, S>, iter_mut
+    with &'a mut Stride<T, S>, iter_mut, as_mut_ptr, { mut }
     impl Iterator<Item = &'a mut T> for IterMut<'a, T, S>
 }
+
+/// Immutable iterator over a strided slice with a runtime stride.
+///
+/// This struct is created by the [`iter()`][`DynStride::iter()`] method on
+/// [`DynStride`].
+pub struct DynIter<'a, T> {
+    /// Points to the next element, unless `len` is zero.
+    ptr: *const T,
+    /// The number of elements left to yield.
+    len: usize,
+    stride: NonZeroUsize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Mutable iterator over a strided slice with a runtime stride.
+///
+/// This struct is created by the [`iter_mut()`][`DynStrideMut::iter_mut()`]
+/// method on [`DynStrideMut`].
+pub struct DynIterMut<'a, T> {
+    /// Points to the next element, unless `len` is zero.
+    ptr: *mut T,
+    /// The number of elements left to yield.
+    len: usize,
+    stride: NonZeroUsize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Sync> Send for DynIter<'_, T> {}
+unsafe impl<T: Sync> Sync for DynIter<'_, T> {}
+unsafe impl<T: Send> Send for DynIterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for DynIterMut<'_, T> {}
+
+impl<T> Clone for DynIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            len: self.len,
+            stride: self.stride,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DynIter<'_, T> {
+    /// Creates an empty stride iterator.
+    fn default() -> Self {
+        DynStride::default().iter()
+    }
+}
+
+impl<T> Default for DynIterMut<'_, T> {
+    /// Creates an empty stride iterator.
+    fn default() -> Self {
+        DynStrideMut::default().into_iter()
+    }
+}
+
+impl<'a, T> DynIter<'a, T> {
+    pub(crate) fn new(data: &'a [T], stride: NonZeroUsize) -> Self {
+        Self {
+            ptr: data.as_ptr(),
+            len: strided_len(data.len(), stride.get()),
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn step(&self) -> usize {
+        self.stride.get()
+    }
+
+    /// Views the underlying data as a strided subslice of the original data.
+    ///
+    /// This has the same lifetime as the original strided slice, and so the
+    /// iterator can continue to be used while this exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStride;
+    /// #
+    /// let stride = DynStride::new(&[1, 2, 3, 4, 5, 6], NonZeroUsize::new(2).unwrap());
+    /// let mut iter = stride.iter();
+    /// iter.next();
+    /// assert_eq!(iter.as_dyn_stride(), &[3, 5]);
+    /// ```
+    pub fn as_dyn_stride(&self) -> DynStride<'a, T> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return DynStride::new(&[], self.stride);
+        }
+        // SAFETY: the remaining elements are all within the underlying slice.
+        let data = unsafe { slice::from_raw_parts(self.ptr, raw_len(self.len, self.step())) };
+        DynStride::new(data, self.stride)
+    }
+}
+
+impl<'a, T> DynIterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], stride: NonZeroUsize) -> Self {
+        Self {
+            len: strided_len(data.len(), stride.get()),
+            ptr: data.as_mut_ptr(),
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn step(&self) -> usize {
+        self.stride.get()
+    }
+
+    /// Views the underlying data as a strided subslice of the original data.
+    ///
+    /// To avoid creating `&mut` references that alias, this returns a shared
+    /// strided slice which borrows the iterator.
+    pub fn as_dyn_stride(&self) -> DynStride<'_, T> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return DynStride::new(&[], self.stride);
+        }
+        // SAFETY: the remaining elements are all within the underlying slice.
+        let data = unsafe { slice::from_raw_parts(self.ptr, raw_len(self.len, self.step())) };
+        DynStride::new(data, self.stride)
+    }
+
+    /// Views the underlying data as a mutable strided subslice of the original
+    /// data.
+    ///
+    /// This consumes the iterator so the returned strided slice has the same
+    /// lifetime as the original strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use stride::DynStrideMut;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let mut stride = DynStrideMut::new(data, NonZeroUsize::new(2).unwrap());
+    /// let mut iter = stride.iter_mut();
+    /// *iter.next().unwrap() = 7;
+    /// let mut rest = iter.into_dyn_stride();
+    /// rest[0] = 8;
+    /// assert_eq!(data, &[7, 2, 8, 4, 5, 6]);
+    /// ```
+    pub fn into_dyn_stride(self) -> DynStrideMut<'a, T> {
+        if self.len == 0 {
+            // The pointer may have stepped past the end of the underlying slice.
+            return DynStrideMut::new(&mut [], self.stride);
+        }
+        // SAFETY: the remaining elements are all within the underlying slice
+        // and the iterator has not yielded references to any of them.
+        let data = unsafe { slice::from_raw_parts_mut(self.ptr, raw_len(self.len, self.step())) };
+        DynStrideMut::new(data, self.stride)
+    }
+}
+
+impl<T> fmt::Debug for DynIter<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynIter")
+            .field(&self.as_dyn_stride())
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for DynIterMut<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynIterMut")
+            .field(&self.as_dyn_stride())
+            .finish()
+    }
+}
+
+impl_iterator! {
+    [<'a, T>] {}
+    impl Iterator<Item = &'a T> for DynIter<'a, T>
+}
+
+impl_iterator! {
+    [<'a, T>] { mut }
+    impl Iterator<Item = &'a mut T> for DynIterMut<'a, T>
+}
+
+/// An iterator over the elements of a strided slice together with their
+/// indexes in the underlying slice.
+///
+/// This struct is created by the
+/// [`iter_with_raw_indices()`][`Stride::iter_with_raw_indices()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct IterWithRawIndices<'a, T, const S: usize> {
+    iter: Enumerate<Iter<'a, T, S>>,
+}
+
+impl<'a, T, const S: usize> IterWithRawIndices<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>) -> Self {
+        Self {
+            iter: stride.iter().enumerate(),
+        }
+    }
+}
+
+fn raw_indexed<T, const S: usize>((i, elem): (usize, T)) -> (usize, T) {
+    (i * S, elem)
+}
+
+impl<'a, T, const S: usize> Iterator for IterWithRawIndices<'a, T, S> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(raw_indexed::<_, S>)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(raw_indexed::<_, S>)
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for IterWithRawIndices<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(raw_indexed::<_, S>)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for IterWithRawIndices<'a, T, S> {}
+
+impl<'a, T, const S: usize> FusedIterator for IterWithRawIndices<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) chunks, starting at
+/// the beginning of the strided slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last chunk of the iteration will be the remainder.
+///
+/// This struct is created by the [`chunks()`][`Stride::chunks()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct Chunks<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> Chunks<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, chunk_size: usize) -> Self {
+        Self { stride, chunk_size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for Chunks<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let mid = cmp::min(self.stride.len(), self.chunk_size);
+        let (chunk, rest) = self.stride.split_at(mid);
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for Chunks<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let len = self.stride.len();
+        let size = match len % self.chunk_size {
+            0 => self.chunk_size,
+            rem => rem,
+        };
+        let (rest, chunk) = self.stride.split_at(len - size);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for Chunks<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.chunk_size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for Chunks<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) mutable chunks,
+/// starting at the beginning of the strided slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last chunk of the iteration will be the remainder.
+///
+/// This struct is created by the [`chunks_mut()`][`Stride::chunks_mut()`]
+/// method on strided slices.
+#[derive(Debug)]
+pub struct ChunksMut<'a, T, const S: usize> {
+    stride: &'a mut Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> ChunksMut<'a, T, S> {
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, chunk_size: usize) -> Self {
+        Self { stride, chunk_size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for ChunksMut<'a, T, S> {
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let mid = cmp::min(self.stride.len(), self.chunk_size);
+        let (chunk, rest) = mem::take(&mut self.stride).split_at_mut(mid);
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for ChunksMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let len = self.stride.len();
+        let size = match len % self.chunk_size {
+            0 => self.chunk_size,
+            rem => rem,
+        };
+        let (rest, chunk) = mem::take(&mut self.stride).split_at_mut(len - size);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for ChunksMut<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.chunk_size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for ChunksMut<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) chunks of exactly
+/// `chunk_size` elements, starting at the beginning of the strided slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last up to `chunk_size - 1` elements will be omitted but can be retrieved
+/// from the [`remainder()`][`ChunksExact::remainder()`] method.
+///
+/// This struct is created by the [`chunks_exact()`][`Stride::chunks_exact()`]
+/// method on strided slices.
+#[derive(Debug, Clone)]
+pub struct ChunksExact<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    rem: &'a Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> ChunksExact<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, chunk_size: usize) -> Self {
+        let rem_len = stride.len() % chunk_size;
+        let (stride, rem) = stride.split_at(stride.len() - rem_len);
+        Self {
+            stride,
+            rem,
+            chunk_size,
+        }
+    }
+
+    /// Returns the remainder of the original strided slice that is not going
+    /// to be returned by the iterator.
+    ///
+    /// The returned strided slice has at most `chunk_size - 1` elements.
+    pub fn remainder(&self) -> &'a Stride<T, S> {
+        self.rem
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for ChunksExact<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.len() < self.chunk_size {
+            return None;
+        }
+        let (chunk, rest) = self.stride.split_at(self.chunk_size);
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for ChunksExact<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.len() < self.chunk_size {
+            return None;
+        }
+        let (rest, chunk) = self.stride.split_at(self.stride.len() - self.chunk_size);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for ChunksExact<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len() / self.chunk_size
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for ChunksExact<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) mutable chunks of
+/// exactly `chunk_size` elements, starting at the beginning of the strided
+/// slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last up to `chunk_size - 1` elements will be omitted but can be retrieved
+/// from the [`into_remainder()`][`ChunksExactMut::into_remainder()`] method.
+///
+/// This struct is created by the
+/// [`chunks_exact_mut()`][`Stride::chunks_exact_mut()`] method on strided
+/// slices.
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, T, const S: usize> {
+    stride: &'a mut Stride<T, S>,
+    rem: &'a mut Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> ChunksExactMut<'a, T, S> {
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, chunk_size: usize) -> Self {
+        let rem_len = stride.len() % chunk_size;
+        let mid = stride.len() - rem_len;
+        let (stride, rem) = stride.split_at_mut(mid);
+        Self {
+            stride,
+            rem,
+            chunk_size,
+        }
+    }
+
+    /// Returns the remainder of the original strided slice that is not going
+    /// to be returned by the iterator.
+    ///
+    /// The returned strided slice has at most `chunk_size - 1` elements.
+    pub fn into_remainder(self) -> &'a mut Stride<T, S> {
+        self.rem
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for ChunksExactMut<'a, T, S> {
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.len() < self.chunk_size {
+            return None;
+        }
+        let (chunk, rest) = mem::take(&mut self.stride).split_at_mut(self.chunk_size);
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for ChunksExactMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.len() < self.chunk_size {
+            return None;
+        }
+        let mid = self.stride.len() - self.chunk_size;
+        let (rest, chunk) = mem::take(&mut self.stride).split_at_mut(mid);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for ChunksExactMut<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len() / self.chunk_size
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for ChunksExactMut<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) chunks, starting at
+/// the end of the strided slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last chunk of the iteration will be the remainder.
+///
+/// This struct is created by the [`rchunks()`][`Stride::rchunks()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct RChunks<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> RChunks<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, chunk_size: usize) -> Self {
+        Self { stride, chunk_size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for RChunks<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let len = self.stride.len();
+        let (rest, chunk) = self.stride.split_at(len - cmp::min(len, self.chunk_size));
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for RChunks<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let size = match self.stride.len() % self.chunk_size {
+            0 => self.chunk_size,
+            rem => rem,
+        };
+        let (chunk, rest) = self.stride.split_at(size);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for RChunks<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.chunk_size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for RChunks<'a, T, S> {}
+
+/// An iterator over a strided slice in (non-overlapping) mutable chunks,
+/// starting at the end of the strided slice.
+///
+/// When the strided slice length is not evenly divided by the chunk size, the
+/// last chunk of the iteration will be the remainder.
+///
+/// This struct is created by the [`rchunks_mut()`][`Stride::rchunks_mut()`]
+/// method on strided slices.
+#[derive(Debug)]
+pub struct RChunksMut<'a, T, const S: usize> {
+    stride: &'a mut Stride<T, S>,
+    chunk_size: usize,
+}
+
+impl<'a, T, const S: usize> RChunksMut<'a, T, S> {
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, chunk_size: usize) -> Self {
+        Self { stride, chunk_size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for RChunksMut<'a, T, S> {
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let len = self.stride.len();
+        let mid = len - cmp::min(len, self.chunk_size);
+        let (rest, chunk) = mem::take(&mut self.stride).split_at_mut(mid);
+        self.stride = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for RChunksMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let size = match self.stride.len() % self.chunk_size {
+            0 => self.chunk_size,
+            rem => rem,
+        };
+        let (chunk, rest) = mem::take(&mut self.stride).split_at_mut(size);
+        self.stride = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for RChunksMut<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.chunk_size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for RChunksMut<'a, T, S> {}
+
+/// An iterator over a strided slice in `n` (non-overlapping) parts of roughly
+/// equal length.
+///
+/// The lengths of the parts differ by at most one, with the longer parts
+/// first.
+///
+/// This struct is created by the [`split_into()`][`Stride::split_into()`]
+/// method on strided slices.
+#[derive(Debug, Clone)]
+pub struct SplitInto<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    parts: usize,
+}
+
+impl<'a, T, const S: usize> SplitInto<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, parts: usize) -> Self {
+        Self { stride, parts }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for SplitInto<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parts == 0 {
+            return None;
+        }
+        let mid = self.stride.len().div_ceil(self.parts);
+        let (part, rest) = self.stride.split_at(mid);
+        self.stride = rest;
+        self.parts -= 1;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parts, Some(self.parts))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for SplitInto<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.parts == 0 {
+            return None;
+        }
+        let len = self.stride.len();
+        let (rest, part) = self.stride.split_at(len - len / self.parts);
+        self.stride = rest;
+        self.parts -= 1;
+        Some(part)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for SplitInto<'a, T, S> {}
+
+impl<'a, T, const S: usize> FusedIterator for SplitInto<'a, T, S> {}
+
+/// An iterator over a strided slice in `n` (non-overlapping) mutable parts of
+/// roughly equal length.
+///
+/// The lengths of the parts differ by at most one, with the longer parts
+/// first.
+///
+/// This struct is created by the
+/// [`split_into_mut()`][`Stride::split_into_mut()`] method on strided slices.
+#[derive(Debug)]
+pub struct SplitIntoMut<'a, T, const S: usize> {
+    stride: &'a mut Stride<T, S>,
+    parts: usize,
+}
+
+impl<'a, T, const S: usize> SplitIntoMut<'a, T, S> {
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, parts: usize) -> Self {
+        Self { stride, parts }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for SplitIntoMut<'a, T, S> {
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parts == 0 {
+            return None;
+        }
+        let mid = self.stride.len().div_ceil(self.parts);
+        let (part, rest) = mem::take(&mut self.stride).split_at_mut(mid);
+        self.stride = rest;
+        self.parts -= 1;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parts, Some(self.parts))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for SplitIntoMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.parts == 0 {
+            return None;
+        }
+        let len = self.stride.len();
+        let (rest, part) = mem::take(&mut self.stride).split_at_mut(len - len / self.parts);
+        self.stride = rest;
+        self.parts -= 1;
+        Some(part)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for SplitIntoMut<'a, T, S> {}
+
+impl<'a, T, const S: usize> FusedIterator for SplitIntoMut<'a, T, S> {}
+
+/// An iterator over overlapping strided subslices of length `size`.
+///
+/// This struct is created by the [`windows()`][`Stride::windows()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct Windows<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    size: usize,
+}
+
+impl<'a, T, const S: usize> Windows<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, size: usize) -> Self {
+        Self { stride, size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for Windows<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size > self.stride.len() {
+            return None;
+        }
+        let window = &self.stride[..self.size];
+        self.stride = &self.stride[1..];
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for Windows<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.stride.len();
+        if self.size > len {
+            return None;
+        }
+        let window = &self.stride[len - self.size..];
+        self.stride = &self.stride[..len - 1];
+        Some(window)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for Windows<'a, T, S> {
+    fn len(&self) -> usize {
+        (self.stride.len() + 1).saturating_sub(self.size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for Windows<'a, T, S> {}
+
+/// An iterator over subslices of a strided slice separated by elements that
+/// match a predicate function.
+///
+/// This struct is created by the [`split()`][`Stride::split()`] method on
+/// strided slices.
+pub struct Split<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    stride: &'a Stride<T, S>,
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P, const S: usize> Split<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(stride: &'a Stride<T, S>, pred: P) -> Self {
+        Self {
+            stride,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+impl<T, P, const S: usize> fmt::Debug for Split<'_, T, P, S>
+where
+    T: fmt::Debug,
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Split")
+            .field("stride", &self.stride)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<T, P, const S: usize> Clone for Split<'_, T, P, S>
+where
+    P: Clone + FnMut(&T) -> bool,
+{
+    fn clone(&self) -> Self {
+        Self {
+            stride: self.stride,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> Iterator for Split<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.stride.iter().position(&mut self.pred) {
+            None => {
+                self.finished = true;
+                Some(self.stride)
+            }
+            Some(idx) => {
+                let head = &self.stride[..idx];
+                self.stride = &self.stride[idx + 1..];
+                Some(head)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.stride.len() + 1))
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> DoubleEndedIterator for Split<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.stride.iter().rposition(&mut self.pred) {
+            None => {
+                self.finished = true;
+                Some(self.stride)
+            }
+            Some(idx) => {
+                let tail = &self.stride[idx + 1..];
+                self.stride = &self.stride[..idx];
+                Some(tail)
+            }
+        }
+    }
+}
+
+impl<T, P, const S: usize> FusedIterator for Split<'_, T, P, S> where P: FnMut(&T) -> bool {}
+
+/// An iterator over mutable subslices of a strided slice separated by elements
+/// that match a predicate function.
+///
+/// This struct is created by the [`split_mut()`][`Stride::split_mut()`] method
+/// on strided slices.
+pub struct SplitMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    stride: &'a mut Stride<T, S>,
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P, const S: usize> SplitMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, pred: P) -> Self {
+        Self {
+            stride,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+impl<T, P, const S: usize> fmt::Debug for SplitMut<'_, T, P, S>
+where
+    T: fmt::Debug,
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitMut")
+            .field("stride", &self.stride)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T, P, const S: usize> Iterator for SplitMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.stride.iter().position(&mut self.pred) {
+            None => {
+                self.finished = true;
+                Some(mem::take(&mut self.stride))
+            }
+            Some(idx) => {
+                let (head, tail) = mem::take(&mut self.stride).split_at_mut(idx);
+                self.stride = &mut tail[1..];
+                Some(head)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.stride.len() + 1))
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> DoubleEndedIterator for SplitMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.stride.iter().rposition(&mut self.pred) {
+            None => {
+                self.finished = true;
+                Some(mem::take(&mut self.stride))
+            }
+            Some(idx) => {
+                let (head, tail) = mem::take(&mut self.stride).split_at_mut(idx);
+                self.stride = head;
+                Some(&mut tail[1..])
+            }
+        }
+    }
+}
+
+impl<T, P, const S: usize> FusedIterator for SplitMut<'_, T, P, S> where P: FnMut(&T) -> bool {}
+
+/// An iterator over subslices of a strided slice separated by elements that
+/// match a predicate function. Unlike [`Split`], it contains the matched part
+/// as a terminator of the subslice.
+///
+/// This struct is created by the
+/// [`split_inclusive()`][`Stride::split_inclusive()`] method on strided
+/// slices.
+pub struct SplitInclusive<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    stride: &'a Stride<T, S>,
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P, const S: usize> SplitInclusive<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(stride: &'a Stride<T, S>, pred: P) -> Self {
+        Self {
+            finished: stride.is_empty(),
+            stride,
+            pred,
+        }
+    }
+}
+
+impl<T, P, const S: usize> fmt::Debug for SplitInclusive<'_, T, P, S>
+where
+    T: fmt::Debug,
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("stride", &self.stride)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<T, P, const S: usize> Clone for SplitInclusive<'_, T, P, S>
+where
+    P: Clone + FnMut(&T) -> bool,
+{
+    fn clone(&self) -> Self {
+        Self {
+            stride: self.stride,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> Iterator for SplitInclusive<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let idx = match self.stride.iter().position(&mut self.pred) {
+            Some(idx) => idx + 1,
+            None => self.stride.len(),
+        };
+        if idx == self.stride.len() {
+            self.finished = true;
+        }
+        let (head, tail) = self.stride.split_at(idx);
+        self.stride = tail;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(cmp::max(1, self.stride.len())))
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> DoubleEndedIterator for SplitInclusive<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // The last element is the terminator of the last subslice, so it is
+        // skipped when searching for the start of that subslice.
+        let (rest, _) = self.stride.split_at(self.stride.len() - 1);
+        let idx = match rest.iter().rposition(&mut self.pred) {
+            Some(idx) => idx + 1,
+            None => 0,
+        };
+        if idx == 0 {
+            self.finished = true;
+        }
+        let (head, tail) = self.stride.split_at(idx);
+        self.stride = head;
+        Some(tail)
+    }
+}
+
+impl<T, P, const S: usize> FusedIterator for SplitInclusive<'_, T, P, S> where P: FnMut(&T) -> bool {}
+
+/// An iterator over mutable subslices of a strided slice separated by elements
+/// that match a predicate function. Unlike [`SplitMut`], it contains the
+/// matched part as a terminator of the subslice.
+///
+/// This struct is created by the
+/// [`split_inclusive_mut()`][`Stride::split_inclusive_mut()`] method on
+/// strided slices.
+pub struct SplitInclusiveMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    stride: &'a mut Stride<T, S>,
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P, const S: usize> SplitInclusiveMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, pred: P) -> Self {
+        Self {
+            finished: stride.is_empty(),
+            stride,
+            pred,
+        }
+    }
+}
+
+impl<T, P, const S: usize> fmt::Debug for SplitInclusiveMut<'_, T, P, S>
+where
+    T: fmt::Debug,
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveMut")
+            .field("stride", &self.stride)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T, P, const S: usize> Iterator for SplitInclusiveMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a mut Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let idx = match self.stride.iter().position(&mut self.pred) {
+            Some(idx) => idx + 1,
+            None => self.stride.len(),
+        };
+        if idx == self.stride.len() {
+            self.finished = true;
+        }
+        let (head, tail) = mem::take(&mut self.stride).split_at_mut(idx);
+        self.stride = tail;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(cmp::max(1, self.stride.len())))
+        }
+    }
+}
+
+impl<'a, T, P, const S: usize> DoubleEndedIterator for SplitInclusiveMut<'a, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // The last element is the terminator of the last subslice, so it is
+        // skipped when searching for the start of that subslice.
+        let (rest, _) = self.stride.split_at(self.stride.len() - 1);
+        let idx = match rest.iter().rposition(&mut self.pred) {
+            Some(idx) => idx + 1,
+            None => 0,
+        };
+        if idx == 0 {
+            self.finished = true;
+        }
+        let (head, tail) = mem::take(&mut self.stride).split_at_mut(idx);
+        self.stride = head;
+        Some(tail)
+    }
+}
+
+impl<T, P, const S: usize> FusedIterator for SplitInclusiveMut<'_, T, P, S> where
+    P: FnMut(&T) -> bool
+{
+}
+
+/// An internal abstraction over the splitting iterators, so that the count
+/// limited versions only have to be implemented once.
+trait SplitIter: DoubleEndedIterator {
+    /// Marks the underlying iterator as complete, returning the remainder of
+    /// the strided slice.
+    fn finish(&mut self) -> Option<Self::Item>;
+}
+
+impl<T, P, const S: usize> SplitIter for Split<'_, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn finish(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.stride)
+        }
+    }
+}
+
+impl<T, P, const S: usize> SplitIter for SplitMut<'_, T, P, S>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn finish(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(mem::take(&mut self.stride))
+        }
+    }
+}
+
+/// An iterator over subslices of a strided slice separated by elements that
+/// match a predicate function, starting from the end of the strided slice.
+///
+/// This struct is created by the [`rsplit()`][`Stride::rsplit()`] method on
+/// strided slices.
+pub struct RSplit<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: Split<'a, T, P, S>,
+}
+
+/// An iterator over mutable subslices of a strided slice separated by elements
+/// that match a predicate function, starting from the end of the strided
+/// slice.
+///
+/// This struct is created by the [`rsplit_mut()`][`Stride::rsplit_mut()`]
+/// method on strided slices.
+pub struct RSplitMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: SplitMut<'a, T, P, S>,
+}
+
+impl<T, P, const S: usize> Clone for RSplit<'_, T, P, S>
+where
+    P: Clone + FnMut(&T) -> bool,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+macro_rules! impl_rsplit {
+    (
+        with $split:ident
+        impl Iterator<Item = $item:ty> for $iter:ident
+    ) => {
+        impl<'a, T, P, const S: usize> $iter<'a, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            pub(crate) fn new(inner: $split<'a, T, P, S>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T, P, const S: usize> fmt::Debug for $iter<'_, T, P, S>
+        where
+            T: fmt::Debug,
+            P: FnMut(&T) -> bool,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($iter))
+                    .field("inner", &self.inner)
+                    .finish()
+            }
+        }
+
+        impl<'a, T, P, const S: usize> Iterator for $iter<'a, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<'a, T, P, const S: usize> DoubleEndedIterator for $iter<'a, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+
+        impl<T, P, const S: usize> SplitIter for $iter<'_, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            fn finish(&mut self) -> Option<Self::Item> {
+                self.inner.finish()
+            }
+        }
+
+        impl<T, P, const S: usize> FusedIterator for $iter<'_, T, P, S> where P: FnMut(&T) -> bool {}
+    };
+}
+
+impl_rsplit! {
+    with Split
+    impl Iterator<Item = &'a Stride<T, S>> for RSplit
+}
+
+impl_rsplit! {
+    with SplitMut
+    impl Iterator<Item = &'a mut Stride<T, S>> for RSplitMut
+}
+
+/// An iterator over the first `count` items of a splitting iterator, where the
+/// last item is the remainder of the strided slice.
+#[derive(Debug, Clone)]
+struct GenericSplitN<I> {
+    iter: I,
+    count: usize,
+}
+
+impl<I: SplitIter> Iterator for GenericSplitN<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count -= 1;
+                self.iter.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.iter.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let count = self.count;
+        let upper = upper.map(|upper| cmp::min(count, upper));
+        (cmp::min(count, lower), upper.or(Some(count)))
+    }
+}
+
+/// An iterator over subslices of a strided slice separated by elements that
+/// match a predicate function, limited to a given number of splits.
+///
+/// This struct is created by the [`splitn()`][`Stride::splitn()`] method on
+/// strided slices.
+pub struct SplitN<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: GenericSplitN<Split<'a, T, P, S>>,
+}
+
+/// An iterator over mutable subslices of a strided slice separated by elements
+/// that match a predicate function, limited to a given number of splits.
+///
+/// This struct is created by the [`splitn_mut()`][`Stride::splitn_mut()`]
+/// method on strided slices.
+pub struct SplitNMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: GenericSplitN<SplitMut<'a, T, P, S>>,
+}
+
+/// An iterator over subslices of a strided slice separated by elements that
+/// match a predicate function, limited to a given number of splits, starting
+/// from the end of the strided slice.
+///
+/// This struct is created by the [`rsplitn()`][`Stride::rsplitn()`] method on
+/// strided slices.
+pub struct RSplitN<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: GenericSplitN<RSplit<'a, T, P, S>>,
+}
+
+/// An iterator over mutable subslices of a strided slice separated by elements
+/// that match a predicate function, limited to a given number of splits,
+/// starting from the end of the strided slice.
+///
+/// This struct is created by the [`rsplitn_mut()`][`Stride::rsplitn_mut()`]
+/// method on strided slices.
+pub struct RSplitNMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: GenericSplitN<RSplitMut<'a, T, P, S>>,
+}
+
+macro_rules! impl_splitn {
+    (
+        with $split:ident
+        impl Iterator<Item = $item:ty> for $iter:ident
+    ) => {
+        impl<'a, T, P, const S: usize> $iter<'a, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            pub(crate) fn new(iter: $split<'a, T, P, S>, count: usize) -> Self {
+                Self {
+                    inner: GenericSplitN { iter, count },
+                }
+            }
+        }
+
+        impl<T, P, const S: usize> fmt::Debug for $iter<'_, T, P, S>
+        where
+            T: fmt::Debug,
+            P: FnMut(&T) -> bool,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($iter))
+                    .field("inner", &self.inner)
+                    .finish()
+            }
+        }
+
+        impl<'a, T, P, const S: usize> Iterator for $iter<'a, T, P, S>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<T, P, const S: usize> FusedIterator for $iter<'_, T, P, S> where P: FnMut(&T) -> bool {}
+    };
+}
+
+impl_splitn! {
+    with Split
+    impl Iterator<Item = &'a Stride<T, S>> for SplitN
+}
+
+impl_splitn! {
+    with SplitMut
+    impl Iterator<Item = &'a mut Stride<T, S>> for SplitNMut
+}
+
+impl_splitn! {
+    with RSplit
+    impl Iterator<Item = &'a Stride<T, S>> for RSplitN
+}
+
+impl_splitn! {
+    with RSplitMut
+    impl Iterator<Item = &'a mut Stride<T, S>> for RSplitNMut
+}
+
+/// An iterator over a strided slice in (non-overlapping) chunks separated by a
+/// predicate.
+///
+/// This struct is created by the [`chunk_by()`][`Stride::chunk_by()`] method
+/// on strided slices.
+pub struct ChunkBy<'a, T, P, const S: usize>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    stride: &'a Stride<T, S>,
+    pred: P,
+}
+
+impl<'a, T, P, const S: usize> ChunkBy<'a, T, P, S>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    pub(crate) fn new(stride: &'a Stride<T, S>, pred: P) -> Self {
+        Self { stride, pred }
+    }
+}
+
+/// An iterator over a strided slice in (non-overlapping) mutable chunks
+/// separated by a predicate.
+///
+/// This struct is created by the [`chunk_by_mut()`][`Stride::chunk_by_mut()`]
+/// method on strided slices.
+pub struct ChunkByMut<'a, T, P, const S: usize>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    stride: &'a mut Stride<T, S>,
+    pred: P,
+}
+
+impl<'a, T, P, const S: usize> ChunkByMut<'a, T, P, S>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    pub(crate) fn new(stride: &'a mut Stride<T, S>, pred: P) -> Self {
+        Self { stride, pred }
+    }
+}
+
+/// Returns the length of the first run of `stride` where each pair of
+/// consecutive elements satisfies `pred`.
+fn run_len<T, P, const S: usize>(stride: &Stride<T, S>, pred: &mut P) -> usize
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    let mut len = 1;
+    while len < stride.len() && pred(&stride[len - 1], &stride[len]) {
+        len += 1;
+    }
+    len
+}
+
+/// Returns the length of the last run of `stride` where each pair of
+/// consecutive elements satisfies `pred`.
+fn rrun_len<T, P, const S: usize>(stride: &Stride<T, S>, pred: &mut P) -> usize
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    let mut len = 1;
+    while len < stride.len() {
+        let i = stride.len() - len;
+        if !pred(&stride[i - 1], &stride[i]) {
+            break;
+        }
+        len += 1;
+    }
+    len
+}
+
+macro_rules! impl_chunk_by {
+    (
+        impl Iterator<Item = $item:ty> for $iter:ident
+        $split_at:ident
+    ) => {
+        impl<T, P, const S: usize> fmt::Debug for $iter<'_, T, P, S>
+        where
+            T: fmt::Debug,
+            P: FnMut(&T, &T) -> bool,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($iter))
+                    .field("stride", &self.stride)
+                    .finish()
+            }
+        }
+
+        impl<'a, T, P, const S: usize> Iterator for $iter<'a, T, P, S>
+        where
+            P: FnMut(&T, &T) -> bool,
+        {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.stride.is_empty() {
+                    return None;
+                }
+                let len = run_len(self.stride, &mut self.pred);
+                let (head, tail) = mem::take(&mut self.stride).$split_at(len);
+                self.stride = tail;
+                Some(head)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.stride.is_empty() {
+                    (0, Some(0))
+                } else {
+                    (1, Some(self.stride.len()))
+                }
+            }
+        }
+
+        impl<'a, T, P, const S: usize> DoubleEndedIterator for $iter<'a, T, P, S>
+        where
+            P: FnMut(&T, &T) -> bool,
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.stride.is_empty() {
+                    return None;
+                }
+                let len = rrun_len(self.stride, &mut self.pred);
+                let mid = self.stride.len() - len;
+                let (head, tail) = mem::take(&mut self.stride).$split_at(mid);
+                self.stride = head;
+                Some(tail)
+            }
+        }
+
+        impl<T, P, const S: usize> FusedIterator for $iter<'_, T, P, S> where
+            P: FnMut(&T, &T) -> bool
+        {
+        }
+    };
+}
+
+impl_chunk_by! {
+    impl Iterator<Item = &'a Stride<T, S>> for ChunkBy
+    split_at
+}
+
+impl_chunk_by! {
+    impl Iterator<Item = &'a mut Stride<T, S>> for ChunkByMut
+    split_at_mut
+}
+
+impl<T, P, const S: usize> Clone for ChunkBy<'_, T, P, S>
+where
+    P: Clone + FnMut(&T, &T) -> bool,
+{
+    fn clone(&self) -> Self {
+        Self {
+            stride: self.stride,
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+/// An iterator over a strided slice in (non-overlapping) chunks of exactly `N`
+/// elements, returned as arrays of references, starting at the beginning of the
+/// strided slice.
+///
+/// When the strided slice length is not evenly divided by `N`, the last up to
+/// `N - 1` elements will be omitted but can be retrieved from the
+/// [`remainder()`][`ArrayChunks::remainder()`] method.
+///
+/// This struct is created by the [`array_chunks()`][`Stride::array_chunks()`]
+/// method on strided slices.
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<'a, T, const S: usize, const N: usize> {
+    iter: ChunksExact<'a, T, S>,
+}
+
+impl<'a, T, const S: usize, const N: usize> ArrayChunks<'a, T, S, N> {
+    pub(crate) fn new(stride: &'a Stride<T, S>) -> Self {
+        Self {
+            iter: ChunksExact::new(stride, N),
+        }
+    }
+
+    /// Returns the remainder of the original strided slice that is not going
+    /// to be returned by the iterator.
+    ///
+    /// The returned strided slice has at most `N - 1` elements.
+    pub fn remainder(&self) -> &'a Stride<T, S> {
+        self.iter.remainder()
+    }
+
+    fn to_array(chunk: &'a Stride<T, S>) -> [&'a T; N] {
+        array::from_fn(|i| &chunk[i])
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> Iterator for ArrayChunks<'a, T, S, N> {
+    type Item = [&'a T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Self::to_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(Self::to_array)
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> DoubleEndedIterator for ArrayChunks<'a, T, S, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Self::to_array)
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> ExactSizeIterator for ArrayChunks<'a, T, S, N> {}
+
+impl<'a, T, const S: usize, const N: usize> FusedIterator for ArrayChunks<'a, T, S, N> {}
+
+/// An iterator over a strided slice in (non-overlapping) mutable chunks of
+/// exactly `N` elements, returned as arrays of mutable references, starting at
+/// the beginning of the strided slice.
+///
+/// When the strided slice length is not evenly divided by `N`, the last up to
+/// `N - 1` elements will be omitted but can be retrieved from the
+/// [`into_remainder()`][`ArrayChunksMut::into_remainder()`] method.
+///
+/// This struct is created by the
+/// [`array_chunks_mut()`][`Stride::array_chunks_mut()`] method on strided
+/// slices.
+#[derive(Debug)]
+pub struct ArrayChunksMut<'a, T, const S: usize, const N: usize> {
+    iter: ChunksExactMut<'a, T, S>,
+}
+
+impl<'a, T, const S: usize, const N: usize> ArrayChunksMut<'a, T, S, N> {
+    pub(crate) fn new(stride: &'a mut Stride<T, S>) -> Self {
+        Self {
+            iter: ChunksExactMut::new(stride, N),
+        }
+    }
+
+    /// Returns the remainder of the original strided slice that is not going
+    /// to be returned by the iterator.
+    ///
+    /// The returned strided slice has at most `N - 1` elements.
+    pub fn into_remainder(self) -> &'a mut Stride<T, S> {
+        self.iter.into_remainder()
+    }
+
+    fn to_array(chunk: &'a mut Stride<T, S>) -> [&'a mut T; N] {
+        let ptr = chunk.as_mut_ptr();
+        // SAFETY: `chunk` has exactly `N` elements, each at a distinct offset
+        // that is a multiple of `S`, so the references are in bounds and do not
+        // alias.
+        array::from_fn(|i| unsafe { &mut *ptr.add(i * S) })
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> Iterator for ArrayChunksMut<'a, T, S, N> {
+    type Item = [&'a mut T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Self::to_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(Self::to_array)
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> DoubleEndedIterator for ArrayChunksMut<'a, T, S, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Self::to_array)
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> ExactSizeIterator for ArrayChunksMut<'a, T, S, N> {}
+
+impl<'a, T, const S: usize, const N: usize> FusedIterator for ArrayChunksMut<'a, T, S, N> {}
+
+/// Formats the remaining elements of a stride iterator as a list without
+/// viewing them as a strided slice, which lanes can't do.
+struct LaneElements<'a, T, const S: usize>(Iter<'a, T, S>);
+
+impl<T, const S: usize> fmt::Debug for LaneElements<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+/// Immutable iterator over a lane.
+///
+/// This struct is created by the [`iter()`][`crate::LaneMut::iter()`] method
+/// on lanes.
+pub struct LaneIter<'a, T, const S: usize>(Iter<'a, T, S>);
+
+/// Mutable iterator over a lane.
+///
+/// This struct is created by the [`iter_mut()`][`crate::LaneMut::iter_mut()`]
+/// method on lanes.
+pub struct LaneIterMut<'a, T, const S: usize>(IterMut<'a, T, S>);
+
+impl<T, const S: usize> LaneIter<'_, T, S> {
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `len` elements spaced `S` apart for
+    /// the lifetime of the iterator.
+    pub(crate) unsafe fn new(ptr: *const T, len: usize) -> Self {
+        Self(Iter {
+            ptr,
+            len,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T, const S: usize> LaneIterMut<'_, T, S> {
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` elements spaced `S`
+    /// apart for the lifetime of the iterator, and they must not be accessed
+    /// through any other pointer in the meantime.
+    pub(crate) unsafe fn new(ptr: *mut T, len: usize) -> Self {
+        Self(IterMut {
+            ptr,
+            len,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T, const S: usize> Clone for LaneIter<'_, T, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for LaneIter<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LaneIter")
+            .field(&LaneElements(self.0.clone()))
+            .finish()
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for LaneIterMut<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: the remaining elements are only read while `self` is
+        // borrowed.
+        let iter = unsafe { LaneIter::<T, S>::new(self.0.ptr, self.0.len) };
+        f.debug_tuple("LaneIterMut")
+            .field(&LaneElements(iter.0))
+            .finish()
+    }
+}
+
+macro_rules! impl_lane_iterator {
+    (impl Iterator<Item = $item:ty> for $iter:ty) => {
+        impl<'a, T, const S: usize> Iterator for $iter {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+
+            #[inline]
+            fn count(self) -> usize {
+                self.0.count()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.0.nth(n)
+            }
+
+            #[inline]
+            fn last(self) -> Option<Self::Item> {
+                self.0.last()
+            }
+        }
+
+        impl<'a, T, const S: usize> DoubleEndedIterator for $iter {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back()
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.0.nth_back(n)
+            }
+        }
+
+        impl<'a, T, const S: usize> ExactSizeIterator for $iter {}
+
+        impl<'a, T, const S: usize> FusedIterator for $iter {}
+
+        #[cfg(feature = "nightly")]
+        unsafe impl<'a, T, const S: usize> TrustedLen for $iter {}
+    };
+}
+
+impl_lane_iterator! { impl Iterator<Item = &'a T> for LaneIter<'a, T, S> }
+impl_lane_iterator! { impl Iterator<Item = &'a mut T> for LaneIterMut<'a, T, S> }
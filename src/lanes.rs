@@ -0,0 +1,239 @@
+//! Mutable lanes of interleaved data.
+//!
+//! The lanes of a strided slice interleave in memory, so the underlying slices
+//! of any two lanes overlap even though their elements don't. A lane
+//! therefore can't be a `&mut Stride<T, S>`, which would give mutable access
+//! to the whole underlying slice, and is instead a pointer and a length that
+//! only ever reach the lane's own elements.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+use core::ptr::{self, NonNull};
+
+use crate::index::index_fail;
+use crate::ops::eq_by;
+use crate::{LaneIter, LaneIterMut, Stride};
+
+/// A mutable lane of interleaved data.
+///
+/// This struct is created by the [`lanes_mut()`][`Stride::lanes_mut()`] method
+/// on strided slices. It behaves like a `&mut Stride<T, S>` that leaves the
+/// elements in between alone, so that the other lanes can be used at the same
+/// time.
+pub struct LaneMut<'a, T, const S: usize> {
+    /// Points to the first element, unless `len` is zero.
+    ptr: *mut T,
+    /// The number of elements in the lane.
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Send, const S: usize> Send for LaneMut<'_, T, S> {}
+unsafe impl<T: Sync, const S: usize> Sync for LaneMut<'_, T, S> {}
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Returns all `S` interleaved lanes of the underlying slice as mutable
+    /// lanes that can be used at the same time.
+    ///
+    /// This is the mutable counterpart of [`lanes()`][`Stride::lanes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// // Interleaved stereo samples.
+    /// let data = &mut [1, -1, 2, -2, 3, -3];
+    /// let [mut left, mut right] = Stride::<_, 2>::new_mut(data).lanes_mut();
+    /// for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+    ///     std::mem::swap(l, r);
+    /// }
+    /// left[0] = 0;
+    /// assert_eq!(data, &[0, 1, -2, 2, -3, 3]);
+    /// ```
+    pub fn lanes_mut(&mut self) -> [LaneMut<'_, T, S>; S] {
+        let len = self.data.len();
+        let ptr = self.as_mut_ptr();
+        core::array::from_fn(|k| LaneMut {
+            // The pointer of an empty lane is never dereferenced.
+            ptr: ptr.wrapping_add(k),
+            len: len.saturating_sub(k).div_ceil(S),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T, const S: usize> LaneMut<'a, T, S> {
+    /// Returns the number of elements in the lane.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the lane has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: the index is in bounds, and can't overflow.
+            Some(unsafe { &*self.ptr.add(index * S) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if
+    /// out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            // SAFETY: the index is in bounds, and can't overflow.
+            Some(unsafe { &mut *self.ptr.add(index * S) })
+        } else {
+            None
+        }
+    }
+
+    /// Swaps two elements in the lane.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are out of bounds.
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.len {
+            index_fail(a, self.len, S);
+        }
+        if b >= self.len {
+            index_fail(b, self.len, S);
+        }
+        // SAFETY: both indexes are in bounds.
+        unsafe { ptr::swap(self.ptr.add(a * S), self.ptr.add(b * S)) }
+    }
+
+    /// Fills the lane with elements by cloning `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for elem in self.iter_mut() {
+            elem.clone_from(&value);
+        }
+    }
+
+    /// Returns an iterator over the lane.
+    pub fn iter(&self) -> LaneIter<'_, T, S> {
+        // SAFETY: the lane's elements can be read while it is borrowed.
+        unsafe { LaneIter::new(self.ptr, self.len) }
+    }
+
+    /// Returns an iterator over the lane that allows modifying each value.
+    pub fn iter_mut(&mut self) -> LaneIterMut<'_, T, S> {
+        // SAFETY: the lane is mutably borrowed by the iterator.
+        unsafe { LaneIterMut::new(self.ptr, self.len) }
+    }
+
+    /// Returns a lane for the same elements that borrows this one.
+    pub fn reborrow(&mut self) -> LaneMut<'_, T, S> {
+        LaneMut {
+            ptr: self.ptr,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const S: usize> Default for LaneMut<'_, T, S> {
+    /// Creates an empty lane.
+    fn default() -> Self {
+        Self {
+            ptr: NonNull::dangling().as_ptr(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for LaneMut<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const S: usize> Index<usize> for LaneMut<'_, T, S> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => index_fail(index, self.len, S),
+        }
+    }
+}
+
+impl<T, const S: usize> IndexMut<usize> for LaneMut<'_, T, S> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(elem) => elem,
+            None => index_fail(index, len, S),
+        }
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for LaneMut<'a, T, S> {
+    type Item = &'a mut T;
+    type IntoIter = LaneIterMut<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // SAFETY: the lane is consumed by the iterator.
+        unsafe { LaneIterMut::new(self.ptr, self.len) }
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a LaneMut<'_, T, S> {
+    type Item = &'a T;
+    type IntoIter = LaneIter<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a mut LaneMut<'_, T, S> {
+    type Item = &'a mut T;
+    type IntoIter = LaneIterMut<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+macro_rules! impl_partial_eq {
+    ($Rhs:ty, [$($params:tt)*]) => {
+        impl<T, U, const S: usize, $($params)*> PartialEq<$Rhs> for LaneMut<'_, T, S>
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$Rhs) -> bool {
+                eq_by(self.iter(), other.iter())
+            }
+        }
+    };
+}
+
+impl_partial_eq! { LaneMut<'_, U, R>, [const R: usize] }
+impl_partial_eq! { Stride<U, R>, [const R: usize] }
+impl_partial_eq! { &Stride<U, R>, [const R: usize] }
+impl_partial_eq! { [U], [] }
+impl_partial_eq! { &[U], [] }
+impl_partial_eq! { [U; N], [const N: usize] }
+impl_partial_eq! { &[U; N], [const N: usize] }
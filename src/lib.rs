@@ -15,6 +15,8 @@
 //!   wraps a [`&[T]`][`slice`].
 //! - [`::new_mut()`][`Stride::new_mut`] to construct a
 //!   [`&mut Stride<T, S>`][`Stride`] that wraps a [`&mut [T]`][`slice`].
+//! - [`DynStride::new()`] and [`DynStrideMut::new()`] if the stride is only
+//!   known at runtime.
 //!
 //! ```rust
 //! use stride::Stride;
@@ -37,20 +39,171 @@
 //! assert_eq!(stride, &[1, 3, 5]);
 //! assert_eq!(data, &[1, 2, 3, 4, 5, 6]);
 //! ```
+//!
+//! References to strided slices implement [`IntoIterator`], so they can be
+//! used directly in `for` loops and passed to generic code.
+//!
+//! ```rust
+//! use stride::Stride;
+//!
+//! let data = &mut [1, 2, 3, 4, 5, 6];
+//! let stride = Stride::<_, 2>::new_mut(data);
+//!
+//! for x in &mut *stride {
+//!     *x *= 10;
+//! }
+//! let sum: i32 = (&*stride).into_iter().sum();
+//!
+//! assert_eq!(sum, 90);
+//! assert_eq!(data, &[10, 2, 30, 4, 50, 6]);
+//! ```
+//!
+//! The constructors and basic accessors are `const fn`, so strided slices
+//! over `static` data can be built and queried at compile time.
+//!
+//! ```rust
+//! use stride::Stride;
+//!
+//! // Interleaved (x, y) pairs.
+//! static POINTS: [u8; 6] = [1, 10, 2, 20, 3, 30];
+//! const XS: &Stride<u8, 2> = Stride::new(&POINTS);
+//! const LAST_Y: Option<&u8> = Stride::<_, 2>::new(POINTS.split_at(1).1).last();
+//!
+//! assert_eq!(XS, &[1, 2, 3]);
+//! assert_eq!(LAST_Y, Some(&30));
+//! ```
+//!
+//! # Features
+//!
+//! - `alloc`: enables methods that need to allocate, such as the stable
+//!   `sort()`, and the owned `StrideBuf<T, S>` type.
+//! - `arbitrary`: implements `Arbitrary` for `&Stride<u8, S>` so that fuzz
+//!   input can be viewed as a strided slice directly, and together with
+//!   `alloc` for `StrideBuf<T, S>` and `Box<Stride<T, S>>`.
+//! - `bytemuck`: adds `cast()` and friends to reinterpret strided slices of
+//!   plain data as another type, adjusting the stride.
+//! - `defmt`: implements `defmt::Format` for strided slices, for logging on
+//!   embedded targets.
+//! - `math`: adds `sum()`, `min_elem()` and `max_elem()` to strided slices of
+//!   integers and floats, which keep several accumulators so that they
+//!   vectorize.
+//! - `nalgebra`: converts strided slices into `nalgebra` column vector views
+//!   with the same stride, without copying.
+//! - `ndarray`: converts strided slices into one-dimensional `ndarray` views
+//!   with the same stride, without copying.
+//! - `proptest`: adds the `proptest` module with strategies for generating
+//!   owned strided slices in property tests. This also enables `alloc`.
+//! - `rayon`: implements `IntoParallelIterator` for strided slices so that
+//!   they can be iterated in parallel with `par_iter()` and `par_iter_mut()`,
+//!   and adds parallel sorts such as `par_sort_unstable()`. This also enables
+//!   `alloc`.
+//! - `rkyv`: implements `rkyv` archiving for `StrideBuf<T, S>`, whose archived
+//!   form `ArchivedStrideBuf<T, S>` can be accessed as a strided slice without
+//!   copying. This also enables `alloc`.
+//! - `serde`: implements `Serialize` for strided slices as a sequence of their
+//!   elements, and together with `alloc` implements `Deserialize` for
+//!   `StrideBuf<T, S>`.
+//! - `std`: adds `gather_to_slice()` and `scatter_from_slice()` for `u8`,
+//!   `f32` and `f64`, which use SSSE3, AVX2, SSE4.1 or NEON kernels for small
+//!   strides when the CPU supports them. This also enables `alloc`.
+//! - `zerocopy`: adds `from_bytes()` and `from_bytes_mut()` to construct
+//!   strided slices over raw bytes, with the layout of the elements checked at
+//!   compile time.
+//! - `nightly`: implements unstable traits such as
+//!   [`TrustedLen`][`core::iter::TrustedLen`] for the iterators. This requires
+//!   a nightly compiler.
+//! - `portable_simd`: adds `load_simd()` and `store_simd()` to gather and
+//!   scatter elements with `core::simd`, and makes `copy_to_slice()`,
+//!   `copy_from_slice()` and `fill()` use SIMD for primitive numbers. This
+//!   requires a nightly compiler.
 
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(trusted_len))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd, min_specialization))]
 #![allow(unused_unsafe)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod arch;
+#[cfg(feature = "rkyv")]
+mod archive;
+mod atomic;
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "zerocopy")]
+mod bytes;
+#[cfg(feature = "bytemuck")]
+mod cast;
+mod dyn_stride;
+mod error;
 mod index;
 mod iter;
+mod lanes;
+#[cfg(feature = "math")]
+mod math;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 mod ops;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod reversed;
+#[cfg(feature = "portable_simd")]
+mod simd;
+mod sort;
+mod uninit;
 
+use core::array;
+use core::cell::Cell;
+use core::cmp::Ordering;
 use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Range, RangeBounds};
+use core::ptr;
+use core::slice;
 
+#[cfg(feature = "rkyv")]
+pub use crate::archive::ArchivedStrideBuf;
+#[cfg(feature = "alloc")]
+pub use crate::buf::StrideBuf;
+pub use crate::dyn_stride::{DynStride, DynStrideMut};
+pub use crate::error::{GetDisjointMutError, StrideError, TryFromStrideError};
 pub use crate::index::StrideIndex;
-pub use crate::iter::{Iter, IterMut};
+pub use crate::iter::{
+    ArrayChunks, ArrayChunksMut, ChunkBy, ChunkByMut, Chunks, ChunksExact, ChunksExactMut,
+    ChunksMut, DynIter, DynIterMut, Iter, IterMut, IterWithRawIndices, LaneIter, LaneIterMut,
+    RChunks, RChunksMut, RSplit, RSplitMut, RSplitN, RSplitNMut, Split, SplitInclusive,
+    SplitInclusiveMut, SplitInto, SplitIntoMut, SplitMut, SplitN, SplitNMut, Windows,
+};
+pub use crate::lanes::LaneMut;
+#[cfg(feature = "rayon")]
+pub use crate::par::{ParIter, ParIterMut};
+pub use crate::reversed::Reversed;
 
 /// A constant strided slice.
+///
+/// # Zero-sized types
+///
+/// Strided slices of zero-sized types behave like those of any other type. The
+/// length is the length of the underlying slice divided by `S`, rounded up, and
+/// iterating yields exactly that many references. Since the elements take up
+/// no memory all pointers into the strided slice are equal.
+///
+/// ```
+/// # use stride::Stride;
+/// #
+/// let stride = Stride::<_, 2>::new(&[(); 5]);
+/// assert_eq!(stride.len(), 3);
+/// assert_eq!(stride.iter().count(), 3);
+/// assert_eq!(stride.iter().rev().count(), 3);
+/// ```
 #[repr(transparent)]
 pub struct Stride<T, const S: usize> {
     data: [T],
@@ -78,6 +231,11 @@ impl<T, const S: usize> Default for &mut Stride<T, S> {
 }
 
 impl<T, const S: usize> Stride<T, S> {
+    /// Fails to compile if `S` is zero.
+    ///
+    /// This must be evaluated in every constructor.
+    const ASSERT_NONZERO: () = assert!(S != 0, "the stride `S` must be non-zero");
+
     /// Constructs a new strided slice.
     ///
     /// # Examples
@@ -88,7 +246,16 @@ impl<T, const S: usize> Stride<T, S> {
     /// let data = &[1, 2, 3, 4, 5, 6];
     /// let stride = Stride::<_, 3>::new(data);
     /// ```
-    pub fn new(data: &[T]) -> &Self {
+    ///
+    /// A stride of zero is rejected at compile time.
+    ///
+    /// ```compile_fail
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 0>::new(&[1, 2, 3]);
+    /// ```
+    pub const fn new(data: &[T]) -> &Self {
+        let () = Self::ASSERT_NONZERO;
         unsafe { &*(data as *const [T] as *const Self) }
     }
 
@@ -102,254 +269,2908 @@ impl<T, const S: usize> Stride<T, S> {
     /// let data = &mut [1, 2, 3, 4, 5, 6];
     /// let stride = Stride::<_, 3>::new_mut(data);
     /// ```
-    pub fn new_mut(data: &mut [T]) -> &mut Self {
+    pub const fn new_mut(data: &mut [T]) -> &mut Self {
+        let () = Self::ASSERT_NONZERO;
         unsafe { &mut *(data as *mut [T] as *mut Self) }
     }
 
-    /// Returns the number of elements in the strided slice.
+    /// Constructs a new strided slice starting at element `offset` of `data`.
     ///
-    /// This is equivalent to the ceiling division of the underlying slice
-    /// length by `S`.
+    /// This is useful for selecting a channel of interleaved data other than
+    /// the first.
+    ///
+    /// # Panics
+    ///
+    /// If `offset > data.len()`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let data = &[1, 2, 3, 4, 5, 6];
-    /// assert_eq!(Stride::<_, 1>::new(data).len(), 6);
-    /// assert_eq!(Stride::<_, 2>::new(data).len(), 3);
-    /// assert_eq!(Stride::<_, 3>::new(data).len(), 2);
+    /// // Interleaved RGB pixels.
+    /// let data = &[10, 20, 30, 11, 21, 31, 12, 22, 32];
+    /// let green = Stride::<_, 3>::new_with_offset(data, 1);
+    /// assert_eq!(green, &[20, 21, 22]);
     /// ```
-    pub const fn len(&self) -> usize {
-        (self.data.len() + S - 1) / S
+    #[track_caller]
+    pub const fn new_with_offset(data: &[T], offset: usize) -> &Self {
+        assert!(offset <= data.len(), "offset > len");
+        Self::new(data.split_at(offset).1)
     }
 
-    /// Returns `true` if the strided slice has a length of 0.
+    /// Constructs a new mutable strided slice starting at element `offset` of
+    /// `data`.
+    ///
+    /// # Panics
+    ///
+    /// If `offset > data.len()`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6]);
-    /// assert!(!stride.is_empty());
+    /// let data = &mut [10, 20, 30, 11, 21, 31];
+    /// let blue = Stride::<_, 3>::new_with_offset_mut(data, 2);
+    /// blue.fill(0);
+    /// assert_eq!(data, &[10, 20, 0, 11, 21, 0]);
     /// ```
-    pub const fn is_empty(&self) -> bool {
-        self.len() == 0
+    #[track_caller]
+    pub const fn new_with_offset_mut(data: &mut [T], offset: usize) -> &mut Self {
+        assert!(offset <= data.len(), "offset > len");
+        Self::new_mut(data.split_at_mut(offset).1)
     }
 
-    /// Returns a raw pointer to the underlying slice's buffer.
+    /// Constructs a new strided slice from an array.
     ///
-    /// *See [`slice::as_ptr()`].*
-    pub const fn as_ptr(&self) -> *const T {
-        self.data.as_ptr()
+    /// The length of the strided slice only depends on `N`, so it is known at
+    /// compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// const STRIDE: &Stride<i32, 2> = Stride::new_from_array(&[1, 2, 3, 4, 5]);
+    /// const LEN: usize = STRIDE.len();
+    /// assert_eq!(LEN, 3);
+    /// ```
+    pub const fn new_from_array<const N: usize>(data: &[T; N]) -> &Self {
+        Self::new(data)
     }
 
-    /// Returns an unsafe mutable pointer to the underlying slice's buffer.
+    /// Constructs a new mutable strided slice from an array.
     ///
-    /// *See [`slice::as_mut_ptr()`].*
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.data.as_mut_ptr()
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// Stride::<_, 2>::new_from_array_mut(&mut data)[1] = 7;
+    /// assert_eq!(data, [1, 2, 7, 4, 5]);
+    /// ```
+    pub const fn new_from_array_mut<const N: usize>(data: &mut [T; N]) -> &mut Self {
+        Self::new_mut(data)
     }
 
-    /// Returns a reference to an element or substride depending on the type of
-    /// index.
+    /// Constructs a new strided slice from an array, requiring `N` to be a
+    /// multiple of `S`.
     ///
-    /// - If given a position, returns a reference to the element at that
-    ///   position or `None` if out of bounds.
-    /// - If given a range, returns the substride corresponding to that range,
-    ///   or `None` if out of bounds.
+    /// This is the compile time checked version of
+    /// [`new_exact()`][`Stride::new_exact`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
-    /// assert_eq!(stride.get(1), Some(&3));
-    /// assert_eq!(stride.get(0..2), Some(Stride::<_, 2>::new(&[1, 2, 3, 4])));
-    /// assert_eq!(stride.get(3), None);
-    /// assert_eq!(stride.get(0..4), None);
+    /// let stride = Stride::<_, 2>::new_exact_from_array(&[1, 2, 3, 4]);
+    /// assert_eq!(stride, &[1, 3]);
     /// ```
-    pub fn get<I>(&self, index: I) -> Option<&I::Output>
-    where
-        I: StrideIndex<Stride<T, S>>,
-    {
-        index.get(self)
+    ///
+    /// An array length that is not a multiple of `S` is rejected at compile
+    /// time.
+    ///
+    /// ```compile_fail
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new_exact_from_array(&[1, 2, 3]);
+    /// ```
+    pub const fn new_exact_from_array<const N: usize>(data: &[T; N]) -> &Self {
+        let () = Multiple::<S, N>::ASSERT_LEN;
+        Self::new(data)
     }
 
-    /// Returns a mutable reference to an element or substride depending on the
-    /// type of index (see [`get`]) or `None` if the index is out of bounds.
+    /// Constructs a new mutable strided slice from an array, requiring `N` to
+    /// be a multiple of `S`.
+    pub const fn new_exact_from_array_mut<const N: usize>(data: &mut [T; N]) -> &mut Self {
+        let () = Multiple::<S, N>::ASSERT_LEN;
+        Self::new_mut(data)
+    }
+
+    /// Constructs a new strided slice, returning an error if the length of
+    /// `data` is not a multiple of `S`.
     ///
-    /// [`get`]: #method.get
+    /// [`new()`][`Stride::new`] allows the last element to have no trailing
+    /// padding, in which case the length of the strided slice is rounded up.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let data = &mut [0, 1, 2, 3];
-    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let stride = Stride::<_, 3>::try_new(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(stride, &[1, 4]);
+    /// assert!(Stride::<_, 3>::try_new(&[1, 2, 3, 4]).is_err());
+    /// ```
+    pub const fn try_new(data: &[T]) -> Result<&Self, StrideError> {
+        match check_exact::<S>(data.len()) {
+            Ok(()) => Ok(Self::new(data)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Constructs a new mutable strided slice, returning an error if the length
+    /// of `data` is not a multiple of `S`.
+    ///
+    /// # Examples
     ///
-    /// if let Some(elem) = stride.get_mut(1) {
-    ///     *elem = 42;
-    /// }
-    /// assert_eq!(stride, Stride::<_, 2>::new(&[0, 1, 42, 3]));
     /// ```
-    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
-    where
-        I: StrideIndex<Stride<T, S>>,
-    {
-        index.get_mut(self)
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 3>::try_new_mut(data).unwrap();
+    /// stride[1] = 7;
+    /// assert_eq!(data, &[1, 2, 3, 7, 5, 6]);
+    /// ```
+    pub const fn try_new_mut(data: &mut [T]) -> Result<&mut Self, StrideError> {
+        match check_exact::<S>(data.len()) {
+            Ok(()) => Ok(Self::new_mut(data)),
+            Err(err) => Err(err),
+        }
     }
 
-    /// Returns a reference to an element or substride, without doing bounds
-    /// checking.
+    /// Constructs a new strided slice, requiring the length of `data` to be a
+    /// multiple of `S`.
     ///
-    /// For a safe alternative see [`get`].
+    /// # Panics
     ///
-    /// # Safety
+    /// If the length of `data` is not a multiple of `S`.
     ///
-    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
-    /// even if the resulting reference is not used.
+    /// # Examples
     ///
-    /// [`get`]: #method.get
-    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
-    pub unsafe fn get_unchecked<I>(&self, index: I) -> &I::Output
-    where
-        I: StrideIndex<Self>,
-    {
-        unsafe { &*index.get_unchecked(self) }
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new_exact(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(stride, &[1, 3, 5]);
+    /// ```
+    #[track_caller]
+    pub fn new_exact(data: &[T]) -> &Self {
+        match Self::try_new(data) {
+            Ok(stride) => stride,
+            Err(err) => panic!("{}", err),
+        }
     }
 
-    /// Returns a mutable reference to an element or substride, without doing
-    /// bounds checking.
+    /// Constructs a new mutable strided slice, requiring the length of `data`
+    /// to be a multiple of `S`.
     ///
-    /// For a safe alternative see [`get_mut`].
+    /// # Panics
     ///
-    /// # Safety
+    /// If the length of `data` is not a multiple of `S`.
     ///
-    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
-    /// even if the resulting reference is not used.
+    /// # Examples
     ///
-    /// [`get_mut`]: #method.get_mut
-    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
-    pub unsafe fn get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
-    where
-        I: StrideIndex<Self>,
-    {
-        unsafe { &mut *index.get_unchecked_mut(self) }
-    }
-
-    /// Returns a reference to the first element of the strided slice, or `None`
-    /// if it is empty.
-    pub fn first(&self) -> Option<&T> {
-        self.get(0)
-    }
-
-    /// Returns a mutable reference to the first element of the strided slice,
-    /// or `None` if it is empty.
-    pub fn first_mut(&mut self) -> Option<&mut T> {
-        self.get_mut(0)
-    }
-
-    /// Returns a reference to the last element of the strided slice, or `None`
-    /// if it is empty.
-    pub fn last(&self) -> Option<&T> {
-        self.get(self.len().saturating_sub(1))
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_exact_mut(data);
+    /// stride[2] = 7;
+    /// assert_eq!(data, &[1, 2, 3, 4, 7, 6]);
+    /// ```
+    #[track_caller]
+    pub fn new_exact_mut(data: &mut [T]) -> &mut Self {
+        match Self::try_new_mut(data) {
+            Ok(stride) => stride,
+            Err(err) => panic!("{}", err),
+        }
     }
 
-    /// Returns a mutable reference to the last element of the strided slice, or
-    /// `None` if it is empty.
-    pub fn last_mut(&mut self) -> Option<&mut T> {
-        self.get_mut(self.len().saturating_sub(1))
+    /// Constructs a strided slice from a pointer and the length of the
+    /// underlying slice.
+    ///
+    /// The `len` argument is the number of elements in the *underlying* slice,
+    /// not the number of elements in the strided slice.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as [`slice::from_raw_parts`] apply, in particular:
+    ///
+    /// - `data` must be non-null, aligned, and valid for reads of
+    ///   `len * mem::size_of::<T>()` bytes, all within a single allocated
+    ///   object.
+    /// - `data` must point to `len` consecutive properly initialized values of
+    ///   type `T`.
+    /// - The memory referenced by the returned strided slice must not be
+    ///   mutated for the duration of lifetime `'a`, including the elements that
+    ///   are not part of the strided slice.
+    /// - The total size `len * mem::size_of::<T>()` must be no larger than
+    ///   `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let stride = unsafe { Stride::<_, 2>::from_raw_parts(data.as_ptr(), data.len()) };
+    /// assert_eq!(stride, &[1, 3, 5]);
+    /// ```
+    pub const unsafe fn from_raw_parts<'a>(data: *const T, len: usize) -> &'a Self {
+        Self::new(unsafe { slice::from_raw_parts(data, len) })
     }
 
-    /// Swaps two elements in the strided slice.
+    /// Constructs a mutable strided slice from a pointer and the length of the
+    /// underlying slice.
     ///
-    /// # Arguments
+    /// The `len` argument is the number of elements in the *underlying* slice,
+    /// not the number of elements in the strided slice.
     ///
-    /// - `a` - The index of the first element
-    /// - `b` - The index of the second element
+    /// # Safety
     ///
-    /// # Panics
+    /// The same requirements as [`slice::from_raw_parts_mut`] apply, in
+    /// particular:
     ///
-    /// If `a` or `b` are out of bounds.
-    pub fn swap(&mut self, a: usize, b: usize) {
-        self.data.swap(a * S, b * S)
+    /// - `data` must be non-null, aligned, and valid for both reads and writes
+    ///   of `len * mem::size_of::<T>()` bytes, all within a single allocated
+    ///   object.
+    /// - `data` must point to `len` consecutive properly initialized values of
+    ///   type `T`.
+    /// - The memory referenced by the returned strided slice must not be
+    ///   accessed through any other pointer for the duration of lifetime `'a`,
+    ///   including the elements that are not part of the strided slice.
+    /// - The total size `len * mem::size_of::<T>()` must be no larger than
+    ///   `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    /// let stride = unsafe { Stride::<_, 3>::from_raw_parts_mut(data.as_mut_ptr(), data.len()) };
+    /// stride[1] = 7;
+    /// assert_eq!(data, [1, 2, 3, 7, 5, 6]);
+    /// ```
+    pub const unsafe fn from_raw_parts_mut<'a>(data: *mut T, len: usize) -> &'a mut Self {
+        Self::new_mut(unsafe { slice::from_raw_parts_mut(data, len) })
     }
 
-    /// Returns an iterator over the stride.
+    /// Returns the number of elements in the strided slice.
+    ///
+    /// This is equivalent to the ceiling division of the underlying slice
+    /// length by `S`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
-    /// let mut iterator = stride.iter();
-    /// assert_eq!(iterator.next(), Some(&1));
-    /// assert_eq!(iterator.next(), Some(&3));
-    /// assert_eq!(iterator.next(), Some(&5));
-    /// assert_eq!(iterator.next(), None);
+    /// let data = &[1, 2, 3, 4, 5, 6];
+    /// assert_eq!(Stride::<_, 1>::new(data).len(), 6);
+    /// assert_eq!(Stride::<_, 2>::new(data).len(), 3);
+    /// assert_eq!(Stride::<_, 3>::new(data).len(), 2);
     /// ```
-    pub fn iter(&self) -> Iter<T, S> {
-        Iter::new(self)
+    pub const fn len(&self) -> usize {
+        index::strided_len(self.data.len(), S)
     }
 
-    /// Returns an iterator over the stride that allows modifying each value.
+    /// Returns `true` if the strided slice has a length of 0.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let slice = &mut [1, 1, 2, 2, 3, 3];
-    /// let stride = Stride::<_, 2>::new_mut(slice);
-    /// for elem in stride.iter_mut() {
-    ///     *elem *= 2;
-    /// }
-    /// assert_eq!(slice, &[2, 1, 4, 2, 6, 3]);
+    /// let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(!stride.is_empty());
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<T, S> {
-        IterMut::new(self)
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-}
 
-impl<T> Stride<T, 1> {
-    /// Returns a slice containing the entire strided slice.
+    /// Returns a raw pointer to the underlying slice's buffer.
     ///
-    /// Only available on strided slices with a stride of `1`.
+    /// *See [`slice::as_ptr()`].*
+    pub const fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the underlying slice's buffer.
+    ///
+    /// *See [`slice::as_mut_ptr()`].*
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    /// Returns the two raw pointers spanning the underlying slice.
+    ///
+    /// The range includes the elements in between those in the strided slice.
+    /// If the last element of the strided slice has no trailing padding the
+    /// range ends directly after it.
+    ///
+    /// *See [`slice::as_ptr_range()`].*
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let slice = &[1, 2, 3];
-    /// let stride = Stride::<_, 1>::new(slice);
-    /// assert_eq!(stride.as_slice(), slice);
+    /// let data = &[1, 2, 3, 4, 5];
+    /// let stride = Stride::<_, 2>::new(data);
+    /// assert_eq!(stride.as_ptr_range(), data.as_ptr_range());
     /// ```
-    pub fn as_slice(&self) -> &[T] {
-        &self.data
+    pub const fn as_ptr_range(&self) -> Range<*const T> {
+        self.data.as_ptr_range()
     }
 
-    /// Returns a mutable slice containing the entire strided slice.
+    /// Returns the two unsafe mutable pointers spanning the underlying slice.
     ///
-    /// Only available on strided slices with a stride of `1`.
+    /// The range includes the elements in between those in the strided slice.
+    ///
+    /// *See [`slice::as_mut_ptr_range()`].*
+    pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.data.as_mut_ptr_range()
+    }
+
+    /// Returns the underlying slice, including the elements in between those
+    /// in the strided slice.
     ///
     /// # Examples
     ///
     /// ```
     /// # use stride::Stride;
     /// #
-    /// let slice = &mut [1, 2, 7];
-    /// let stride = Stride::<_, 1>::new_mut(slice);
-    /// stride.as_mut_slice()[2] = 3;
-    /// assert_eq!(slice, &[1, 2, 3])
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(stride.len(), 3);
+    /// assert_eq!(stride.underlying(), &[1, 2, 3, 4, 5]);
+    /// assert_eq!(stride[1..].underlying(), &[3, 4, 5]);
     /// ```
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        &mut self.data
+    pub const fn underlying(&self) -> &[T] {
+        &self.data
     }
+
+    /// Returns the underlying mutable slice, including the elements in between
+    /// those in the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.underlying_mut()[1] = 7;
+    /// assert_eq!(data, &[1, 7, 3, 4, 5]);
+    /// ```
+    pub const fn underlying_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the underlying slice as a slice of `S`-element arrays, or an
+    /// error if its length is not a multiple of `S`.
+    ///
+    /// The first element of each array is an element of the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(stride.as_arrays().unwrap(), &[[1, 2], [3, 4], [5, 6]]);
+    /// assert!(stride[1..].as_arrays().is_ok());
+    /// assert!(Stride::<_, 2>::new(&[1, 2, 3]).as_arrays().is_err());
+    /// ```
+    pub const fn as_arrays(&self) -> Result<&[[T; S]], StrideError> {
+        match check_exact::<S>(self.data.len()) {
+            Ok(()) => Ok(self.data.as_chunks().0),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the underlying mutable slice as a slice of `S`-element arrays,
+    /// or an error if its length is not a multiple of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 3>::new_mut(data);
+    /// stride.as_arrays_mut().unwrap().reverse();
+    /// assert_eq!(data, &[4, 5, 6, 1, 2, 3]);
+    /// ```
+    pub const fn as_arrays_mut(&mut self) -> Result<&mut [[T; S]], StrideError> {
+        match check_exact::<S>(self.data.len()) {
+            Ok(()) => Ok(self.data.as_chunks_mut().0),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns every `R / S`-th element of the strided slice, as a strided
+    /// slice with a stride of `R`.
+    ///
+    /// This composes strides without copying, e.g. every 3rd element of a
+    /// `Stride<T, 2>` is a `Stride<T, 6>`. Fails to compile if `R` is not a
+    /// multiple of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    /// let stride = Stride::<_, 2>::new(data);
+    /// assert_eq!(stride, &[1, 3, 5, 7, 9, 11, 13]);
+    /// assert_eq!(stride.restride::<6>(), &[1, 7, 13]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let stride = stride.restride::<3>();
+    /// ```
+    pub const fn restride<const R: usize>(&self) -> &Stride<T, R> {
+        let () = Multiple::<S, R>::ASSERT_STRIDE;
+        Stride::new(&self.data)
+    }
+
+    /// Returns every `R / S`-th element of the mutable strided slice, as a
+    /// mutable strided slice with a stride of `R`.
+    ///
+    /// Fails to compile if `R` is not a multiple of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.restride_mut::<4>().fill(0);
+    /// assert_eq!(data, &[0, 2, 3, 4, 0, 6, 7, 8]);
+    /// ```
+    pub const fn restride_mut<const R: usize>(&mut self) -> &mut Stride<T, R> {
+        let () = Multiple::<S, R>::ASSERT_STRIDE;
+        Stride::new_mut(&mut self.data)
+    }
+
+    /// Returns a strided slice of cells from a mutable strided slice.
+    ///
+    /// The returned strided slice can be shared and copied, and still allows
+    /// its elements to be mutated, as long as it stays on one thread. This
+    /// is the strided version of
+    /// [`Cell::as_slice_of_cells()`][`Cell::as_slice_of_cells()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let cells = Stride::<_, 2>::new_mut(data).as_cell_stride();
+    /// let (a, b) = (cells, cells);
+    /// for (x, y) in a.iter().zip(b.iter().skip(1)) {
+    ///     x.set(x.get() + y.get());
+    /// }
+    /// assert_eq!(data, &[4, 2, 8, 4, 5, 6]);
+    /// ```
+    pub fn as_cell_stride(&mut self) -> &Stride<Cell<T>, S> {
+        Stride::new(Cell::from_mut(&mut self.data).as_slice_of_cells())
+    }
+
+    /// Returns all `S` interleaved lanes of the underlying slice.
+    ///
+    /// Lane `k` starts at element `k` of the underlying slice, so lane 0 is
+    /// the strided slice itself and the other lanes are made up of the
+    /// elements in between. Lanes that start past the end of the underlying
+    /// slice are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// // Interleaved RGB pixels.
+    /// let data = &[10, 20, 30, 11, 21, 31, 12, 22, 32];
+    /// let [r, g, b] = Stride::<_, 3>::new(data).lanes();
+    /// assert_eq!(r, &[10, 11, 12]);
+    /// assert_eq!(g, &[20, 21, 22]);
+    /// assert_eq!(b, &[30, 31, 32]);
+    /// ```
+    pub fn lanes(&self) -> [&Self; S] {
+        array::from_fn(|k| Self::new(&self.data[min(k, self.data.len())..]))
+    }
+
+    /// Returns the index in the underlying slice of the element at `index`, or
+    /// `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 10, 2, 20, 3, 30];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let i = stride.iter().position(|&x| x == 2).unwrap();
+    /// let raw = stride.raw_index(i).unwrap();
+    /// stride.underlying_mut()[raw + 1] = 0;
+    /// assert_eq!(data, &[1, 10, 2, 0, 3, 30]);
+    /// assert_eq!(Stride::<_, 2>::new(data).raw_index(3), None);
+    /// ```
+    pub const fn raw_index(&self, index: usize) -> Option<usize> {
+        if index < self.len() {
+            Some(index * S)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to an element or substride depending on the type of
+    /// index.
+    ///
+    /// - If given a position, returns a reference to the element at that
+    ///   position or `None` if out of bounds.
+    /// - If given a range, returns the substride corresponding to that range,
+    ///   or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(stride.get(1), Some(&3));
+    /// assert_eq!(stride.get(0..2), Some(Stride::<_, 2>::new(&[1, 2, 3, 4])));
+    /// assert_eq!(stride.get(3), None);
+    /// assert_eq!(stride.get(0..4), None);
+    /// ```
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: StrideIndex<Self>,
+    {
+        index.get(self)
+    }
+
+    /// Returns a mutable reference to an element or substride depending on the
+    /// type of index (see [`get`]) or `None` if the index is out of bounds.
+    ///
+    /// [`get`]: #method.get
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0, 1, 2, 3];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    ///
+    /// if let Some(elem) = stride.get_mut(1) {
+    ///     *elem = 42;
+    /// }
+    /// assert_eq!(stride, Stride::<_, 2>::new(&[0, 1, 42, 3]));
+    /// ```
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: StrideIndex<Self>,
+    {
+        index.get_mut(self)
+    }
+
+    /// Returns a reference to an element or substride, without doing bounds
+    /// checking.
+    ///
+    /// For a safe alternative see [`get`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [`get`]: #method.get
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// unsafe {
+    ///     assert_eq!(stride.get_unchecked(1), &3);
+    /// }
+    /// ```
+    pub unsafe fn get_unchecked<I>(&self, index: I) -> &I::Output
+    where
+        I: StrideIndex<Self>,
+    {
+        unsafe { &*index.get_unchecked(self) }
+    }
+
+    /// Returns a mutable reference to an element or substride, without doing
+    /// bounds checking.
+    ///
+    /// For a safe alternative see [`get_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [`get_mut`]: #method.get_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// unsafe {
+    ///     *stride.get_unchecked_mut(1) = 7;
+    /// }
+    /// assert_eq!(data, &[1, 2, 7, 4, 5, 6]);
+    /// ```
+    pub unsafe fn get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: StrideIndex<Self>,
+    {
+        unsafe { &mut *index.get_unchecked_mut(self) }
+    }
+
+    /// Returns mutable references to many elements at once.
+    ///
+    /// Returns an error if any index is out of bounds, or if the same index was
+    /// passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::{GetDisjointMutError, Stride};
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// if let Ok([a, b]) = stride.get_disjoint_mut([0, 2]) {
+    ///     *a = 7;
+    ///     *b = 8;
+    /// }
+    /// assert_eq!(data, &[7, 2, 3, 4, 8, 6]);
+    ///
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// assert_eq!(stride.get_disjoint_mut([0, 3]), Err(GetDisjointMutError::IndexOutOfBounds));
+    /// assert_eq!(stride.get_disjoint_mut([1, 1]), Err(GetDisjointMutError::OverlappingIndices));
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut T; N], GetDisjointMutError> {
+        let len = self.len();
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= len {
+                return Err(GetDisjointMutError::IndexOutOfBounds);
+            }
+            if indices[..i].contains(&index) {
+                return Err(GetDisjointMutError::OverlappingIndices);
+            }
+        }
+        // SAFETY: all indexes were checked to be in bounds and disjoint.
+        Ok(unsafe { self.get_disjoint_unchecked_mut(indices) })
+    }
+
+    /// Returns mutable references to many elements at once, without doing any
+    /// checks.
+    ///
+    /// For a safe alternative see [`get_disjoint_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping or out-of-bounds indexes is
+    /// *[undefined behavior]* even if the resulting references are not used.
+    ///
+    /// [`get_disjoint_mut`]: #method.get_disjoint_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let [a, b] = unsafe { stride.get_disjoint_unchecked_mut([2, 0]) };
+    /// core::mem::swap(a, b);
+    /// assert_eq!(data, &[5, 2, 3, 4, 1, 6]);
+    /// ```
+    pub unsafe fn get_disjoint_unchecked_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> [&mut T; N] {
+        let ptr = self.as_mut_ptr();
+        indices.map(|index| unsafe { &mut *ptr.add(index * S) })
+    }
+
+    /// Returns a reference to the first element of the strided slice, or `None`
+    /// if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(stride.first(), Some(&1));
+    ///
+    /// let stride = Stride::<i32, 2>::new(&[]);
+    /// assert_eq!(stride.first(), None);
+    /// ```
+    pub const fn first(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns a mutable reference to the first element of the strided slice,
+    /// or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// if let Some(first) = stride.first_mut() {
+    ///     *first = 7;
+    /// }
+    /// assert_eq!(data, &[7, 2, 3, 4, 5, 6]);
+    /// ```
+    pub const fn first_mut(&mut self) -> Option<&mut T> {
+        self.data.first_mut()
+    }
+
+    /// Returns a reference to the last element of the strided slice, or `None`
+    /// if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(stride.last(), Some(&5));
+    ///
+    /// let stride = Stride::<i32, 2>::new(&[]);
+    /// assert_eq!(stride.last(), None);
+    /// ```
+    pub const fn last(&self) -> Option<&T> {
+        match self.len() {
+            0 => None,
+            len => Some(&self.data[(len - 1) * S]),
+        }
+    }
+
+    /// Returns a mutable reference to the last element of the strided slice, or
+    /// `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// if let Some(last) = stride.last_mut() {
+    ///     *last = 7;
+    /// }
+    /// assert_eq!(data, &[1, 2, 3, 4, 7, 6]);
+    /// ```
+    pub const fn last_mut(&mut self) -> Option<&mut T> {
+        match self.len() {
+            0 => None,
+            len => Some(&mut self.data[(len - 1) * S]),
+        }
+    }
+
+    /// Returns the first element and the rest of the strided slice, or `None`
+    /// if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// if let Some((first, rest)) = stride.split_first() {
+    ///     assert_eq!(first, &1);
+    ///     assert_eq!(rest, &[3, 5]);
+    /// }
+    /// ```
+    pub const fn split_first(&self) -> Option<(&T, &Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (first, rest) = self.data.split_at(min(S, self.data.len()));
+        Some((&first[0], Stride::new(rest)))
+    }
+
+    /// Returns the first element and the rest of the strided slice as mutable
+    /// references, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// if let Some((first, rest)) = stride.split_first_mut() {
+    ///     *first = 7;
+    ///     rest[1] = 8;
+    /// }
+    /// assert_eq!(data, &[7, 2, 3, 4, 8, 6]);
+    /// ```
+    pub const fn split_first_mut(&mut self) -> Option<(&mut T, &mut Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let mid = min(S, self.data.len());
+        let (first, rest) = self.data.split_at_mut(mid);
+        Some((&mut first[0], Stride::new_mut(rest)))
+    }
+
+    /// Returns the last element and the rest of the strided slice, or `None`
+    /// if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// if let Some((last, rest)) = stride.split_last() {
+    ///     assert_eq!(last, &5);
+    ///     assert_eq!(rest, &[1, 3]);
+    /// }
+    /// ```
+    pub const fn split_last(&self) -> Option<(&T, &Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (rest, last) = self.data.split_at((self.len() - 1) * S);
+        Some((&last[0], Stride::new(rest)))
+    }
+
+    /// Returns the last element and the rest of the strided slice as mutable
+    /// references, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// if let Some((last, rest)) = stride.split_last_mut() {
+    ///     *last = 7;
+    ///     rest[1] = 8;
+    /// }
+    /// assert_eq!(data, &[1, 2, 8, 4, 7, 6]);
+    /// ```
+    pub const fn split_last_mut(&mut self) -> Option<(&mut T, &mut Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let mid = (self.len() - 1) * S;
+        let (rest, last) = self.data.split_at_mut(mid);
+        Some((&mut last[0], Stride::new_mut(rest)))
+    }
+
+    /// Divides one strided slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index
+    /// `mid` itself) and the second will contain all indices from `[mid, len)`
+    /// (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// If `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let (left, right) = stride.split_at(1);
+    /// assert_eq!(left, &[1]);
+    /// assert_eq!(right, &[3, 5]);
+    /// ```
+    #[track_caller]
+    pub const fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        assert!(mid <= self.len(), "mid > len");
+        let (left, right) = self
+            .data
+            .split_at(min(mid.saturating_mul(S), self.data.len()));
+        (Stride::new(left), Stride::new(right))
+    }
+
+    /// Divides one mutable strided slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index
+    /// `mid` itself) and the second will contain all indices from `[mid, len)`
+    /// (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// If `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let (left, right) = stride.split_at_mut(1);
+    /// left[0] = 7;
+    /// right[1] = 8;
+    /// assert_eq!(data, &[7, 2, 3, 4, 8, 6]);
+    /// ```
+    #[track_caller]
+    pub const fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+        assert!(mid <= self.len(), "mid > len");
+        let mid = min(mid.saturating_mul(S), self.data.len());
+        let (left, right) = self.data.split_at_mut(mid);
+        (Stride::new_mut(left), Stride::new_mut(right))
+    }
+
+    /// Swaps two elements in the strided slice.
+    ///
+    /// # Arguments
+    ///
+    /// - `a` - The index of the first element
+    /// - `b` - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.swap(0, 2);
+    /// assert_eq!(data, &[5, 2, 3, 4, 1, 6]);
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        for index in [a, b] {
+            if index >= len {
+                index::index_fail(index, len, S);
+            }
+        }
+        unsafe { self.swap_unchecked(a, b) }
+    }
+
+    /// Swaps two elements in the strided slice, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`swap`].
+    ///
+    /// # Arguments
+    ///
+    /// - `a` - The index of the first element
+    /// - `b` - The index of the second element
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*.
+    /// The caller has to ensure that `a < self.len()` and `b < self.len()`.
+    ///
+    /// [`swap`]: #method.swap
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// unsafe { stride.swap_unchecked(0, 2) };
+    /// assert_eq!(data, &[5, 2, 3, 4, 1, 6]);
+    /// ```
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let ptr = self.as_mut_ptr();
+        unsafe { ptr::swap(ptr.add(a * S), ptr.add(b * S)) }
+    }
+
+    /// Returns `true` if the strided slice contains an element with the given
+    /// value.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are not considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(stride.contains(&3));
+    /// assert!(!stride.contains(&4));
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|elem| elem == x)
+    }
+
+    /// Returns `true` if `needle` is a prefix of the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(stride.starts_with(&[1, 3]));
+    /// assert!(!stride.starts_with(&[1, 2]));
+    /// assert!(stride.starts_with(&[]));
+    /// ```
+    pub fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        let n = needle.len();
+        self.len() >= n && self[..n] == *needle
+    }
+
+    /// Returns `true` if `needle` is a suffix of the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(stride.ends_with(&[3, 5]));
+    /// assert!(!stride.ends_with(&[5, 6]));
+    /// assert!(stride.ends_with(&[]));
+    /// ```
+    pub fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        let (m, n) = (self.len(), needle.len());
+        m >= n && self[m - n..] == *needle
+    }
+
+    /// Returns `true` if another strided slice `needle` is a prefix of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(stride.starts_with_stride(Stride::<_, 3>::new(&[1, 0, 0, 3])));
+    /// ```
+    pub fn starts_with_stride<const R: usize>(&self, needle: &Stride<T, R>) -> bool
+    where
+        T: PartialEq,
+    {
+        let n = needle.len();
+        self.len() >= n && self[..n] == *needle
+    }
+
+    /// Returns `true` if another strided slice `needle` is a suffix of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// assert!(stride.ends_with_stride(Stride::<_, 3>::new(&[3, 0, 0, 5])));
+    /// ```
+    pub fn ends_with_stride<const R: usize>(&self, needle: &Stride<T, R>) -> bool
+    where
+        T: PartialEq,
+    {
+        let (m, n) = (self.len(), needle.len());
+        m >= n && self[m - n..] == *needle
+    }
+
+    /// Binary searches this sorted strided slice for a given element.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the
+    /// index of the matching element. If there are multiple matches, then any
+    /// one of the matches could be returned. If the value is not found then
+    /// [`Result::Err`] is returned, containing the index where a matching
+    /// element could be inserted while maintaining sorted order.
+    ///
+    /// *See [`slice::binary_search()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 9, 3, 9, 5, 0, 7]);
+    /// assert_eq!(stride.binary_search(&5), Ok(2));
+    /// assert_eq!(stride.binary_search(&4), Err(2));
+    /// assert_eq!(stride.binary_search(&9), Err(4));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|p| p.cmp(x))
+    }
+
+    /// Binary searches this sorted strided slice with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` than the desired
+    /// target. The return value is interpreted the same way as for
+    /// [`binary_search`].
+    ///
+    /// [`binary_search`]: #method.binary_search
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 9, 3, 9, 5, 0, 7]);
+    /// assert_eq!(stride.binary_search_by(|x| x.cmp(&3)), Ok(1));
+    /// ```
+    pub fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        let mut left = 0;
+        let mut right = self.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            match f(unsafe { self.get_unchecked(mid) }) {
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(left)
+    }
+
+    /// Binary searches this sorted strided slice with a key extraction
+    /// function.
+    ///
+    /// Assumes that the strided slice is sorted by the key. The return value is
+    /// interpreted the same way as for [`binary_search`].
+    ///
+    /// [`binary_search`]: #method.binary_search
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[(1, 'a'), (0, 'z'), (2, 'b'), (0, 'z')]);
+    /// assert_eq!(stride.binary_search_by_key(&2, |&(k, _)| k), Ok(1));
+    /// assert_eq!(stride.binary_search_by_key(&3, |&(k, _)| k), Err(2));
+    /// ```
+    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate (the index of the first element of the second partition).
+    ///
+    /// The strided slice is assumed to be partitioned according to the given
+    /// predicate. This means that all elements for which the predicate returns
+    /// `true` are at the start of the strided slice and all elements for which
+    /// the predicate returns `false` are at the end.
+    ///
+    /// *See [`slice::partition_point()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 3, 0, 5, 0, 6]);
+    /// assert_eq!(stride.partition_point(|&x| x < 5), 3);
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.binary_search_by(|x| {
+            if pred(x) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+
+    /// Returns `true` if the elements of the strided slice are sorted.
+    ///
+    /// *See [`slice::is_sorted()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// assert!(Stride::<_, 2>::new(&[1, 9, 2, 0, 2, 9, 4]).is_sorted());
+    /// assert!(!Stride::<_, 2>::new(&[1, 0, 3, 0, 2, 0, 4]).is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Returns `true` if the elements of the strided slice are sorted using the
+    /// given comparator function.
+    ///
+    /// The comparator is given each pair of consecutive elements and should
+    /// return `true` if they are in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[4, 0, 2, 0, 2, 0, 1]);
+    /// assert!(stride.is_sorted_by(|a, b| a >= b));
+    /// assert!(!stride.is_sorted_by(|a, b| a > b));
+    /// ```
+    pub fn is_sorted_by<'a, F>(&'a self, mut compare: F) -> bool
+    where
+        F: FnMut(&'a T, &'a T) -> bool,
+    {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(a, b)| compare(a, b))
+    }
+
+    /// Returns `true` if the elements of the strided slice are sorted using the
+    /// given key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1i32, 0, -2, 0, 3, 0, -4]);
+    /// assert!(stride.is_sorted_by_key(|x| x.abs()));
+    /// assert!(!stride.is_sorted());
+    /// ```
+    pub fn is_sorted_by_key<'a, K, F>(&'a self, f: F) -> bool
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialOrd,
+    {
+        let mut iter = self.iter().map(f);
+        let mut prev = match iter.next() {
+            Some(key) => key,
+            None => return true,
+        };
+        for key in iter {
+            // Incomparable keys are not in order, like in `is_sorted()`.
+            if prev <= key {
+                prev = key;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Sorts the strided slice.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*n* \* log(*n*)) worst-case. It allocates a buffer of indexes the
+    /// same length as the strided slice. Elements in the underlying slice that
+    /// are not part of the strided slice are left untouched.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort();
+    /// assert_eq!(data, &[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        sort::stable_sort(self, &mut T::cmp);
+    }
+
+    /// Sorts the strided slice with a comparator function.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// *See [`sort`] and [`slice::sort_by()`].*
+    ///
+    /// [`sort`]: #method.sort
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(data, &[5, 0, 4, 0, 3, 0, 2, 0, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::stable_sort(self, &mut compare);
+    }
+
+    /// Sorts the strided slice with a key extraction function.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// *See [`sort`] and [`slice::sort_by_key()`].*
+    ///
+    /// [`sort`]: #method.sort
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [-5i32, 0, 4, 0, 1, 0, -3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_by_key(|k| k.abs());
+    /// assert_eq!(data, &[1, 0, 2, 0, -3, 0, 4, 0, -5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::stable_sort(self, &mut |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the strided slice with a key extraction function, calling the key
+    /// function only once per element.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*m* \* *n* + *n* \* log(*n*)) worst-case, where the key function is
+    /// *O*(*m*). It allocates a buffer of keys the same length as the strided
+    /// slice.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// *See [`slice::sort_by_cached_key()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [-5i32, 0, 4, 0, 32, 0, -3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_by_cached_key(|k| k.to_string());
+    /// assert_eq!(data, &[-3, 0, -5, 0, 2, 0, 32, 0, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::cached_key_sort(self, &mut f);
+    }
+
+    /// Sorts the strided slice, but might not preserve the order of equal
+    /// elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_unstable();
+    /// assert_eq!(data, &[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        sort::quicksort(self, &mut T::lt);
+    }
+
+    /// Sorts the strided slice with a comparator function, but might not
+    /// preserve the order of equal elements.
+    ///
+    /// *See [`sort_unstable`] and [`slice::sort_unstable_by()`].*
+    ///
+    /// [`sort_unstable`]: #method.sort_unstable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(data, &[5, 0, 4, 0, 3, 0, 2, 0, 1]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::quicksort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the strided slice with a key extraction function, but might not
+    /// preserve the order of equal elements.
+    ///
+    /// *See [`sort_unstable`] and [`slice::sort_unstable_by_key()`].*
+    ///
+    /// [`sort_unstable`]: #method.sort_unstable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [-5i32, 0, 4, 0, 1, 0, -3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.sort_unstable_by_key(|k| k.abs());
+    /// assert_eq!(data, &[1, 0, 2, 0, -3, 0, 4, 0, -5]);
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::quicksort(self, &mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Reorders the strided slice such that the element at `index` is at its
+    /// final sorted position.
+    ///
+    /// All elements before `index` are less than or equal to it and all
+    /// elements after `index` are greater than or equal to it. This reordering
+    /// is unstable, in-place, and *O*(*n*) on average. Returns the strided
+    /// slice before `index`, the element at `index`, and the strided slice
+    /// after `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let (_, median, _) = stride.select_nth_unstable(2);
+    /// assert_eq!(median, &mut 3);
+    /// ```
+    #[track_caller]
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut Self, &mut T, &mut Self)
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable_by(index, T::cmp)
+    }
+
+    /// Reorders the strided slice with a comparator function such that the
+    /// element at `index` is at its final sorted position.
+    ///
+    /// *See [`select_nth_unstable`].*
+    ///
+    /// [`select_nth_unstable`]: #method.select_nth_unstable
+    ///
+    /// # Panics
+    ///
+    /// If `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let (_, second, _) = stride.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(second, &mut 4);
+    /// ```
+    #[track_caller]
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        mut compare: F,
+    ) -> (&mut Self, &mut T, &mut Self)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        assert!(
+            index < len,
+            "partition index {} greater than length of stride {}",
+            index,
+            len
+        );
+        sort::select(self, index, &mut |a, b| compare(a, b) == Ordering::Less);
+        let (left, rest) = self.split_at_mut(index);
+        let (nth, right) = rest.split_first_mut().unwrap();
+        (left, nth, right)
+    }
+
+    /// Reorders the strided slice with a key extraction function such that the
+    /// element at `index` is at its final sorted position.
+    ///
+    /// *See [`select_nth_unstable`].*
+    ///
+    /// [`select_nth_unstable`]: #method.select_nth_unstable
+    ///
+    /// # Panics
+    ///
+    /// If `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [-5i32, 0, 4, 0, 1, 0, -3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let (_, median, _) = stride.select_nth_unstable_by_key(2, |k| k.abs());
+    /// assert_eq!(median, &mut -3);
+    /// ```
+    #[track_caller]
+    pub fn select_nth_unstable_by_key<K, F>(
+        &mut self,
+        index: usize,
+        mut f: F,
+    ) -> (&mut Self, &mut T, &mut Self)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.select_nth_unstable_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Reverses the order of elements in the strided slice, in place.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.reverse();
+    /// assert_eq!(data, &[5, 2, 3, 4, 1, 6]);
+    /// ```
+    pub fn reverse(&mut self) {
+        let len = self.len();
+        let (left, right) = self.split_at_mut(len / 2);
+        for (a, b) in left.iter_mut().zip(right.iter_mut().rev()) {
+            mem::swap(a, b);
+        }
+    }
+
+    /// Rotates the strided slice in-place such that the first `mid` elements
+    /// move to the end while the last `len - mid` elements move to the front.
+    ///
+    /// After calling `rotate_left`, the element previously at index `mid` will
+    /// become the first element in the strided slice.
+    ///
+    /// # Panics
+    ///
+    /// If `mid` is greater than the length of the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 3, 0, 4, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.rotate_left(1);
+    /// assert_eq!(data, &[2, 0, 3, 0, 4, 0, 1, 0]);
+    /// ```
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "mid > len");
+        let (left, right) = self.split_at_mut(mid);
+        left.reverse();
+        right.reverse();
+        self.reverse();
+    }
+
+    /// Rotates the strided slice in-place such that the first `len - k`
+    /// elements move to the end while the last `k` elements move to the front.
+    ///
+    /// After calling `rotate_right`, the element previously at index `len - k`
+    /// will become the first element in the strided slice.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is greater than the length of the strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 3, 0, 4, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.rotate_right(1);
+    /// assert_eq!(data, &[4, 0, 1, 0, 2, 0, 3, 0]);
+    /// ```
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "k > len");
+        self.rotate_left(self.len() - k);
+    }
+
+    /// Fills the strided slice with elements by cloning `value`.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched. The last element is moved into place instead of
+    /// cloned.
+    ///
+    /// For `Copy` types `clone_from()` is a plain store, so this is a loop of
+    /// stores without any calls. With the `portable_simd` feature, primitive
+    /// numbers with a small stride are filled several elements at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.fill(0);
+    /// assert_eq!(data, &[0, 2, 0, 4, 0, 6]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "portable_simd")]
+        simd::SpecFill::fill(self, value);
+        #[cfg(not(feature = "portable_simd"))]
+        self.fill_scalar(value);
+    }
+
+    /// Fills the strided slice by cloning `value`, one element at a time.
+    fn fill_scalar(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        if let Some((last, elems)) = self.split_last_mut() {
+            for elem in elems {
+                elem.clone_from(&value);
+            }
+            *last = value;
+        }
+    }
+
+    /// Fills the strided slice with elements returned by calling a closure
+    /// repeatedly.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let mut n = 0;
+    /// stride.fill_with(|| {
+    ///     n += 10;
+    ///     n
+    /// });
+    /// assert_eq!(data, &[10, 2, 20, 4, 30, 6]);
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for elem in self {
+            *elem = f();
+        }
+    }
+
+    /// Copies all elements from `src` into the strided slice, using a memcpy
+    /// per element.
+    ///
+    /// The length of `src` must be the same as the strided slice. Elements in
+    /// the underlying slice that are not part of the strided slice are left
+    /// untouched.
+    ///
+    /// With the `portable_simd` feature, primitive numbers with a small stride
+    /// are copied several elements at a time instead.
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.copy_from_slice(&[7, 8, 9]);
+    /// assert_eq!(data, &[7, 2, 8, 4, 9, 6]);
+    /// ```
+    #[track_caller]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        if self.len() != src.len() {
+            len_mismatch_fail(self.len(), src.len());
+        }
+        #[cfg(feature = "portable_simd")]
+        simd::SpecCopy::copy_from_slice(self, src);
+        #[cfg(not(feature = "portable_simd"))]
+        self.copy_from_slice_scalar(src);
+    }
+
+    /// Copies all elements from `src`, which must have the same length, one
+    /// at a time.
+    fn copy_from_slice_scalar(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        let dst = self.as_mut_ptr();
+        for (i, elem) in src.iter().enumerate() {
+            unsafe { *dst.add(i * S) = *elem };
+        }
+    }
+
+    /// Copies the elements from `src` into the strided slice, cloning each one.
+    ///
+    /// The length of `src` must be the same as the strided slice. Elements in
+    /// the underlying slice that are not part of the strided slice are left
+    /// untouched.
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut ["a", "b", "c", "d"].map(String::from);
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.clone_from_slice(&["e", "f"].map(String::from));
+    /// assert_eq!(data, &["e", "b", "f", "d"]);
+    /// ```
+    #[track_caller]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        if self.len() != src.len() {
+            len_mismatch_fail(self.len(), src.len());
+        }
+        for (dst, src) in self.iter_mut().zip(src) {
+            dst.clone_from(src);
+        }
+    }
+
+    /// Copies all elements from the strided slice into `dst`.
+    ///
+    /// The length of `dst` must be the same as the strided slice. This is the
+    /// inverse of [`copy_from_slice`].
+    ///
+    /// With the `portable_simd` feature, primitive numbers with a small stride
+    /// are copied several elements at a time.
+    ///
+    /// [`copy_from_slice`]: #method.copy_from_slice
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let mut dst = [0; 3];
+    /// stride.copy_to_slice(&mut dst);
+    /// assert_eq!(dst, [1, 3, 5]);
+    /// ```
+    #[track_caller]
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        if self.len() != dst.len() {
+            len_mismatch_fail(dst.len(), self.len());
+        }
+        // SAFETY: only initialized elements are written to `dst`.
+        let dst = unsafe { &mut *(dst as *mut [T] as *mut [MaybeUninit<T>]) };
+        self.copy_to_uninit(dst);
+    }
+
+    /// Copies all elements into `dst`, which must have the same length.
+    fn copy_to_uninit(&self, dst: &mut [MaybeUninit<T>])
+    where
+        T: Copy,
+    {
+        #[cfg(feature = "portable_simd")]
+        simd::SpecCopy::copy_to_uninit(self, dst);
+        #[cfg(not(feature = "portable_simd"))]
+        self.copy_to_uninit_scalar(dst);
+    }
+
+    /// Copies all elements into `dst`, which must have the same length, one at
+    /// a time.
+    fn copy_to_uninit_scalar(&self, dst: &mut [MaybeUninit<T>])
+    where
+        T: Copy,
+    {
+        let src = self.as_ptr();
+        for (i, elem) in dst.iter_mut().enumerate() {
+            elem.write(unsafe { *src.add(i * S) });
+        }
+    }
+
+    /// Copies all elements from the strided slice into a new array.
+    ///
+    /// Returns `None` if the length of the strided slice is not `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let matrix = &[1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let column = Stride::<_, 3>::new(&matrix[1..]);
+    /// assert_eq!(column.collect_array(), Some([2, 5, 8]));
+    /// assert_eq!(column.collect_array::<2>(), None);
+    /// ```
+    pub fn collect_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        if self.len() != N {
+            return None;
+        }
+        let src = self.as_ptr();
+        Some(array::from_fn(|i| unsafe { *src.add(i * S) }))
+    }
+
+    /// Copies all elements from another strided slice `src` into this one.
+    ///
+    /// The strides of the two strided slices may differ but their lengths must
+    /// be the same. Elements in the underlying slice that are not part of the
+    /// strided slice are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let stride = Stride::<_, 4>::new_mut(data);
+    /// stride.copy_from_stride(Stride::<_, 2>::new(&[9, 0, 10, 0]));
+    /// assert_eq!(data, &[9, 2, 3, 4, 10, 6, 7, 8]);
+    /// ```
+    #[track_caller]
+    pub fn copy_from_stride<const R: usize>(&mut self, src: &Stride<T, R>)
+    where
+        T: Copy,
+    {
+        if self.len() != src.len() {
+            len_mismatch_fail(self.len(), src.len());
+        }
+        let dst = self.as_mut_ptr();
+        let src_ptr = src.as_ptr();
+        for i in 0..src.len() {
+            unsafe { *dst.add(i * S) = *src_ptr.add(i * R) };
+        }
+    }
+
+    /// Swaps all elements in the strided slice with those in `other`.
+    ///
+    /// The length of `other` must be the same as the strided slice.
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let other = &mut [7, 8, 9];
+    /// stride.swap_with_slice(other);
+    /// assert_eq!(data, &[7, 2, 8, 4, 9, 6]);
+    /// assert_eq!(other, &[1, 3, 5]);
+    /// ```
+    #[track_caller]
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        if self.len() != other.len() {
+            len_mismatch_fail(self.len(), other.len());
+        }
+        for (a, b) in self.iter_mut().zip(other) {
+            mem::swap(a, b);
+        }
+    }
+
+    /// Swaps all elements in the strided slice with those in another strided
+    /// slice `other`.
+    ///
+    /// The strides of the two strided slices may differ but their lengths must
+    /// be the same.
+    ///
+    /// # Panics
+    ///
+    /// If the two lengths are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let a = &mut [1, 2, 3, 4];
+    /// let b = &mut [5, 6, 7, 8, 9, 10];
+    /// Stride::<_, 2>::new_mut(a).swap_with_stride(Stride::<_, 3>::new_mut(b));
+    /// assert_eq!(a, &[5, 2, 8, 4]);
+    /// assert_eq!(b, &[1, 6, 7, 3, 9, 10]);
+    /// ```
+    #[track_caller]
+    pub fn swap_with_stride<const R: usize>(&mut self, other: &mut Stride<T, R>) {
+        if self.len() != other.len() {
+            len_mismatch_fail(self.len(), other.len());
+        }
+        for (a, b) in self.iter_mut().zip(other) {
+            mem::swap(a, b);
+        }
+    }
+
+    /// Copies elements from one part of the strided slice to another part of
+    /// itself.
+    ///
+    /// `src` is the range within the strided slice to copy from. `dest` is the
+    /// starting index of the range within the strided slice to copy to, which
+    /// will have the same length as `src`. The two ranges may overlap.
+    ///
+    /// # Panics
+    ///
+    /// If either range exceeds the end of the strided slice, or if the end of
+    /// `src` is before the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 3, 0, 4, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.copy_within(..3, 1);
+    /// assert_eq!(data, &[1, 0, 1, 0, 2, 0, 3, 0]);
+    /// ```
+    #[track_caller]
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        let Range { start, end } = index::range::<_, S>(src, self.len());
+        let count = end - start;
+        assert!(dest <= self.len() - count, "dest is out of bounds");
+        let ptr = self.as_mut_ptr();
+        if dest < start {
+            for i in 0..count {
+                unsafe { *ptr.add((dest + i) * S) = *ptr.add((start + i) * S) };
+            }
+        } else {
+            for i in (0..count).rev() {
+                unsafe { *ptr.add((dest + i) * S) = *ptr.add((start + i) * S) };
+            }
+        }
+    }
+
+    /// Returns an iterator over the stride.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let mut iterator = stride.iter();
+    /// assert_eq!(iterator.next(), Some(&1));
+    /// assert_eq!(iterator.next(), Some(&3));
+    /// assert_eq!(iterator.next(), Some(&5));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, S> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over the stride that allows modifying each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let slice = &mut [1, 1, 2, 2, 3, 3];
+    /// let stride = Stride::<_, 2>::new_mut(slice);
+    /// for elem in stride.iter_mut() {
+    ///     *elem *= 2;
+    /// }
+    /// assert_eq!(slice, &[2, 1, 4, 2, 6, 3]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, S> {
+        IterMut::new(self)
+    }
+
+    /// Returns an iterator over the strided slice yielding each element
+    /// together with its index in the underlying slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    /// let mut iter = stride.iter_with_raw_indices();
+    /// assert_eq!(iter.next(), Some((0, &1)));
+    /// assert_eq!(iter.next(), Some((3, &4)));
+    /// assert_eq!(iter.next(), Some((6, &7)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_with_raw_indices(&self) -> IterWithRawIndices<'_, T, S> {
+        IterWithRawIndices::new(self)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the beginning of the strided slice.
+    ///
+    /// The chunks are strided slices and do not overlap. If `chunk_size` does
+    /// not divide the length of the strided slice, then the last chunk will not
+    /// have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let mut iter = stride.chunks(2);
+    /// assert_eq!(iter.next().unwrap(), &[1, 3]);
+    /// assert_eq!(iter.next().unwrap(), &[5, 7]);
+    /// assert_eq!(iter.next().unwrap(), &[9]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        Chunks::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the beginning of the strided slice.
+    ///
+    /// The chunks are mutable strided slices and do not overlap. If
+    /// `chunk_size` does not divide the length of the strided slice, then the
+    /// last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for (i, chunk) in stride.chunks_mut(2).enumerate() {
+    ///     chunk.fill(i + 1);
+    /// }
+    /// assert_eq!(data, &[1, 0, 1, 0, 2, 0, 2, 0, 3]);
+    /// ```
+    #[track_caller]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ChunksMut::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the beginning of the strided slice.
+    ///
+    /// The chunks are strided slices and do not overlap. If `chunk_size` does
+    /// not divide the length of the strided slice, then the last up to
+    /// `chunk_size - 1` elements will be omitted and can be retrieved from the
+    /// [`remainder()`][`ChunksExact::remainder()`] method of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let mut iter = stride.chunks_exact(2);
+    /// assert_eq!(iter.next().unwrap(), &[1, 3]);
+    /// assert_eq!(iter.next().unwrap(), &[5, 7]);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &[9]);
+    /// ```
+    #[track_caller]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ChunksExact::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the beginning of the strided slice.
+    ///
+    /// The chunks are mutable strided slices and do not overlap. If
+    /// `chunk_size` does not divide the length of the strided slice, then the
+    /// last up to `chunk_size - 1` elements will be omitted and can be
+    /// retrieved from the [`into_remainder()`][`ChunksExactMut::into_remainder()`]
+    /// method of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let mut iter = stride.chunks_exact_mut(2);
+    /// for (i, chunk) in iter.by_ref().enumerate() {
+    ///     chunk.fill(i + 1);
+    /// }
+    /// iter.into_remainder().fill(9);
+    /// assert_eq!(data, &[1, 0, 1, 0, 2, 0, 2, 0, 9]);
+    /// ```
+    #[track_caller]
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ChunksExactMut::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over `N` elements of the strided slice at a time,
+    /// starting at the beginning of the strided slice.
+    ///
+    /// The chunks are arrays of references to the elements and do not overlap.
+    /// If `N` does not divide the length of the strided slice, then the last up
+    /// to `N - 1` elements will be omitted and can be retrieved from the
+    /// [`remainder()`][`ArrayChunks::remainder()`] method of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    /// let mut iter = stride.array_chunks();
+    /// assert_eq!(iter.next(), Some([&1, &2]));
+    /// assert_eq!(iter.next(), Some([&3, &4]));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), &[5]);
+    /// ```
+    #[track_caller]
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, S, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunks::new(self)
+    }
+
+    /// Returns an iterator over `N` elements of the strided slice at a time,
+    /// starting at the beginning of the strided slice.
+    ///
+    /// The chunks are arrays of mutable references to the elements and do not
+    /// overlap. If `N` does not divide the length of the strided slice, then
+    /// the last up to `N - 1` elements will be omitted and can be retrieved
+    /// from the [`into_remainder()`][`ArrayChunksMut::into_remainder()`] method
+    /// of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 3, 0, 4, 0, 5];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for [a, b] in stride.array_chunks_mut() {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(data, &[2, 0, 1, 0, 4, 0, 3, 0, 5]);
+    /// ```
+    #[track_caller]
+    pub fn array_chunks_mut<const N: usize>(&mut self) -> ArrayChunksMut<'_, T, S, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunksMut::new(self)
+    }
+
+    /// Returns an iterator over the frames of the underlying slice.
+    ///
+    /// Each frame is the `S` elements of the underlying slice starting at an
+    /// element of the strided slice, i.e. the element itself followed by the
+    /// elements up to the next one.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the underlying slice is not a multiple of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// // Interleaved stereo samples.
+    /// let data = &[1, -1, 2, -2, 3, -3];
+    /// let stride = Stride::<_, 2>::new(data);
+    /// let mut frames = stride.frames();
+    /// assert_eq!(frames.next(), Some(&[1, -1]));
+    /// assert_eq!(frames.next(), Some(&[2, -2]));
+    /// assert_eq!(frames.next(), Some(&[3, -3]));
+    /// assert_eq!(frames.next(), None);
+    /// ```
+    #[track_caller]
+    pub fn frames(&self) -> slice::Iter<'_, [T; S]> {
+        match self.as_arrays() {
+            Ok(frames) => frames.iter(),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Returns an iterator over the frames of the underlying slice that
+    /// allows modifying each frame.
+    ///
+    /// *See [`frames()`][`Stride::frames()`].*
+    ///
+    /// # Panics
+    ///
+    /// If the length of the underlying slice is not a multiple of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, -1, 2, -2, 3, -3];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for [left, right] in stride.frames_mut() {
+    ///     core::mem::swap(left, right);
+    /// }
+    /// assert_eq!(data, &[-1, 1, -2, 2, -3, 3]);
+    /// ```
+    #[track_caller]
+    pub fn frames_mut(&mut self) -> slice::IterMut<'_, [T; S]> {
+        match self.as_arrays_mut() {
+            Ok(frames) => frames.iter_mut(),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the end of the strided slice.
+    ///
+    /// The chunks are strided slices and do not overlap. If `chunk_size` does
+    /// not divide the length of the strided slice, then the last chunk will not
+    /// have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let mut iter = stride.rchunks(2);
+    /// assert_eq!(iter.next().unwrap(), &[7, 9]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 5]);
+    /// assert_eq!(iter.next().unwrap(), &[1]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn rchunks(&self, chunk_size: usize) -> RChunks<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        RChunks::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the strided slice at a
+    /// time, starting at the end of the strided slice.
+    ///
+    /// The chunks are mutable strided slices and do not overlap. If
+    /// `chunk_size` does not divide the length of the strided slice, then the
+    /// last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for (i, chunk) in stride.rchunks_mut(2).enumerate() {
+    ///     chunk.fill(i + 1);
+    /// }
+    /// assert_eq!(data, &[3, 0, 2, 0, 2, 0, 1, 0, 1]);
+    /// ```
+    #[track_caller]
+    pub fn rchunks_mut(&mut self, chunk_size: usize) -> RChunksMut<'_, T, S> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        RChunksMut::new(self, chunk_size)
+    }
+
+    /// Returns an iterator over the strided slice split into `n` parts of
+    /// roughly equal length.
+    ///
+    /// The parts are strided slices and do not overlap. Their lengths differ
+    /// by at most one, with the longer parts first, and if `n` is greater than
+    /// the length of the strided slice then the last parts are empty.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    /// let mut iter = stride.split_into(3);
+    /// assert_eq!(iter.next().unwrap(), &[1, 2]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 4]);
+    /// assert_eq!(iter.next().unwrap(), &[5]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn split_into(&self, n: usize) -> SplitInto<'_, T, S> {
+        assert!(n != 0, "number of parts must be non-zero");
+        SplitInto::new(self, n)
+    }
+
+    /// Returns an iterator over the strided slice split into `n` mutable parts
+    /// of roughly equal length.
+    ///
+    /// The parts are mutable strided slices and do not overlap, so they can be
+    /// sent to different threads. Their lengths differ by at most one, with the
+    /// longer parts first, and if `n` is greater than the length of the strided
+    /// slice then the last parts are empty.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::thread;
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [0; 10];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// thread::scope(|s| {
+    ///     for (i, part) in stride.split_into_mut(2).enumerate() {
+    ///         s.spawn(move || part.fill(i + 1));
+    ///     }
+    /// });
+    /// assert_eq!(data, &[1, 0, 1, 0, 1, 0, 2, 0, 2, 0]);
+    /// ```
+    #[track_caller]
+    pub fn split_into_mut(&mut self, n: usize) -> SplitIntoMut<'_, T, S> {
+        assert!(n != 0, "number of parts must be non-zero");
+        SplitIntoMut::new(self, n)
+    }
+
+    /// Returns an iterator over the strided slice producing non-overlapping
+    /// runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements, meaning
+    /// that it is called on `stride[0]` and `stride[1]`, followed by `stride[1]`
+    /// and `stride[2]`, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 1, 0, 2, 0, 3, 0, 3]);
+    /// let mut iter = stride.chunk_by(|a, b| a == b);
+    /// assert_eq!(iter.next().unwrap(), &[1, 1]);
+    /// assert_eq!(iter.next().unwrap(), &[2]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn chunk_by<P>(&self, pred: P) -> ChunkBy<'_, T, P, S>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        ChunkBy::new(self, pred)
+    }
+
+    /// Returns an iterator over the strided slice producing non-overlapping
+    /// mutable runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements, meaning
+    /// that it is called on `stride[0]` and `stride[1]`, followed by `stride[1]`
+    /// and `stride[2]`, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 3, 0, 1, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for run in stride.chunk_by_mut(|a, b| a <= b) {
+    ///     run.reverse();
+    /// }
+    /// assert_eq!(data, &[3, 0, 2, 0, 1, 0, 2, 0, 1]);
+    /// ```
+    pub fn chunk_by_mut<P>(&mut self, pred: P) -> ChunkByMut<'_, T, P, S>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        ChunkByMut::new(self, pred)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`.
+    ///
+    /// The windows are strided slices and overlap. If the strided slice is
+    /// shorter than `size`, the iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    /// let mut iter = stride.windows(2);
+    /// assert_eq!(iter.next().unwrap(), &[1, 3]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 5]);
+    /// assert_eq!(iter.next().unwrap(), &[5, 7]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn windows(&self, size: usize) -> Windows<'_, T, S> {
+        assert!(size != 0, "window size must be non-zero");
+        Windows::new(self, size)
+    }
+
+    /// Returns an iterator over strided subslices separated by elements that
+    /// match `pred`. The matched element is not contained in the subslices.
+    ///
+    /// If the first element is matched, an empty strided slice will be the
+    /// first item returned by the iterator. Similarly, if the last element is
+    /// matched, an empty strided slice will be the last item returned by the
+    /// iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 0, 0, 3, 0, 4]);
+    /// let mut iter = stride.split(|&x| x == 0);
+    /// assert_eq!(iter.next().unwrap(), &[1, 2]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 4]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split<P>(&self, pred: P) -> Split<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        Split::new(self, pred)
+    }
+
+    /// Returns an iterator over mutable strided subslices separated by elements
+    /// that match `pred`. The matched element is not contained in the
+    /// subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 2, 0, 0, 0, 3, 0, 4];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for group in stride.split_mut(|&x| x == 0) {
+    ///     group.reverse();
+    /// }
+    /// assert_eq!(data, &[2, 0, 1, 0, 0, 0, 4, 0, 3]);
+    /// ```
+    pub fn split_mut<P>(&mut self, pred: P) -> SplitMut<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitMut::new(self, pred)
+    }
+
+    /// Returns an iterator over strided subslices separated by elements that
+    /// match `pred`. The matched element is contained in the end of the
+    /// previous subslice as a terminator.
+    ///
+    /// If the last element of the strided slice is matched, that element will
+    /// be considered the terminator of the preceding subslice, so no empty
+    /// subslice is returned at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 0, 0, 2, 0, 3, 0, 0]);
+    /// let mut iter = stride.split_inclusive(|&x| x == 0);
+    /// assert_eq!(iter.next().unwrap(), &[1, 0]);
+    /// assert_eq!(iter.next().unwrap(), &[2, 3, 0]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split_inclusive<P>(&self, pred: P) -> SplitInclusive<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitInclusive::new(self, pred)
+    }
+
+    /// Returns an iterator over mutable strided subslices separated by elements
+    /// that match `pred`. The matched element is contained in the end of the
+    /// previous subslice as a terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 0, 0, 2, 0, 3, 0, 0];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for group in stride.split_inclusive_mut(|&x| x == 0) {
+    ///     group[0] = 5;
+    /// }
+    /// assert_eq!(data, &[5, 0, 0, 0, 5, 0, 3, 0, 0]);
+    /// ```
+    pub fn split_inclusive_mut<P>(&mut self, pred: P) -> SplitInclusiveMut<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitInclusiveMut::new(self, pred)
+    }
+
+    /// Returns an iterator over strided subslices separated by elements that
+    /// match `pred`, limited to returning at most `n` items. The matched
+    /// element is not contained in the subslices.
+    ///
+    /// The last element returned, if any, will contain the remainder of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    /// let mut iter = stride.splitn(2, |&x| x == 0);
+    /// assert_eq!(iter.next().unwrap(), &[1]);
+    /// assert_eq!(iter.next().unwrap(), &[2, 0, 3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn splitn<P>(&self, n: usize, pred: P) -> SplitN<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitN::new(self.split(pred), n)
+    }
+
+    /// Returns an iterator over mutable strided subslices separated by elements
+    /// that match `pred`, limited to returning at most `n` items. The matched
+    /// element is not contained in the subslices.
+    ///
+    /// The last element returned, if any, will contain the remainder of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 0, 0, 2, 0, 0, 0, 3];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for group in stride.splitn_mut(2, |&x| x == 0) {
+    ///     group[0] = 4;
+    /// }
+    /// assert_eq!(data, &[4, 0, 0, 0, 4, 0, 0, 0, 3]);
+    /// ```
+    pub fn splitn_mut<P>(&mut self, n: usize, pred: P) -> SplitNMut<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitNMut::new(self.split_mut(pred), n)
+    }
+
+    /// Returns an iterator over strided subslices separated by elements that
+    /// match `pred`, starting at the end of the strided slice and working
+    /// backwards. The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 0, 0, 2, 0, 3]);
+    /// let mut iter = stride.rsplit(|&x| x == 0);
+    /// assert_eq!(iter.next().unwrap(), &[2, 3]);
+    /// assert_eq!(iter.next().unwrap(), &[1]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn rsplit<P>(&self, pred: P) -> RSplit<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        RSplit::new(self.split(pred))
+    }
+
+    /// Returns an iterator over mutable strided subslices separated by elements
+    /// that match `pred`, starting at the end of the strided slice and working
+    /// backwards. The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 0, 0, 2, 0, 3];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for (i, group) in stride.rsplit_mut(|&x| x == 0).enumerate() {
+    ///     group[0] = i;
+    /// }
+    /// assert_eq!(data, &[1, 0, 0, 0, 0, 0, 3]);
+    /// ```
+    pub fn rsplit_mut<P>(&mut self, pred: P) -> RSplitMut<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        RSplitMut::new(self.split_mut(pred))
+    }
+
+    /// Returns an iterator over strided subslices separated by elements that
+    /// match `pred`, limited to returning at most `n` items, starting at the
+    /// end of the strided slice and working backwards. The matched element is
+    /// not contained in the subslices.
+    ///
+    /// The last element returned, if any, will contain the remainder of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    /// let mut iter = stride.rsplitn(2, |&x| x == 0);
+    /// assert_eq!(iter.next().unwrap(), &[3]);
+    /// assert_eq!(iter.next().unwrap(), &[1, 0, 2]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn rsplitn<P>(&self, n: usize, pred: P) -> RSplitN<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        RSplitN::new(self.rsplit(pred), n)
+    }
+
+    /// Returns an iterator over mutable strided subslices separated by elements
+    /// that match `pred`, limited to returning at most `n` items, starting at
+    /// the end of the strided slice and working backwards. The matched element
+    /// is not contained in the subslices.
+    ///
+    /// The last element returned, if any, will contain the remainder of the
+    /// strided slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 0, 0, 0, 2, 0, 0, 0, 3];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// for group in stride.rsplitn_mut(2, |&x| x == 0) {
+    ///     group[0] = 4;
+    /// }
+    /// assert_eq!(data, &[4, 0, 0, 0, 2, 0, 0, 0, 4]);
+    /// ```
+    pub fn rsplitn_mut<P>(&mut self, n: usize, pred: P) -> RSplitNMut<'_, T, P, S>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        RSplitNMut::new(self.rsplit_mut(pred), n)
+    }
+}
+
+impl<T> Stride<T, 1> {
+    /// Returns a slice containing the entire strided slice.
+    ///
+    /// Only available on strided slices with a stride of `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let slice = &[1, 2, 3];
+    /// let stride = Stride::<_, 1>::new(slice);
+    /// assert_eq!(stride.as_slice(), slice);
+    /// ```
+    pub const fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns a mutable slice containing the entire strided slice.
+    ///
+    /// Only available on strided slices with a stride of `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let slice = &mut [1, 2, 7];
+    /// let stride = Stride::<_, 1>::new_mut(slice);
+    /// stride.as_mut_slice()[2] = 3;
+    /// assert_eq!(slice, &[1, 2, 3])
+    /// ```
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+/// Holds compile time checks that `N` is a multiple of `S`, which have to be
+/// made on a type that is generic over both.
+struct Multiple<const S: usize, const N: usize>;
+
+impl<const S: usize, const N: usize> Multiple<S, N> {
+    /// Fails to compile if the new stride `N` is not a multiple of `S`.
+    const ASSERT_STRIDE: () = assert!(
+        N.is_multiple_of(S),
+        "the new stride `R` must be a multiple of the stride `S`"
+    );
+
+    /// Fails to compile if the array length `N` is not a multiple of `S`.
+    const ASSERT_LEN: () = assert!(
+        N.is_multiple_of(S),
+        "the array length `N` must be a multiple of the stride `S`"
+    );
+}
+
+/// A `const` version of `cmp::min` for `usize`.
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+const fn check_exact<const S: usize>(len: usize) -> Result<(), StrideError> {
+    if len.is_multiple_of(S) {
+        Ok(())
+    } else {
+        Err(StrideError { len, stride: S })
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn len_mismatch_fail(dst_len: usize, src_len: usize) -> ! {
+    panic!(
+        "source length ({}) does not match destination length ({})",
+        src_len, dst_len,
+    );
 }
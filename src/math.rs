@@ -0,0 +1,179 @@
+//! Numeric reductions over strided slices.
+//!
+//! Folding a strided slice of floats with an iterator adds one element at a
+//! time, since the compiler may not reorder the additions, so each step has to
+//! wait for the previous one. The reductions here keep several independent
+//! accumulators instead, which the compiler can keep in vector registers, and
+//! combine them at the end. For integers the compiler can often do this for
+//! the iterator as well, so the gain there is smaller.
+//!
+//! The minimum and maximum are named `min_elem()` and `max_elem()` so that
+//! they don't shadow [`Ord::min()`] and [`Ord::max()`], which compare whole
+//! strided slices.
+//!
+//! Only available with the `math` feature.
+
+use crate::Stride;
+
+/// The number of independent accumulators.
+const LANES: usize = 8;
+
+impl<T, const S: usize> Stride<T, S>
+where
+    T: Copy,
+{
+    /// Folds the elements into `acc` with `f`, where the `i`th element is
+    /// folded into the accumulator `i % LANES`, then folds the accumulators
+    /// together and the remaining elements into the result.
+    #[inline]
+    fn fold_lanes(&self, mut acc: [T; LANES], f: impl Fn(T, T) -> T) -> T {
+        // The last group of elements might not have the full stride of data
+        // after it, so it's folded separately.
+        let chunks = self.data.chunks_exact(LANES * S);
+        let n = chunks.len() * LANES;
+        for chunk in chunks {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = f(*acc, chunk[i * S]);
+            }
+        }
+        let [a, b, c, d, e, g, h, k] = acc;
+        let acc = f(f(f(a, b), f(c, d)), f(f(e, g), f(h, k)));
+        self[n..].iter().fold(acc, |acc, &elem| f(acc, elem))
+    }
+}
+
+macro_rules! impl_int {
+    ($($int:ty),*) => {$(
+        impl<const S: usize> Stride<$int, S> {
+            /// Returns the sum of all elements in the strided slice.
+            ///
+            /// The sum wraps around on overflow, as if the elements were
+            /// added with `wrapping_add()`, which gives the same result in any
+            /// order.
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($int), "] = &[1, 2, 3, 4, 5, 6];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).sum(), 9);
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).sum(), 0);
+            /// ```
+            pub fn sum(&self) -> $int {
+                self.fold_lanes([0; LANES], <$int>::wrapping_add)
+            }
+
+            /// Returns the minimum element of the strided slice, or `None` if
+            /// it is empty.
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($int), "] = &[4, 1, 3, 2, 5, 6];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).min_elem(), Some(3));
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).min_elem(), None);
+            /// ```
+            pub fn min_elem(&self) -> Option<$int> {
+                let first = *self.first()?;
+                Some(self.fold_lanes([first; LANES], Ord::min))
+            }
+
+            /// Returns the maximum element of the strided slice, or `None` if
+            /// it is empty.
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($int), "] = &[4, 1, 3, 2, 5, 6];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).max_elem(), Some(5));
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).max_elem(), None);
+            /// ```
+            pub fn max_elem(&self) -> Option<$int> {
+                let first = *self.first()?;
+                Some(self.fold_lanes([first; LANES], Ord::max))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($float:ty),*) => {$(
+        impl<const S: usize> Stride<$float, S> {
+            /// Returns the sum of all elements in the strided slice.
+            ///
+            /// The elements are not added in order, so rounding can make the
+            /// result differ slightly from a sequential sum.
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($float), "] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).sum(), 9.0);
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).sum(), 0.0);
+            /// ```
+            pub fn sum(&self) -> $float {
+                self.fold_lanes([0.0; LANES], |a, b| a + b)
+            }
+
+            /// Returns the minimum element of the strided slice, or `None` if
+            /// it is empty.
+            ///
+            #[doc = concat!("NaNs are ignored like in [`", stringify!($float), "::min()`], so the result is only NaN if all elements are NaN.")]
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($float), "] = &[4.0, 1.0, ", stringify!($float), "::NAN, 2.0, 3.0, 6.0];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).min_elem(), Some(3.0));
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).min_elem(), None);
+            /// ```
+            pub fn min_elem(&self) -> Option<$float> {
+                let first = *self.first()?;
+                Some(self.fold_lanes([first; LANES], <$float>::min))
+            }
+
+            /// Returns the maximum element of the strided slice, or `None` if
+            /// it is empty.
+            ///
+            #[doc = concat!("NaNs are ignored like in [`", stringify!($float), "::max()`], so the result is only NaN if all elements are NaN.")]
+            ///
+            /// Only available with the `math` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use stride::Stride;
+            /// #
+            #[doc = concat!("let data: &[", stringify!($float), "] = &[4.0, 1.0, ", stringify!($float), "::NAN, 2.0, 3.0, 6.0];")]
+            /// assert_eq!(Stride::<_, 2>::new(data).max_elem(), Some(4.0));
+            /// assert_eq!(Stride::<_, 2>::new(&data[..0]).max_elem(), None);
+            /// ```
+            pub fn max_elem(&self) -> Option<$float> {
+                let first = *self.first()?;
+                Some(self.fold_lanes([first; LANES], <$float>::max))
+            }
+        }
+    )*};
+}
+
+impl_int! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+impl_float! { f32, f64 }
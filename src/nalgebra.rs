@@ -0,0 +1,104 @@
+//! Conversions of strided slices into `nalgebra` vector views.
+//!
+//! Only available with the `nalgebra` feature.
+
+use nalgebra::{Const, DVectorView, DVectorViewMut, Dyn, Scalar};
+
+use crate::{DynStride, DynStrideMut, Stride};
+
+impl<'a, T> From<DynStride<'a, T>> for DVectorView<'a, T, Dyn, Dyn>
+where
+    T: Scalar,
+{
+    /// Converts to a `nalgebra` column vector view with the same row stride,
+    /// without copying.
+    ///
+    /// There is no conversion in the other direction, because a matrix view
+    /// doesn't borrow the elements in between, and those might be borrowed
+    /// mutably by another view.
+    ///
+    /// Only available with the `nalgebra` feature.
+    fn from(stride: DynStride<'a, T>) -> Self {
+        let (len, rstride) = (stride.len(), stride.stride());
+        DVectorView::from_slice_with_strides_generic(
+            stride.underlying(),
+            Dyn(len),
+            Const::<1>,
+            Dyn(rstride),
+            Dyn(len * rstride),
+        )
+    }
+}
+
+impl<'a, T> From<DynStrideMut<'a, T>> for DVectorViewMut<'a, T, Dyn, Dyn>
+where
+    T: Scalar,
+{
+    /// Converts to a mutable `nalgebra` column vector view with the same row
+    /// stride, without copying.
+    ///
+    /// Only available with the `nalgebra` feature.
+    fn from(stride: DynStrideMut<'a, T>) -> Self {
+        let (len, rstride) = (stride.len(), stride.stride());
+        DVectorViewMut::from_slice_with_strides_generic(
+            stride.into_underlying_mut(),
+            Dyn(len),
+            Const::<1>,
+            Dyn(rstride),
+            Dyn(len * rstride),
+        )
+    }
+}
+
+impl<'a, T, const S: usize> From<&'a Stride<T, S>> for DVectorView<'a, T, Const<S>, Dyn>
+where
+    T: Scalar,
+{
+    /// Converts to a `nalgebra` column vector view with a row stride of `S`,
+    /// without copying.
+    ///
+    /// Only available with the `nalgebra` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use nalgebra::{Const, DVectorView, Dyn, Matrix3};
+    /// # use stride::Stride;
+    /// #
+    /// // A column major matrix, so the second row has a stride of 3.
+    /// let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let row = Stride::<_, 3>::new(&m.as_slice()[1..]);
+    /// let v = DVectorView::<_, Const<3>, Dyn>::from(row);
+    /// assert_eq!(v.dot(&v), 16.0 + 25.0 + 36.0);
+    /// ```
+    fn from(stride: &'a Stride<T, S>) -> Self {
+        let len = stride.len();
+        DVectorView::from_slice_with_strides_generic(
+            &stride.data,
+            Dyn(len),
+            Const::<1>,
+            Const::<S>,
+            Dyn(len * S),
+        )
+    }
+}
+
+impl<'a, T, const S: usize> From<&'a mut Stride<T, S>> for DVectorViewMut<'a, T, Const<S>, Dyn>
+where
+    T: Scalar,
+{
+    /// Converts to a mutable `nalgebra` column vector view with a row stride
+    /// of `S`, without copying.
+    ///
+    /// Only available with the `nalgebra` feature.
+    fn from(stride: &'a mut Stride<T, S>) -> Self {
+        let len = stride.len();
+        DVectorViewMut::from_slice_with_strides_generic(
+            &mut stride.data,
+            Dyn(len),
+            Const::<1>,
+            Const::<S>,
+            Dyn(len * S),
+        )
+    }
+}
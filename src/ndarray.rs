@@ -0,0 +1,75 @@
+//! Conversions of strided slices into `ndarray` views.
+//!
+//! Only available with the `ndarray` feature.
+
+use ndarray::{ArrayView1, ArrayViewMut1, ShapeBuilder};
+
+use crate::{DynStride, DynStrideMut, Stride};
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Returns a one-dimensional `ndarray` view of the strided slice with a
+    /// stride of `S`, without copying.
+    ///
+    /// Only available with the `ndarray` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1.0, 0.0, 2.0, 0.0, 3.0]);
+    /// let view = stride.as_array_view();
+    /// assert_eq!(view.strides(), &[2]);
+    /// assert_eq!(view.dot(&view), 14.0);
+    /// ```
+    pub fn as_array_view(&self) -> ArrayView1<'_, T> {
+        self.as_dyn().into()
+    }
+
+    /// Returns a one-dimensional mutable `ndarray` view of the strided slice
+    /// with a stride of `S`, without copying.
+    ///
+    /// Only available with the `ndarray` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let mut view = Stride::<_, 3>::new_mut(data).as_array_view_mut();
+    /// view *= 10;
+    /// assert_eq!(data, &[10, 2, 3, 40, 5, 6]);
+    /// ```
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMut1<'_, T> {
+        self.as_dyn_mut().into()
+    }
+}
+
+impl<'a, T> From<DynStride<'a, T>> for ArrayView1<'a, T> {
+    /// Converts to a one-dimensional `ndarray` view with the same stride,
+    /// without copying.
+    ///
+    /// There is no conversion in the other direction, because an array view
+    /// doesn't borrow the elements in between, and those might be borrowed
+    /// mutably by another view.
+    ///
+    /// Only available with the `ndarray` feature.
+    fn from(stride: DynStride<'a, T>) -> Self {
+        let shape = stride.len().strides(stride.stride());
+        ArrayView1::from_shape(shape, stride.underlying())
+            .expect("strided slice is a valid array view")
+    }
+}
+
+impl<'a, T> From<DynStrideMut<'a, T>> for ArrayViewMut1<'a, T> {
+    /// Converts to a one-dimensional mutable `ndarray` view with the same
+    /// stride, without copying.
+    ///
+    /// Only available with the `ndarray` feature.
+    fn from(stride: DynStrideMut<'a, T>) -> Self {
+        let shape = stride.len().strides(stride.stride());
+        ArrayViewMut1::from_shape(shape, stride.into_underlying_mut())
+            .expect("strided slice is a valid array view")
+    }
+}
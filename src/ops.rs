@@ -1,14 +1,27 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::cmp::{self, Ordering};
+use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 use core::ops::*;
 
-use crate::{Stride, StrideIndex};
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::{Stride, StrideIndex, TryFromStrideError};
 
 impl<T, U, const S: usize, const R: usize> PartialEq<Stride<U, R>> for Stride<T, S>
 where
     T: PartialEq<U>,
 {
     fn eq(&self, other: &Stride<U, R>) -> bool {
+        if S == 1 && R == 1 {
+            // Both underlying slices are exactly the strided elements, so use
+            // slice equality which compares using `memcmp` where possible.
+            return self.data == other.data;
+        }
         self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
     }
 }
@@ -17,6 +30,9 @@ fn partial_eq_slice<T, U, const S: usize>(stride: &Stride<T, S>, slice: &[U]) ->
 where
     T: PartialEq<U>,
 {
+    if S == 1 {
+        return stride.data == *slice;
+    }
     stride.len() == slice.len() && stride.iter().zip(slice.iter()).all(|(a, b)| a == b)
 }
 
@@ -25,7 +41,7 @@ where
     T: PartialEq<U>,
 {
     fn eq(&self, other: &[U; N]) -> bool {
-        partial_eq_slice(self, &*other)
+        partial_eq_slice(self, other)
     }
 }
 
@@ -56,17 +72,177 @@ where
     }
 }
 
+impl<T, U, const S: usize, const N: usize> PartialEq<[U; N]> for &Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        partial_eq_slice(self, other)
+    }
+}
+
+impl<T, U, const S: usize, const N: usize> PartialEq<[U; N]> for &mut Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        partial_eq_slice(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const S: usize> PartialEq<Vec<U>> for Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        partial_eq_slice(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const S: usize> PartialEq<Vec<U>> for &Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        partial_eq_slice(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const S: usize> PartialEq<Vec<U>> for &mut Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        partial_eq_slice(self, other)
+    }
+}
+
+fn slice_partial_eq<T, U, const S: usize>(slice: &[T], stride: &Stride<U, S>) -> bool
+where
+    T: PartialEq<U>,
+{
+    if S == 1 {
+        return *slice == stride.data;
+    }
+    slice.len() == stride.len() && slice.iter().zip(stride.iter()).all(|(a, b)| a == b)
+}
+
+macro_rules! impl_partial_eq_reverse {
+    ($([$($vars:tt)*] $lhs:ty),* $(,)?) => {
+        $(
+            impl<T, U, const S: usize, $($vars)*> PartialEq<Stride<U, S>> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &Stride<U, S>) -> bool {
+                    slice_partial_eq(&self[..], other)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq_reverse! {
+    [] [T],
+    [] &[T],
+    [] &mut [T],
+    [const N: usize] [T; N],
+    [const N: usize] &[T; N],
+}
+
+#[cfg(feature = "alloc")]
+impl_partial_eq_reverse! {
+    [] Vec<T>,
+}
+
 impl<T, const S: usize> Eq for Stride<T, S> where T: Eq {}
 
+/// Compares the elements of two iterators for equality, checking the lengths
+/// first.
+///
+/// This is used by the other strided slice types, which can't make use of the
+/// fast paths above.
+pub(crate) fn eq_by<'a, 'b, T, U>(
+    a: impl ExactSizeIterator<Item = &'a T>,
+    b: impl ExactSizeIterator<Item = &'b U>,
+) -> bool
+where
+    T: PartialEq<U> + 'a,
+    U: 'b,
+{
+    a.len() == b.len() && a.zip(b).all(|(a, b)| a == b)
+}
+
+/// Hashes the elements of a strided slice, prefixed with the length.
+///
+/// This is used by all the strided slice types so that equal views have equal
+/// hashes.
+pub(crate) fn hash_elements<'a, T, H>(iter: impl ExactSizeIterator<Item = &'a T>, state: &mut H)
+where
+    T: Hash + 'a,
+    H: Hasher,
+{
+    // Prefix the length like slices do so that adjacent strided slices in a
+    // larger structure can't collide, e.g. `([1, 2], [3])` and `([1], [2, 3])`.
+    state.write_usize(iter.len());
+    // Hash one element at a time even for a stride of 1, so that equal strided
+    // slices write the same sequence to the hasher regardless of the stride.
+    // `hash_slice()` may write all elements at once instead, which is why
+    // `Stride<T, 1>` doesn't implement `Borrow<[T]>`.
+    for element in iter {
+        element.hash(state);
+    }
+}
+
 impl<T, const S: usize> Hash for Stride<T, S>
 where
     T: Hash,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        for element in self {
-            element.hash(state);
+        hash_elements(self.iter(), state)
+    }
+}
+
+/// Returns the first non-`None` result of calling `f` on pairs of elements at
+/// the same position in both strided slices.
+///
+/// This uses a counted loop over raw pointers which is much faster than
+/// zipping the two iterators.
+fn zip_find<T, U, F, B, const S: usize, const R: usize>(
+    a: &Stride<T, S>,
+    b: &Stride<U, R>,
+    mut f: F,
+) -> Option<B>
+where
+    F: FnMut(&T, &U) -> Option<B>,
+{
+    let len = cmp::min(a.len(), b.len());
+    let (a, b) = (a.as_ptr(), b.as_ptr());
+    for i in 0..len {
+        // SAFETY: `i` is in bounds for both strided slices.
+        let (a, b) = unsafe { (&*a.add(i * S), &*b.add(i * R)) };
+        if let Some(result) = f(a, b) {
+            return Some(result);
         }
     }
+    None
+}
+
+fn partial_cmp_stride<T, U, const S: usize, const R: usize>(
+    a: &Stride<T, S>,
+    b: &Stride<U, R>,
+) -> Option<Ordering>
+where
+    T: PartialOrd<U>,
+{
+    let non_eq = zip_find(a, b, |a, b| match a.partial_cmp(b) {
+        Some(Ordering::Equal) => None,
+        non_eq => Some(non_eq),
+    });
+    non_eq.unwrap_or_else(|| a.len().partial_cmp(&b.len()))
 }
 
 impl<T, U, const S: usize, const R: usize> PartialOrd<Stride<U, R>> for Stride<T, S>
@@ -74,14 +250,60 @@ where
     T: PartialOrd<U>,
 {
     fn partial_cmp(&self, other: &Stride<U, R>) -> Option<Ordering> {
-        let len = cmp::min(self.len(), other.len());
-        for i in 0..len {
-            match self[i].partial_cmp(&other[i]) {
-                Some(Ordering::Equal) => continue,
-                non_eq => return non_eq,
-            }
-        }
-        self.len().partial_cmp(&other.len())
+        partial_cmp_stride(self, other)
+    }
+}
+
+fn partial_cmp_slice<T, U, const S: usize>(stride: &Stride<T, S>, slice: &[U]) -> Option<Ordering>
+where
+    T: PartialOrd<U>,
+{
+    partial_cmp_stride(stride, Stride::<_, 1>::new(slice))
+}
+
+impl<T, U, const S: usize> PartialOrd<[U]> for Stride<T, S>
+where
+    T: PartialOrd<U>,
+{
+    fn partial_cmp(&self, other: &[U]) -> Option<Ordering> {
+        partial_cmp_slice(self, other)
+    }
+}
+
+impl<T, U, const S: usize> PartialOrd<&[U]> for Stride<T, S>
+where
+    T: PartialOrd<U>,
+{
+    fn partial_cmp(&self, other: &&[U]) -> Option<Ordering> {
+        partial_cmp_slice(self, other)
+    }
+}
+
+impl<T, U, const S: usize, const N: usize> PartialOrd<[U; N]> for Stride<T, S>
+where
+    T: PartialOrd<U>,
+{
+    fn partial_cmp(&self, other: &[U; N]) -> Option<Ordering> {
+        partial_cmp_slice(self, other)
+    }
+}
+
+impl<T, U, const S: usize, const N: usize> PartialOrd<&[U; N]> for Stride<T, S>
+where
+    T: PartialOrd<U>,
+{
+    fn partial_cmp(&self, other: &&[U; N]) -> Option<Ordering> {
+        partial_cmp_slice(self, *other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const S: usize> PartialOrd<Vec<U>> for Stride<T, S>
+where
+    T: PartialOrd<U>,
+{
+    fn partial_cmp(&self, other: &Vec<U>) -> Option<Ordering> {
+        partial_cmp_slice(self, other)
     }
 }
 
@@ -90,14 +312,15 @@ where
     T: Ord,
 {
     fn cmp(&self, other: &Stride<T, S>) -> Ordering {
-        let len = cmp::min(self.len(), other.len());
-        for i in 0..len {
-            match self[i].cmp(&other[i]) {
-                Ordering::Equal => continue,
-                non_eq => return non_eq,
-            }
+        if S == 1 {
+            // Slices compare using `memcmp` where possible.
+            return self.data.cmp(&other.data);
         }
-        self.len().cmp(&other.len())
+        let non_eq = zip_find(self, other, |a, b| match a.cmp(b) {
+            Ordering::Equal => None,
+            non_eq => Some(non_eq),
+        });
+        non_eq.unwrap_or_else(|| self.len().cmp(&other.len()))
     }
 }
 
@@ -107,6 +330,7 @@ where
 {
     type Output = I::Output;
 
+    #[track_caller]
     fn index(&self, index: I) -> &Self::Output {
         index.index(self)
     }
@@ -116,7 +340,155 @@ impl<I, T, const S: usize> IndexMut<I> for Stride<T, S>
 where
     I: StrideIndex<Self>,
 {
+    #[track_caller]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         index.index_mut(self)
     }
 }
+
+impl<T, const S: usize> AsRef<Stride<T, S>> for Stride<T, S> {
+    fn as_ref(&self) -> &Stride<T, S> {
+        self
+    }
+}
+
+impl<T, const S: usize> AsMut<Stride<T, S>> for Stride<T, S> {
+    fn as_mut(&mut self) -> &mut Stride<T, S> {
+        self
+    }
+}
+
+impl<T> AsRef<[T]> for Stride<T, 1> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for Stride<T, 1> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> AsRef<Stride<T, 1>> for [T] {
+    fn as_ref(&self) -> &Stride<T, 1> {
+        Stride::new(self)
+    }
+}
+
+impl<T> AsMut<Stride<T, 1>> for [T] {
+    fn as_mut(&mut self) -> &mut Stride<T, 1> {
+        Stride::new_mut(self)
+    }
+}
+
+impl<'a, T> From<&'a [T]> for &'a Stride<T, 1> {
+    fn from(data: &'a [T]) -> Self {
+        Stride::new(data)
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for &'a mut Stride<T, 1> {
+    fn from(data: &'a mut [T]) -> Self {
+        Stride::new_mut(data)
+    }
+}
+
+impl<'a, T> From<&'a Stride<T, 1>> for &'a [T] {
+    fn from(stride: &'a Stride<T, 1>) -> Self {
+        stride.as_slice()
+    }
+}
+
+impl<'a, T> From<&'a mut Stride<T, 1>> for &'a mut [T] {
+    fn from(stride: &'a mut Stride<T, 1>) -> Self {
+        stride.as_mut_slice()
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> From<&'a [T; N]> for &'a Stride<T, S> {
+    fn from(data: &'a [T; N]) -> Self {
+        Stride::new_from_array(data)
+    }
+}
+
+impl<'a, T, const S: usize, const N: usize> From<&'a mut [T; N]> for &'a mut Stride<T, S> {
+    fn from(data: &'a mut [T; N]) -> Self {
+        Stride::new_from_array_mut(data)
+    }
+}
+
+impl<T, const S: usize, const N: usize> TryFrom<&Stride<T, S>> for [T; N]
+where
+    T: Copy,
+{
+    type Error = TryFromStrideError;
+
+    fn try_from(stride: &Stride<T, S>) -> Result<Self, Self::Error> {
+        stride.collect_array().ok_or(TryFromStrideError(()))
+    }
+}
+
+impl<T, const S: usize, const N: usize> TryFrom<&mut Stride<T, S>> for [T; N]
+where
+    T: Copy,
+{
+    type Error = TryFromStrideError;
+
+    fn try_from(stride: &mut Stride<T, S>) -> Result<Self, Self::Error> {
+        stride.collect_array().ok_or(TryFromStrideError(()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, const S: usize> Serialize for Stride<T, S>
+where
+    T: Serialize,
+{
+    /// Serializes the elements of the strided slice as a sequence, leaving
+    /// out the elements in between.
+    fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const S: usize> Arbitrary<'a> for &'a Stride<u8, S> {
+    /// Takes an arbitrary number of bytes of the fuzz input as the underlying
+    /// slice.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        <&[u8]>::arbitrary(u).map(Stride::new)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        <&[u8]>::arbitrary_take_rest(u).map(Stride::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&[u8]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, const S: usize> defmt::Format for Stride<T, S>
+where
+    T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        if S == 1 {
+            // Encode the whole slice at once, which is more compact.
+            return self.data.format(f);
+        }
+        defmt::write!(f, "[");
+        for (i, elem) in self.iter().enumerate() {
+            if i != 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}", elem);
+        }
+        defmt::write!(f, "]");
+    }
+}
@@ -0,0 +1,281 @@
+//! Parallel iterators and sorts for strided slices with `rayon`.
+//!
+//! Only available with the `rayon` feature.
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use core::cmp::Ordering;
+
+use crate::sort;
+use crate::{Iter, IterMut, Stride};
+
+/// Parallel iterator over a strided slice.
+///
+/// This struct is created by the `par_iter()` method on strided slices, which
+/// comes from [`IntoParallelRefIterator`][`rayon::iter::IntoParallelRefIterator`].
+///
+/// # Examples
+///
+/// ```
+/// # use rayon::prelude::*;
+/// # use stride::Stride;
+/// #
+/// let data: Vec<u64> = (0..1000).collect();
+/// let stride = Stride::<_, 10>::new(&data);
+/// assert_eq!(stride.par_iter().sum::<u64>(), 49500);
+/// ```
+#[derive(Debug)]
+pub struct ParIter<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+}
+
+/// Parallel iterator over a mutable strided slice.
+///
+/// This struct is created by the `par_iter_mut()` method on mutable strided
+/// slices, which comes from
+/// [`IntoParallelRefMutIterator`][`rayon::iter::IntoParallelRefMutIterator`].
+///
+/// # Examples
+///
+/// ```
+/// # use rayon::prelude::*;
+/// # use stride::Stride;
+/// #
+/// let data = &mut [1, 2, 3, 4, 5, 6];
+/// let stride = Stride::<_, 2>::new_mut(data);
+/// stride.par_iter_mut().for_each(|x| *x *= 10);
+/// assert_eq!(data, &[10, 2, 30, 4, 50, 6]);
+/// ```
+#[derive(Debug)]
+pub struct ParIterMut<'a, T, const S: usize> {
+    stride: &'a mut Stride<T, S>,
+}
+
+impl<T, const S: usize> Clone for ParIter<'_, T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            stride: self.stride,
+        }
+    }
+}
+
+impl<'a, T, const S: usize> IntoParallelIterator for &'a Stride<T, S>
+where
+    T: Sync,
+{
+    type Item = &'a T;
+    type Iter = ParIter<'a, T, S>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { stride: self }
+    }
+}
+
+impl<'a, T, const S: usize> IntoParallelIterator for &'a mut Stride<T, S>
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+    type Iter = ParIterMut<'a, T, S>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut { stride: self }
+    }
+}
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Sorts the strided slice in parallel.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*n* \* log(*n*)) worst-case. It allocates a buffer of indexes the
+    /// same length as the strided slice, sorts it in parallel, and then
+    /// reorders the strided slice sequentially.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`sort()`][`Stride::sort()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.par_sort();
+    /// assert_eq!(data, &[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    /// ```
+    pub fn par_sort(&mut self)
+    where
+        T: Ord + Sync,
+    {
+        sort::par_stable_sort(self, &T::cmp);
+    }
+
+    /// Sorts the strided slice in parallel with a comparator function.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`par_sort()`][`Stride::par_sort()`] and
+    /// [`sort_by()`][`Stride::sort_by()`].*
+    pub fn par_sort_by<F>(&mut self, compare: F)
+    where
+        T: Sync,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        sort::par_stable_sort(self, &compare);
+    }
+
+    /// Sorts the strided slice in parallel with a key extraction function.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`par_sort()`][`Stride::par_sort()`] and
+    /// [`sort_by_key()`][`Stride::sort_by_key()`].*
+    pub fn par_sort_by_key<K, F>(&mut self, f: F)
+    where
+        T: Sync,
+        F: Fn(&T) -> K + Sync,
+        K: Ord,
+    {
+        sort::par_stable_sort(self, &|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the strided slice in parallel, but might not preserve the order
+    /// of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case. Both
+    /// sides of each partition are sorted in parallel. Elements in the
+    /// underlying slice that are not part of the strided slice are left
+    /// untouched.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`sort_unstable()`][`Stride::sort_unstable()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let mut data: Vec<u32> = (0..10_000).rev().collect();
+    /// let stride = Stride::<_, 4>::new_mut(&mut data);
+    /// stride.par_sort_unstable();
+    /// assert!(stride.is_sorted());
+    /// assert_eq!(data[..5], [3, 9998, 9997, 9996, 7]);
+    /// ```
+    pub fn par_sort_unstable(&mut self)
+    where
+        T: Ord + Send,
+    {
+        sort::par_quicksort(self, &T::lt);
+    }
+
+    /// Sorts the strided slice in parallel with a comparator function, but
+    /// might not preserve the order of equal elements.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`par_sort_unstable()`][`Stride::par_sort_unstable()`] and
+    /// [`sort_unstable_by()`][`Stride::sort_unstable_by()`].*
+    pub fn par_sort_unstable_by<F>(&mut self, compare: F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        sort::par_quicksort(self, &|a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the strided slice in parallel with a key extraction function, but
+    /// might not preserve the order of equal elements.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// *See [`par_sort_unstable()`][`Stride::par_sort_unstable()`] and
+    /// [`sort_unstable_by_key()`][`Stride::sort_unstable_by_key()`].*
+    pub fn par_sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        T: Send,
+        F: Fn(&T) -> K + Sync,
+        K: Ord,
+    {
+        sort::par_quicksort(self, &|a, b| f(a).lt(&f(b)));
+    }
+}
+
+macro_rules! impl_par_iter {
+    ($par_iter:ident, $producer:ident, $bound:ident, $stride:ty, $item:ty, $iter:ty, $split_at:ident) => {
+        impl<'a, T, const S: usize> ParallelIterator for $par_iter<'a, T, S>
+        where
+            T: $bound,
+        {
+            type Item = $item;
+
+            fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where
+                C: UnindexedConsumer<Self::Item>,
+            {
+                bridge(self, consumer)
+            }
+
+            fn opt_len(&self) -> Option<usize> {
+                Some(self.stride.len())
+            }
+        }
+
+        impl<'a, T, const S: usize> IndexedParallelIterator for $par_iter<'a, T, S>
+        where
+            T: $bound,
+        {
+            fn drive<C>(self, consumer: C) -> C::Result
+            where
+                C: Consumer<Self::Item>,
+            {
+                bridge(self, consumer)
+            }
+
+            fn len(&self) -> usize {
+                self.stride.len()
+            }
+
+            fn with_producer<CB>(self, callback: CB) -> CB::Output
+            where
+                CB: ProducerCallback<Self::Item>,
+            {
+                callback.callback($producer {
+                    stride: self.stride,
+                })
+            }
+        }
+
+        struct $producer<'a, T, const S: usize> {
+            stride: $stride,
+        }
+
+        impl<'a, T, const S: usize> Producer for $producer<'a, T, S>
+        where
+            T: $bound,
+        {
+            type Item = $item;
+            type IntoIter = $iter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.stride.into_iter()
+            }
+
+            fn split_at(self, index: usize) -> (Self, Self) {
+                // Split on the logical index, so that the elements in between
+                // end up on the left.
+                let (left, right) = self.stride.$split_at(index);
+                ($producer { stride: left }, $producer { stride: right })
+            }
+        }
+    };
+}
+
+impl_par_iter! { ParIter, IterProducer, Sync, &'a Stride<T, S>, &'a T, Iter<'a, T, S>, split_at }
+impl_par_iter! {
+    ParIterMut, IterMutProducer, Send, &'a mut Stride<T, S>, &'a mut T, IterMut<'a, T, S>, split_at_mut
+}
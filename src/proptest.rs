@@ -0,0 +1,68 @@
+//! Strategies for generating owned strided slices with `proptest`.
+//!
+//! Only available with the `proptest` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use stride::proptest::stride_buf;
+//!
+//! proptest! {
+//!     fn check_len(buf in stride_buf::<_, 3>(any::<u8>(), 1..10)) {
+//!         prop_assert!((1..10).contains(&buf.len()));
+//!         prop_assert_eq!(buf.first(), buf.underlying().first());
+//!     }
+//! }
+//! # check_len();
+//! ```
+
+use alloc::vec::Vec;
+
+use ::proptest::arbitrary::{any_with, Arbitrary};
+use ::proptest::collection::{vec, SizeRange, VecStrategy};
+use ::proptest::strategy::{Map, Strategy};
+
+use crate::StrideBuf;
+
+/// Strategy to create owned strided slices with a length in a certain range.
+///
+/// This is created by the [`stride_buf()`] function in this module.
+pub type StrideBufStrategy<E, const S: usize> =
+    Map<VecStrategy<E>, fn(Vec<<E as Strategy>::Value>) -> StrideBuf<<E as Strategy>::Value, S>>;
+
+/// Creates a strategy to generate owned strided slices containing elements
+/// drawn from `element` and with a length in the range given by `size`.
+///
+/// The underlying slice is generated as a whole, so the elements in between
+/// are drawn from `element` too, and its length isn't always a multiple of
+/// `S`.
+pub fn stride_buf<E, const S: usize>(
+    element: E,
+    size: impl Into<SizeRange>,
+) -> StrideBufStrategy<E, S>
+where
+    E: Strategy,
+{
+    let (start, end) = size.into().start_end_incl();
+    // The shortest underlying slice has a single element after the last
+    // stride, and the longest fills every stride.
+    let start = match start {
+        0 => 0,
+        start => (start - 1).saturating_mul(S).saturating_add(1),
+    };
+    let end = end.saturating_mul(S);
+    vec(element, start..=end).prop_map(StrideBuf::from_vec)
+}
+
+impl<T, const S: usize> Arbitrary for StrideBuf<T, S>
+where
+    T: Arbitrary,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = StrideBufStrategy<T::Strategy, S>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        stride_buf(any_with::<T>(params), size)
+    }
+}
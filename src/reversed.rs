@@ -0,0 +1,387 @@
+//! A reversed view of a strided slice.
+//!
+//! This is the equivalent of a negative stride: the logical element `i` of a
+//! [`Reversed`] is the logical element `len - 1 - i` of the wrapped strided
+//! slice, so indexing, iteration, and comparisons all work back-to-front
+//! without copying.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Rev;
+use core::ops::{
+    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+use crate::index::{self, index_fail};
+use crate::ops::{eq_by, hash_elements};
+use crate::{Iter, IterMut, Stride, StrideIndex};
+
+/// A strided slice viewed back-to-front.
+///
+/// This struct is created by the [`reversed()`][`Stride::reversed()`] and
+/// [`reversed_mut()`][`Stride::reversed_mut()`] methods on strided slices.
+///
+/// # Examples
+///
+/// ```
+/// # use stride::Stride;
+/// #
+/// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+/// let rev = stride.reversed();
+/// assert_eq!(rev, &[5, 3, 1]);
+/// assert_eq!(rev[0], 5);
+/// assert_eq!(rev[1..], &[3, 1]);
+/// ```
+#[repr(transparent)]
+pub struct Reversed<T, const S: usize> {
+    inner: Stride<T, S>,
+}
+
+impl<T, const S: usize> Stride<T, S> {
+    /// Returns a view of the strided slice with the elements in reverse order.
+    ///
+    /// Unlike [`reverse()`][`Stride::reverse()`] this does not move any
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(stride.reversed(), &[7, 4, 1]);
+    /// assert_eq!(stride.reversed().reversed(), stride);
+    /// ```
+    pub fn reversed(&self) -> &Reversed<T, S> {
+        unsafe { &*(self as *const Self as *const Reversed<T, S>) }
+    }
+
+    /// Returns a mutable view of the strided slice with the elements in
+    /// reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// stride.reversed_mut()[0] = 7;
+    /// assert_eq!(data, &[1, 2, 3, 4, 7, 6]);
+    /// ```
+    pub fn reversed_mut(&mut self) -> &mut Reversed<T, S> {
+        unsafe { &mut *(self as *mut Self as *mut Reversed<T, S>) }
+    }
+}
+
+impl<T, const S: usize> Reversed<T, S> {
+    /// Returns the number of elements.
+    pub const fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the strided slice in its original order.
+    pub fn reversed(&self) -> &Stride<T, S> {
+        &self.inner
+    }
+
+    /// Returns the mutable strided slice in its original order.
+    pub fn reversed_mut(&mut self) -> &mut Stride<T, S> {
+        &mut self.inner
+    }
+
+    /// Returns a reference to an element or subview depending on the type of
+    /// index, or `None` if the index is out of bounds.
+    ///
+    /// *See [`Stride::get()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let rev = stride.reversed();
+    /// assert_eq!(rev.get(0), Some(&5));
+    /// assert_eq!(rev.get(0..2).unwrap(), &[5, 3]);
+    /// assert_eq!(rev.get(3), None);
+    /// ```
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: StrideIndex<Self>,
+    {
+        index.get(self)
+    }
+
+    /// Returns a mutable reference to an element or subview depending on the
+    /// type of index, or `None` if the index is out of bounds.
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: StrideIndex<Self>,
+    {
+        index.get_mut(self)
+    }
+
+    /// Returns a reference to the first element, which is the last element of
+    /// the strided slice in its original order.
+    pub fn first(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    /// Returns a mutable reference to the first element.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.inner.last_mut()
+    }
+
+    /// Returns a reference to the last element, which is the first element of
+    /// the strided slice in its original order.
+    pub fn last(&self) -> Option<&T> {
+        self.inner.first()
+    }
+
+    /// Returns a mutable reference to the last element.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.inner.first_mut()
+    }
+
+    /// Returns an iterator over the elements, starting from the back of the
+    /// strided slice.
+    pub fn iter(&self) -> Rev<Iter<'_, T, S>> {
+        self.inner.iter().rev()
+    }
+
+    /// Returns an iterator over the elements that allows modifying each value.
+    pub fn iter_mut(&mut self) -> Rev<IterMut<'_, T, S>> {
+        self.inner.iter_mut().rev()
+    }
+}
+
+impl<T, const S: usize> Default for &Reversed<T, S> {
+    fn default() -> Self {
+        <&Stride<T, S>>::default().reversed()
+    }
+}
+
+impl<T, const S: usize> Default for &mut Reversed<T, S> {
+    fn default() -> Self {
+        <&mut Stride<T, S>>::default().reversed_mut()
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for Reversed<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a Reversed<T, S> {
+    type Item = &'a T;
+    type IntoIter = Rev<Iter<'a, T, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const S: usize> IntoIterator for &'a mut Reversed<T, S> {
+    type Item = &'a mut T;
+    type IntoIter = Rev<IterMut<'a, T, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<I, T, const S: usize> Index<I> for Reversed<T, S>
+where
+    I: StrideIndex<Self>,
+{
+    type Output = I::Output;
+
+    #[track_caller]
+    fn index(&self, index: I) -> &Self::Output {
+        index.index(self)
+    }
+}
+
+impl<I, T, const S: usize> IndexMut<I> for Reversed<T, S>
+where
+    I: StrideIndex<Self>,
+{
+    #[track_caller]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        index.index_mut(self)
+    }
+}
+
+unsafe impl<T, const S: usize> StrideIndex<Reversed<T, S>> for usize {
+    type Output = T;
+
+    fn get(self, rev: &Reversed<T, S>) -> Option<&Self::Output> {
+        let index = rev.len().checked_sub(self)?.checked_sub(1)?;
+        rev.inner.get(index)
+    }
+
+    fn get_mut(self, rev: &mut Reversed<T, S>) -> Option<&mut Self::Output> {
+        let index = rev.len().checked_sub(self)?.checked_sub(1)?;
+        rev.inner.get_mut(index)
+    }
+
+    unsafe fn get_unchecked(self, rev: *const Reversed<T, S>) -> *const Self::Output {
+        let len = unsafe { (*rev).len() };
+        unsafe { (len - 1 - self).get_unchecked(rev as *const Stride<T, S>) }
+    }
+
+    unsafe fn get_unchecked_mut(self, rev: *mut Reversed<T, S>) -> *mut Self::Output {
+        let len = unsafe { (*rev).len() };
+        unsafe { (len - 1 - self).get_unchecked_mut(rev as *mut Stride<T, S>) }
+    }
+
+    #[track_caller]
+    fn index(self, rev: &Reversed<T, S>) -> &Self::Output {
+        match self.get(rev) {
+            Some(elem) => elem,
+            None => index_fail(self, rev.len(), S),
+        }
+    }
+
+    #[track_caller]
+    fn index_mut(self, rev: &mut Reversed<T, S>) -> &mut Self::Output {
+        let len = rev.len();
+        match self.get_mut(rev) {
+            Some(elem) => elem,
+            None => index_fail(self, len, S),
+        }
+    }
+}
+
+/// Converts any range over `usize` into a `Range`, or returns `None` if it is
+/// out of bounds of `len`.
+fn checked_range<R>(range: R, len: usize) -> Option<Range<usize>>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return None;
+    }
+    Some(Range { start, end })
+}
+
+/// Maps a range of the reversed view to the range of the same elements in the
+/// original strided slice.
+fn unreverse(range: Range<usize>, len: usize) -> Range<usize> {
+    (len - range.end)..(len - range.start)
+}
+
+macro_rules! impl_stride_index {
+    ($Index:ty) => {
+        unsafe impl<T, const S: usize> StrideIndex<Reversed<T, S>> for $Index {
+            type Output = Reversed<T, S>;
+
+            fn get(self, rev: &Reversed<T, S>) -> Option<&Self::Output> {
+                let range = unreverse(checked_range(self, rev.len())?, rev.len());
+                rev.inner.get(range).map(Stride::reversed)
+            }
+
+            fn get_mut(self, rev: &mut Reversed<T, S>) -> Option<&mut Self::Output> {
+                let range = unreverse(checked_range(self, rev.len())?, rev.len());
+                rev.inner.get_mut(range).map(Stride::reversed_mut)
+            }
+
+            unsafe fn get_unchecked(self, rev: *const Reversed<T, S>) -> *const Self::Output {
+                let len = unsafe { (*rev).len() };
+                let range = unreverse(unsafe { checked_range(self, len).unwrap_unchecked() }, len);
+                let inner = unsafe { range.get_unchecked(rev as *const Stride<T, S>) };
+                inner as *const Reversed<T, S>
+            }
+
+            unsafe fn get_unchecked_mut(self, rev: *mut Reversed<T, S>) -> *mut Self::Output {
+                let len = unsafe { (*rev).len() };
+                let range = unreverse(unsafe { checked_range(self, len).unwrap_unchecked() }, len);
+                let inner = unsafe { range.get_unchecked_mut(rev as *mut Stride<T, S>) };
+                inner as *mut Reversed<T, S>
+            }
+
+            #[track_caller]
+            fn index(self, rev: &Reversed<T, S>) -> &Self::Output {
+                let range = unreverse(index::range::<_, S>(self, rev.len()), rev.len());
+                // SAFETY: the range was checked to be in bounds.
+                unsafe { rev.inner.get_unchecked(range).reversed() }
+            }
+
+            #[track_caller]
+            fn index_mut(self, rev: &mut Reversed<T, S>) -> &mut Self::Output {
+                let range = unreverse(index::range::<_, S>(self, rev.len()), rev.len());
+                // SAFETY: the range was checked to be in bounds.
+                unsafe { rev.inner.get_unchecked_mut(range).reversed_mut() }
+            }
+        }
+    };
+}
+
+impl_stride_index! { Range<usize> }
+impl_stride_index! { RangeFrom<usize> }
+impl_stride_index! { RangeFull }
+impl_stride_index! { RangeInclusive<usize> }
+impl_stride_index! { RangeTo<usize> }
+impl_stride_index! { RangeToInclusive<usize> }
+
+macro_rules! impl_partial_eq {
+    ($Rhs:ty, [$($params:tt)*]) => {
+        impl<T, U, const S: usize, $($params)*> PartialEq<$Rhs> for Reversed<T, S>
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$Rhs) -> bool {
+                eq_by(self.iter(), other.iter())
+            }
+        }
+    };
+}
+
+impl_partial_eq! { Reversed<U, R>, [const R: usize] }
+impl_partial_eq! { Stride<U, R>, [const R: usize] }
+impl_partial_eq! { &Stride<U, R>, [const R: usize] }
+impl_partial_eq! { [U], [] }
+impl_partial_eq! { &[U], [] }
+impl_partial_eq! { [U; N], [const N: usize] }
+impl_partial_eq! { &[U; N], [const N: usize] }
+
+impl<T, U, const S: usize, const R: usize> PartialEq<Reversed<U, R>> for Stride<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Reversed<U, R>) -> bool {
+        eq_by(self.iter(), other.iter())
+    }
+}
+
+impl<T: Eq, const S: usize> Eq for Reversed<T, S> {}
+
+impl<T, const S: usize> Hash for Reversed<T, S>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_elements(self.iter(), state)
+    }
+}
@@ -0,0 +1,351 @@
+//! Gathering and scattering strided slices with portable SIMD.
+//!
+//! This also specializes [`copy_to_slice()`][`Stride::copy_to_slice()`],
+//! [`copy_from_slice()`][`Stride::copy_from_slice()`] and
+//! [`fill()`][`Stride::fill()`] for the primitive types with small strides.
+//! Gathering each element with its own pointer is no faster than the scalar
+//! loop, so these load two contiguous vectors at a time and shuffle the
+//! elements of a chunk out of them, or blend them in.
+//!
+//! Only available with the `portable_simd` feature, which requires a nightly
+//! compiler.
+
+use core::array;
+use core::mem::{self, MaybeUninit};
+use core::simd::ptr::{SimdConstPtr, SimdMutPtr};
+use core::simd::{Mask, MaskElement, Select, Simd, SimdElement, Swizzle};
+
+use crate::Stride;
+
+/// The number of lanes used to copy strided slices to and from slices.
+const LANES: usize = 8;
+
+/// Copies between strided slices and slices of the same length, with SIMD for
+/// the primitive types and one element at a time for any other type.
+pub(crate) trait SpecCopy: Copy {
+    fn copy_to_uninit<const S: usize>(src: &Stride<Self, S>, dst: &mut [MaybeUninit<Self>]);
+
+    fn copy_from_slice<const S: usize>(dst: &mut Stride<Self, S>, src: &[Self]);
+}
+
+impl<T> SpecCopy for T
+where
+    T: Copy,
+{
+    default fn copy_to_uninit<const S: usize>(src: &Stride<T, S>, dst: &mut [MaybeUninit<T>]) {
+        src.copy_to_uninit_scalar(dst)
+    }
+
+    default fn copy_from_slice<const S: usize>(dst: &mut Stride<T, S>, src: &[T]) {
+        dst.copy_from_slice_scalar(src)
+    }
+}
+
+macro_rules! impl_spec_copy {
+    ($($ty:ty),*) => {$(
+        impl SpecCopy for $ty {
+            fn copy_to_uninit<const S: usize>(src: &Stride<$ty, S>, dst: &mut [MaybeUninit<$ty>]) {
+                src.copy_to_uninit_simd(dst)
+            }
+
+            fn copy_from_slice<const S: usize>(dst: &mut Stride<$ty, S>, src: &[$ty]) {
+                dst.copy_from_slice_simd(src)
+            }
+        }
+    )*};
+}
+
+impl_spec_copy! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64 }
+
+/// Fills strided slices, with SIMD for the primitive types and one element at
+/// a time for any other type.
+pub(crate) trait SpecFill: Clone {
+    fn fill<const S: usize>(dst: &mut Stride<Self, S>, value: Self);
+}
+
+impl<T> SpecFill for T
+where
+    T: Clone,
+{
+    default fn fill<const S: usize>(dst: &mut Stride<T, S>, value: T) {
+        dst.fill_scalar(value)
+    }
+}
+
+macro_rules! impl_spec_fill {
+    ($($ty:ty),*) => {$(
+        impl SpecFill for $ty {
+            fn fill<const S: usize>(dst: &mut Stride<$ty, S>, value: $ty) {
+                dst.fill_simd(value)
+            }
+        }
+    )*};
+}
+
+impl_spec_fill! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64 }
+
+impl<T, const S: usize> Stride<T, S>
+where
+    T: SimdElement,
+{
+    /// Returns the offsets of `N` consecutive elements in the underlying
+    /// slice.
+    fn simd_offsets<const N: usize>() -> Simd<usize, N> {
+        Simd::from_array(array::from_fn(|i| i * S))
+    }
+
+    /// Gathers `N` consecutive elements of a strided slice starting at `src`.
+    ///
+    /// # Safety
+    ///
+    /// The `N` elements must be in bounds of the same underlying slice.
+    unsafe fn gather<const N: usize>(src: *const T) -> Simd<T, N> {
+        let ptrs = Simd::splat(src).wrapping_add(Self::simd_offsets());
+        // All lanes are enabled, so the fallback, which only needs to be
+        // some `T`, is never used.
+        unsafe { Simd::gather_select_ptr(ptrs, Mask::splat(true), Simd::splat(*src)) }
+    }
+
+    /// Gathers the first `N` elements of the strided slice into a SIMD
+    /// vector.
+    ///
+    /// Only available with the `portable_simd` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the strided slice is less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// assert_eq!(stride.load_simd(), Simd::from_array([1, 4, 7, 10]));
+    /// assert_eq!(stride[1..].load_simd(), Simd::from_array([4, 7]));
+    /// ```
+    #[track_caller]
+    pub fn load_simd<const N: usize>(&self) -> Simd<T, N> {
+        assert!(
+            self.len() >= N,
+            "strided slice of length {} is too short for {} lanes",
+            self.len(),
+            N
+        );
+        // SAFETY: the first `N` elements are in bounds as checked above.
+        unsafe { Self::gather(self.as_ptr()) }
+    }
+
+    /// Scatters a SIMD vector into the first `N` elements of the strided
+    /// slice.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched.
+    ///
+    /// Only available with the `portable_simd` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the strided slice is less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// # use stride::Stride;
+    /// #
+    /// let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    /// let stride = Stride::<_, 2>::new_mut(data);
+    /// let value = stride.load_simd::<4>() * Simd::splat(10);
+    /// stride.store_simd(value);
+    /// assert_eq!(data, &[10, 2, 30, 4, 50, 6, 70]);
+    /// ```
+    #[track_caller]
+    pub fn store_simd<const N: usize>(&mut self, value: Simd<T, N>) {
+        assert!(
+            self.len() >= N,
+            "strided slice of length {} is too short for {} lanes",
+            self.len(),
+            N
+        );
+        let ptrs = Simd::splat(self.as_mut_ptr()).wrapping_add(Self::simd_offsets());
+        // SAFETY: the first `N` elements are in bounds as checked above.
+        unsafe { value.scatter_ptr(ptrs) }
+    }
+
+    /// Copies all elements into `dst`, which must have the same length,
+    /// `LANES` elements at a time.
+    fn copy_to_uninit_simd(&self, dst: &mut [MaybeUninit<T>]) {
+        // Each chunk is picked out of two vectors of `WIDE` elements, so
+        // chunks of strides that don't fit and chunks near the end of the
+        // underlying slice use the scalar loop.
+        let n = if is_wide::<T>(S) {
+            wide_chunks(self.data.len(), S).min(dst.len() / LANES)
+        } else {
+            0
+        };
+        let src = self.data.as_ptr();
+        for (i, chunk) in dst[..n * LANES].chunks_exact_mut(LANES).enumerate() {
+            // SAFETY: the two vectors are in bounds as checked by
+            // `wide_chunks()`, and `MaybeUninit<T>` has the same layout as `T`.
+            unsafe {
+                let p = src.add(i * LANES * S);
+                let a = p.cast::<Simd<T, WIDE>>().read_unaligned();
+                let b = p.add(WIDE).cast::<Simd<T, WIDE>>().read_unaligned();
+                let value = Deinterleave::<S>::concat_swizzle(a, b);
+                chunk
+                    .as_mut_ptr()
+                    .cast::<Simd<T, LANES>>()
+                    .write_unaligned(value);
+            }
+        }
+        self[n * LANES..].copy_to_uninit_scalar(&mut dst[n * LANES..]);
+    }
+
+    /// Copies all elements from `src`, which must have the same length,
+    /// `LANES` elements at a time.
+    ///
+    /// The elements in between are read and written back with the same value.
+    fn copy_from_slice_simd(&mut self, src: &[T]) {
+        // Spreading a chunk with a stride of 3 doesn't lower to efficient
+        // shuffles, so it's slower than the scalar loop.
+        let n = if is_wide::<T>(S) && S != 3 {
+            wide_chunks(self.data.len(), S).min(src.len() / LANES)
+        } else {
+            0
+        };
+        let (mask_low, mask_high) = (
+            Spread::<S, 0>::mask::<T::Mask>(),
+            Spread::<S, WIDE>::mask::<T::Mask>(),
+        );
+        let dst = self.data.as_mut_ptr();
+        for (i, chunk) in src[..n * LANES].chunks_exact(LANES).enumerate() {
+            // SAFETY: the two vectors are in bounds as checked by
+            // `wide_chunks()`.
+            unsafe {
+                let p = dst.add(i * LANES * S);
+                let a = p.cast::<Simd<T, WIDE>>().read_unaligned();
+                let b = p.add(WIDE).cast::<Simd<T, WIDE>>().read_unaligned();
+                let value = Simd::<T, LANES>::from_slice(chunk).resize::<WIDE>(a[0]);
+                let low = Spread::<S, 0>::swizzle(value);
+                let high = Spread::<S, WIDE>::swizzle(value);
+                let a = mask_low.select(low, a);
+                let b = mask_high.select(high, b);
+                p.cast::<Simd<T, WIDE>>().write_unaligned(a);
+                p.add(WIDE).cast::<Simd<T, WIDE>>().write_unaligned(b);
+            }
+        }
+        self[n * LANES..].copy_from_slice_scalar(&src[n * LANES..]);
+    }
+
+    /// Fills the strided slice with `value`, `LANES` elements at a time.
+    ///
+    /// The elements in between are read and written back with the same value.
+    fn fill_simd(&mut self, value: T) {
+        // Like in `copy_from_slice_simd()`, blending chunks with a stride of 3
+        // is slower than the scalar loop.
+        let n = if is_wide::<T>(S) && S != 3 {
+            wide_chunks(self.data.len(), S).min(self.len() / LANES)
+        } else {
+            0
+        };
+        let (mask_low, mask_high) = (
+            Spread::<S, 0>::mask::<T::Mask>(),
+            Spread::<S, WIDE>::mask::<T::Mask>(),
+        );
+        let value = Simd::<T, WIDE>::splat(value);
+        let dst = self.data.as_mut_ptr();
+        for i in 0..n {
+            // SAFETY: the two vectors are in bounds as checked by
+            // `wide_chunks()`.
+            unsafe {
+                let p = dst.add(i * LANES * S);
+                let a = p.cast::<Simd<T, WIDE>>().read_unaligned();
+                let b = p.add(WIDE).cast::<Simd<T, WIDE>>().read_unaligned();
+                let a = mask_low.select(value, a);
+                let b = mask_high.select(value, b);
+                p.cast::<Simd<T, WIDE>>().write_unaligned(a);
+                p.add(WIDE).cast::<Simd<T, WIDE>>().write_unaligned(b);
+            }
+        }
+        self[n * LANES..].fill_scalar(value[0]);
+    }
+}
+
+/// The number of elements in each of the two vectors that a chunk of `LANES`
+/// elements is copied from or to.
+const WIDE: usize = 2 * LANES;
+
+/// The largest stride where a chunk fits into two vectors of `WIDE` elements.
+const MAX_STRIDE: usize = (2 * WIDE - 1) / (LANES - 1);
+
+/// Returns whether chunks with the given stride are copied with two vectors of
+/// `WIDE` elements.
+///
+/// A stride of 1 is already a plain copy, and strides that span 24 bytes or
+/// more are no faster than the scalar loop.
+fn is_wide<T>(stride: usize) -> bool {
+    (2..=MAX_STRIDE).contains(&stride) && stride * mem::size_of::<T>() < 24
+}
+
+/// Returns the number of chunks of `LANES` elements at the start of an
+/// underlying slice of length `len` whose two vectors are in bounds.
+fn wide_chunks(len: usize, stride: usize) -> usize {
+    match len.checked_sub(2 * WIDE) {
+        Some(rest) => rest / (LANES * stride) + 1,
+        None => 0,
+    }
+}
+
+/// Picks every `S`th element of two concatenated vectors.
+struct Deinterleave<const S: usize>;
+
+impl<const S: usize> Swizzle<LANES> for Deinterleave<S> {
+    const INDEX: [usize; LANES] = {
+        let mut index = [0; LANES];
+        let mut i = 0;
+        while i < LANES {
+            // Strides that don't fit are never swizzled, but the indices still
+            // have to be in bounds.
+            if i * S < 2 * WIDE {
+                index[i] = i * S;
+            }
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Moves the elements of a chunk to every `S`th position of the vector that
+/// starts `OFFSET` elements into the chunk.
+struct Spread<const S: usize, const OFFSET: usize>;
+
+impl<const S: usize, const OFFSET: usize> Spread<S, OFFSET> {
+    /// Returns whether each position of the vector is an element of the chunk.
+    const fn is_element(i: usize) -> bool {
+        let pos = OFFSET + i;
+        pos.is_multiple_of(S) && pos / S < LANES
+    }
+
+    /// Returns the positions of the vector that are elements of the chunk.
+    fn mask<M: MaskElement>() -> Mask<M, WIDE> {
+        Mask::from_array(array::from_fn(Self::is_element))
+    }
+}
+
+impl<const S: usize, const OFFSET: usize> Swizzle<WIDE> for Spread<S, OFFSET> {
+    const INDEX: [usize; WIDE] = {
+        let mut index = [0; WIDE];
+        let mut i = 0;
+        while i < WIDE {
+            if Self::is_element(i) {
+                index[i] = (OFFSET + i) / S;
+            }
+            i += 1;
+        }
+        index
+    };
+}
@@ -0,0 +1,295 @@
+//! Sorting algorithms for strided slices.
+//!
+//! The unstable sort is an introsort: quicksort with a median of three pivot
+//! and a three-way partition, falling back to heapsort if the recursion gets
+//! too deep and to insertion sort for short strided slices. All algorithms
+//! operate purely by swapping elements at logical positions so elements in the
+//! underlying slice that are not part of the strided slice are never touched.
+//!
+//! The stable sort sorts a vector of indexes into the strided slice using the
+//! standard library's stable sort and then applies the resulting permutation.
+//!
+//! The parallel sorts work the same way, except that the unstable sort sorts
+//! both sides of each partition in parallel and the stable sort uses Rayon's
+//! parallel sort for the indexes.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+#[cfg(feature = "rayon")]
+use rayon::slice::ParallelSliceMut;
+
+use crate::Stride;
+
+/// Strided slices of up to this length get sorted using insertion sort.
+const MAX_INSERTION: usize = 20;
+
+/// Strided slices of up to this length get sorted sequentially by the parallel
+/// sort, since splitting them up costs more than it saves.
+#[cfg(feature = "rayon")]
+const MAX_SEQUENTIAL: usize = 2000;
+
+/// Sorts `v` using insertion sort, which is *O*(*n*^2) worst-case.
+fn insertion_sort<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && is_less(&v[j], &v[j - 1]) {
+            v.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Restores the heap property of the heap `v[..len]` rooted at `node`.
+fn sift_down<T, F, const S: usize>(
+    v: &mut Stride<T, S>,
+    mut node: usize,
+    len: usize,
+    is_less: &mut F,
+) where
+    F: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= len {
+            break;
+        }
+        if child + 1 < len && is_less(&v[child], &v[child + 1]) {
+            child += 1;
+        }
+        if !is_less(&v[node], &v[child]) {
+            break;
+        }
+        v.swap(node, child);
+        node = child;
+    }
+}
+
+/// Sorts `v` using heapsort, which guarantees *O*(*n* \* log(*n*)) worst-case.
+fn heapsort<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    for node in (0..len / 2).rev() {
+        sift_down(v, node, len, is_less);
+    }
+    for end in (1..len).rev() {
+        v.swap(0, end);
+        sift_down(v, 0, end, is_less);
+    }
+}
+
+/// Returns the index of the median of the first, middle, and last elements.
+///
+/// `v` must not be empty.
+fn choose_pivot<T, F, const S: usize>(v: &Stride<T, S>, is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let (a, b, c) = (0, v.len() / 2, v.len() - 1);
+    let ab = is_less(&v[a], &v[b]);
+    let bc = is_less(&v[b], &v[c]);
+    let ac = is_less(&v[a], &v[c]);
+    if ab == bc {
+        b
+    } else if ab == ac {
+        c
+    } else {
+        a
+    }
+}
+
+/// Partitions `v` into elements less than, equal to, and greater than the
+/// element at `pivot`.
+///
+/// Returns `(lt, gt)` such that `v[..lt]` are less than the pivot, `v[lt..gt]`
+/// are equal to the pivot, and `v[gt..]` are greater than the pivot.
+fn partition<T, F, const S: usize>(
+    v: &mut Stride<T, S>,
+    pivot: usize,
+    is_less: &mut F,
+) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    v.swap(0, pivot);
+    // The element at `lt` is always equal to the pivot.
+    let (mut lt, mut i, mut gt) = (0, 1, v.len());
+    while i < gt {
+        if is_less(&v[i], &v[lt]) {
+            v.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if is_less(&v[lt], &v[i]) {
+            gt -= 1;
+            v.swap(i, gt);
+        } else {
+            i += 1;
+        }
+    }
+    (lt, gt)
+}
+
+/// Returns the number of imbalanced partitions allowed before switching to
+/// heapsort.
+fn limit(len: usize) -> u32 {
+    2 * (usize::BITS - len.leading_zeros())
+}
+
+/// Sorts `v` using introsort, which is *O*(*n* \* log(*n*)) worst-case.
+pub(crate) fn quicksort<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    recurse(v, is_less, limit(v.len()));
+}
+
+fn recurse<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &mut F, limit: u32)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if v.len() <= MAX_INSERTION {
+        return insertion_sort(v, is_less);
+    }
+    if limit == 0 {
+        return heapsort(v, is_less);
+    }
+    let pivot = choose_pivot(v, is_less);
+    let (lt, gt) = partition(v, pivot, is_less);
+    let (left, rest) = v.split_at_mut(lt);
+    let (_, right) = rest.split_at_mut(gt - lt);
+    recurse(left, is_less, limit - 1);
+    recurse(right, is_less, limit - 1);
+}
+
+/// Sorts `v` using introsort, sorting both sides of each partition in
+/// parallel.
+#[cfg(feature = "rayon")]
+pub(crate) fn par_quicksort<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    par_recurse(v, is_less, limit(v.len()));
+}
+
+#[cfg(feature = "rayon")]
+fn par_recurse<T, F, const S: usize>(v: &mut Stride<T, S>, is_less: &F, limit: u32)
+where
+    T: Send,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    if v.len() <= MAX_SEQUENTIAL {
+        return recurse(v, &mut |a, b| is_less(a, b), limit);
+    }
+    if limit == 0 {
+        return heapsort(v, &mut |a, b| is_less(a, b));
+    }
+    let pivot = choose_pivot(v, &mut |a, b| is_less(a, b));
+    let (lt, gt) = partition(v, pivot, &mut |a, b| is_less(a, b));
+    let (left, rest) = v.split_at_mut(lt);
+    let (_, right) = rest.split_at_mut(gt - lt);
+    rayon::join(
+        || par_recurse(left, is_less, limit - 1),
+        || par_recurse(right, is_less, limit - 1),
+    );
+}
+
+/// Reorders `v` such that the element at `index` is at its final sorted
+/// position, using quickselect.
+///
+/// Falls back to sorting `v` if it is short or the recursion gets too deep.
+pub(crate) fn select<T, F, const S: usize>(v: &mut Stride<T, S>, index: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    select_recurse(v, index, is_less, limit(v.len()));
+}
+
+fn select_recurse<T, F, const S: usize>(
+    v: &mut Stride<T, S>,
+    index: usize,
+    is_less: &mut F,
+    limit: u32,
+) where
+    F: FnMut(&T, &T) -> bool,
+{
+    if v.len() <= MAX_INSERTION {
+        return insertion_sort(v, is_less);
+    }
+    if limit == 0 {
+        return heapsort(v, is_less);
+    }
+    let pivot = choose_pivot(v, is_less);
+    let (lt, gt) = partition(v, pivot, is_less);
+    if index < lt {
+        select_recurse(&mut v[..lt], index, is_less, limit - 1);
+    } else if index >= gt {
+        select_recurse(&mut v[gt..], index - gt, is_less, limit - 1);
+    }
+}
+
+/// Sorts `v` using a stable sort, which is *O*(*n* \* log(*n*)) worst-case.
+#[cfg(feature = "alloc")]
+pub(crate) fn stable_sort<T, F, const S: usize>(v: &mut Stride<T, S>, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut indices: Vec<usize> = (0..v.len()).collect();
+    indices.sort_by(|&a, &b| compare(&v[a], &v[b]));
+    permute(v, &mut indices);
+}
+
+/// Sorts `v` using a stable sort, sorting the indexes in parallel.
+#[cfg(feature = "rayon")]
+pub(crate) fn par_stable_sort<T, F, const S: usize>(v: &mut Stride<T, S>, compare: &F)
+where
+    T: Sync,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let mut indices: Vec<usize> = (0..v.len()).collect();
+    let shared = &*v;
+    indices.par_sort_by(|&a, &b| compare(&shared[a], &shared[b]));
+    permute(v, &mut indices);
+}
+
+/// Sorts `v` using a stable sort, calling the key extraction function only once
+/// per element.
+#[cfg(feature = "alloc")]
+pub(crate) fn cached_key_sort<T, K, F, const S: usize>(v: &mut Stride<T, S>, f: &mut F)
+where
+    F: FnMut(&T) -> K,
+    K: Ord,
+{
+    // Each key is paired with its index, so an unstable sort is stable here.
+    let mut keys: Vec<(K, usize)> = v.iter().map(f).enumerate().map(|(i, k)| (k, i)).collect();
+    keys.sort_unstable();
+    let mut indices: Vec<usize> = keys.into_iter().map(|(_, i)| i).collect();
+    permute(v, &mut indices);
+}
+
+/// Moves the element at `indices[i]` to `i` for each `i`, by following each
+/// cycle of the permutation.
+///
+/// `indices` is left as the identity permutation.
+#[cfg(feature = "alloc")]
+fn permute<T, const S: usize>(v: &mut Stride<T, S>, indices: &mut [usize]) {
+    for start in 0..indices.len() {
+        let mut i = start;
+        loop {
+            let j = indices[i];
+            indices[i] = i;
+            if j == start {
+                break;
+            }
+            v.swap(i, j);
+            i = j;
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Initializing strided slices of uninitialized memory.
+//!
+//! Writing to a strided slice of [`MaybeUninit<T>`] only initializes its own
+//! elements, not the elements in between. A `&mut Stride<T, S>` gives access to
+//! the whole underlying slice, so it can only be assumed once every element of
+//! the underlying slice has been written, e.g. by initializing each lane.
+
+use core::mem::MaybeUninit;
+
+use crate::Stride;
+
+impl<T, const S: usize> Stride<MaybeUninit<T>, S> {
+    /// Initializes every element of the strided slice with a clone of `value`.
+    ///
+    /// Elements in the underlying slice that are not part of the strided slice
+    /// are left untouched. Any previously initialized elements are overwritten
+    /// without being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use stride::Stride;
+    /// #
+    /// let mut buf = [MaybeUninit::uninit(); 6];
+    /// Stride::<_, 2>::new_mut(&mut buf).write_filled(0);
+    /// Stride::<_, 2>::new_with_offset_mut(&mut buf, 1).write_filled(1);
+    /// // SAFETY: both lanes were initialized.
+    /// let stride = unsafe { Stride::<_, 2>::new_mut(&mut buf).assume_init() };
+    /// assert_eq!(stride.underlying(), &[0, 1, 0, 1, 0, 1]);
+    /// ```
+    pub fn write_filled(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        if let Some((last, elems)) = self.split_last_mut() {
+            for elem in elems {
+                elem.write(value.clone());
+            }
+            last.write(value);
+        }
+    }
+
+    /// Initializes the elements of the strided slice with the values from an
+    /// iterator, returning the number of elements that were initialized.
+    ///
+    /// This stops when either the strided slice or the iterator runs out, so
+    /// the first `n` elements are initialized where `n` is the returned
+    /// count. Elements in the underlying slice that are not part of the
+    /// strided slice are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use stride::Stride;
+    /// #
+    /// let mut buf = [MaybeUninit::uninit(); 6];
+    /// assert_eq!(Stride::<_, 2>::new_mut(&mut buf).write_iter(1..), 3);
+    /// assert_eq!(Stride::<_, 2>::new_with_offset_mut(&mut buf, 1).write_iter([7, 8, 9]), 3);
+    /// // SAFETY: both lanes were initialized.
+    /// let stride = unsafe { Stride::<_, 2>::new_mut(&mut buf).assume_init() };
+    /// assert_eq!(stride, &[1, 2, 3]);
+    /// assert_eq!(stride.underlying(), &[1, 7, 2, 8, 3, 9]);
+    /// ```
+    pub fn write_iter<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut n = 0;
+        for (elem, value) in self.iter_mut().zip(iter) {
+            elem.write(value);
+            n += 1;
+        }
+        n
+    }
+
+    /// Returns the strided slice as initialized elements.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the underlying slice must be initialized, including
+    /// the elements in between that are not part of the strided slice.
+    pub unsafe fn assume_init_ref(&self) -> &Stride<T, S> {
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and the caller
+        // guarantees that every element is initialized.
+        unsafe { Stride::from_raw_parts(self.data.as_ptr().cast(), self.data.len()) }
+    }
+
+    /// Returns the mutable strided slice as initialized elements.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the underlying slice must be initialized, including
+    /// the elements in between that are not part of the strided slice.
+    pub unsafe fn assume_init(&mut self) -> &mut Stride<T, S> {
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and the caller
+        // guarantees that every element is initialized.
+        unsafe { Stride::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.data.len()) }
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use stride::Stride;
+
+#[test]
+fn stride_arbitrary() {
+    let bytes = [1, 2, 3, 4, 5, 6, 7];
+    let stride = <&Stride<u8, 2>>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    assert_eq!(stride, &[1, 3, 5, 7]);
+
+    let mut u = Unstructured::new(&bytes);
+    let stride = <&Stride<u8, 3>>::arbitrary(&mut u).unwrap();
+    assert_eq!(stride.underlying(), &bytes[..stride.underlying().len()]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_buf_arbitrary() {
+    use stride::StrideBuf;
+
+    let bytes: Vec<u8> = (0..64).collect();
+    let vec = Vec::<u16>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    let buf = StrideBuf::<u16, 2>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    assert_eq!(buf.underlying(), vec);
+    assert_eq!(buf, *Stride::<_, 2>::new(&vec));
+
+    let boxed = Box::<Stride<u16, 2>>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    assert_eq!(*boxed, *buf);
+}
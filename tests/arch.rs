@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use stride::Stride;
+
+macro_rules! check_gather {
+    ($ty:ty, $($s:literal),*) => {$({
+        let data: Vec<$ty> = (0..500u32).map(|x| x as u8 as $ty).collect();
+        for start in 0..4 {
+            for end in (start..data.len()).step_by(37).chain([data.len()]) {
+                let stride = Stride::<_, $s>::new(&data[start..end]);
+                let mut expected = vec![0 as $ty; stride.len()];
+                stride.copy_to_slice(&mut expected);
+                let mut dst = vec![0 as $ty; stride.len()];
+                stride.gather_to_slice(&mut dst);
+                assert_eq!(dst, expected, "stride {} of {}..{}", $s, start, end);
+            }
+        }
+    })*};
+}
+
+macro_rules! check_scatter {
+    ($ty:ty, $($s:literal),*) => {$({
+        let src: Vec<$ty> = (0..500u32).map(|x| x as u8 as $ty).collect();
+        for start in 0..4 {
+            for end in (start..src.len()).step_by(37).chain([src.len()]) {
+                let mut expected = vec![255 as $ty; end - start];
+                let stride = Stride::<_, $s>::new_mut(&mut expected);
+                let len = stride.len();
+                stride.copy_from_slice(&src[..len]);
+                let mut data = vec![255 as $ty; end - start];
+                Stride::<_, $s>::new_mut(&mut data).scatter_from_slice(&src[..len]);
+                assert_eq!(data, expected, "stride {} of {}..{}", $s, start, end);
+            }
+        }
+    })*};
+}
+
+#[test]
+fn stride_gather_to_slice() {
+    check_gather!(u8, 1, 2, 3, 4, 5);
+    check_gather!(f32, 1, 2, 3, 4, 5);
+    check_gather!(f64, 1, 2, 3, 4, 5);
+}
+
+#[test]
+fn stride_gather_to_slice_nan() {
+    let nan = f32::from_bits(0x7fc0_1234);
+    let data = vec![nan; 64];
+    let mut dst = vec![0.0; 32];
+    Stride::<_, 2>::new(&data).gather_to_slice(&mut dst);
+    assert!(dst.iter().all(|x| x.to_bits() == nan.to_bits()));
+}
+
+#[test]
+fn stride_scatter_from_slice() {
+    check_scatter!(u8, 1, 2, 3, 4, 5);
+    check_scatter!(f32, 1, 2, 3, 4, 5);
+    check_scatter!(f64, 1, 2, 3, 4, 5);
+}
+
+#[test]
+#[should_panic(expected = "source length (3) does not match destination length (2)")]
+fn stride_gather_to_slice_len_mismatch() {
+    let stride = Stride::<_, 2>::new(&[1.0f32, 2.0, 3.0, 4.0, 5.0]);
+    stride.gather_to_slice(&mut [0.0; 2]);
+}
+
+#[test]
+#[should_panic(expected = "source length (2) does not match destination length (3)")]
+fn stride_scatter_from_slice_len_mismatch() {
+    let data = &mut [0u8; 5];
+    Stride::<_, 2>::new_mut(data).scatter_from_slice(&[1, 2]);
+}
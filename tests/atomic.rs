@@ -0,0 +1,64 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+use stride::Stride;
+
+#[test]
+fn stride_as_atomic() {
+    let data: &mut [u32] = &mut [1, 2, 3, 4, 5];
+    let atomics = Stride::<_, 2>::new_mut(data).as_atomic();
+    assert_eq!(atomics.len(), 3);
+    assert_eq!(atomics.underlying().len(), 5);
+    atomics[1].store(7, Ordering::Relaxed);
+    assert_eq!(atomics[2].swap(0, Ordering::Relaxed), 5);
+    assert_eq!(data, &[1, 2, 7, 4, 0]);
+}
+
+#[test]
+fn stride_as_atomic_channels() {
+    let mut data = vec![0u64; 3 * 1000];
+    let atomics = Stride::<_, 1>::new_mut(&mut data).as_atomic();
+    thread::scope(|s| {
+        for channel in 0..3 {
+            let stride = Stride::<_, 3>::new(&atomics.underlying()[channel..]);
+            s.spawn(move || {
+                for elem in stride {
+                    elem.fetch_add(channel as u64 + 1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert!(data.chunks(3).all(|chunk| chunk == [1, 2, 3]));
+}
+
+#[test]
+fn stride_as_atomic_shared() {
+    let data: &mut [u32] = &mut [0; 8];
+    let atomics: &Stride<AtomicU32, 4> = Stride::new_mut(data).as_atomic();
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    atomics[1].fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(data, &[0, 0, 0, 0, 400, 0, 0, 0]);
+}
+
+#[test]
+fn stride_as_atomic_signed() {
+    let data: &mut [i8] = &mut [-1, 0, -2];
+    let atomics = Stride::<_, 2>::new_mut(data).as_atomic();
+    atomics[1].fetch_sub(1, Ordering::Relaxed);
+    assert_eq!(data, &[-1, 0, -3]);
+}
+
+#[test]
+fn stride_as_atomic_empty() {
+    let data: &mut [u64] = &mut [];
+    let atomics = Stride::<_, 3>::new_mut(data).as_atomic();
+    assert!(atomics.is_empty());
+    assert!(atomics.underlying().is_empty());
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::{Borrow, Cow};
+use std::collections::HashSet;
+
+use stride::{Stride, StrideBuf};
+
+#[test]
+fn stride_buf_new() {
+    let buf = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3, 4, 5]);
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf, [1, 3, 5]);
+    assert_eq!(buf[1], 3);
+    assert_eq!(buf.underlying(), &[1, 2, 3, 4, 5]);
+    assert_eq!(buf.into_underlying(), vec![1, 2, 3, 4, 5]);
+    assert!(StrideBuf::<i32, 2>::default().is_empty());
+}
+
+#[test]
+fn stride_buf_mut() {
+    let mut buf: StrideBuf<_, 2> = vec![1, 2, 3, 4].into();
+    buf[0] = 7;
+    for x in &mut buf {
+        *x += 1;
+    }
+    assert_eq!(buf, &[8, 4]);
+    assert_eq!(buf.into_underlying(), vec![8, 2, 4, 4]);
+}
+
+#[test]
+fn stride_to_owned() {
+    let data = &[1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 3>::new(data);
+    let buf = stride.to_owned();
+    assert_eq!(buf, *stride);
+    assert_eq!(buf.underlying(), data);
+    assert_eq!(*Borrow::<Stride<_, 3>>::borrow(&buf), *stride);
+
+    let mut target = StrideBuf::from_vec(vec![0; 10]);
+    Stride::<_, 3>::new(&[9, 8]).clone_into(&mut target);
+    assert_eq!(target.into_underlying(), vec![9, 8]);
+}
+
+#[test]
+fn stride_buf_cow() {
+    let data = &[1, 2, 3, 4];
+    let mut cow = Cow::Borrowed(Stride::<_, 2>::new(data));
+    assert_eq!(*cow, [1, 3]);
+    cow.to_mut()[1] = 5;
+    assert_eq!(*cow, [1, 5]);
+    assert_eq!(cow.into_owned().into_underlying(), vec![1, 2, 5, 4]);
+}
+
+#[test]
+fn stride_buf_hash() {
+    let mut set = HashSet::new();
+    set.insert(StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]));
+    assert!(set.contains(Stride::<_, 2>::new(&[1, 0, 3])));
+    assert!(!set.contains(Stride::<_, 2>::new(&[1, 2])));
+}
+
+#[test]
+fn stride_buf_cmp() {
+    let a = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]);
+    let b = StrideBuf::<_, 1>::from_vec(vec![1, 3]);
+    assert_eq!(a, b);
+    assert_eq!(a, vec![1, 3]);
+    assert!(a < StrideBuf::from_vec(vec![1, 0, 4]));
+}
+
+#[test]
+fn stride_buf_debug() {
+    let buf = StrideBuf::<_, 2>::from_vec(vec![1, 2, 3]);
+    assert_eq!(format!("{:?}", buf), "[1, 3]");
+}
+
+#[test]
+fn stride_boxed() {
+    let boxed: Box<Stride<_, 2>> = vec![1, 2, 3, 4, 5].into();
+    assert_eq!(*boxed, [1, 3, 5]);
+    assert_eq!(*boxed.clone(), [1, 3, 5]);
+    assert_eq!(Vec::from(boxed.clone()), vec![1, 2, 3, 4, 5]);
+    let buf = StrideBuf::from(boxed);
+    assert_eq!(buf, [1, 3, 5]);
+    let boxed = Box::<Stride<_, 2>>::from(buf);
+    assert_eq!(*Box::<[_]>::from(boxed), [1, 2, 3, 4, 5]);
+    assert!(Box::<Stride<i32, 3>>::default().is_empty());
+}
+
+#[test]
+fn stride_boxed_drop() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let data = vec![rc.clone(), rc.clone(), rc.clone()].into_boxed_slice();
+    let boxed = Stride::<_, 2>::from_boxed_slice(data);
+    assert_eq!(boxed.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    drop(boxed);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn stride_arc() {
+    use std::sync::Arc;
+
+    let data: Arc<[i32]> = Arc::from(vec![1, 2, 3, 4, 5]);
+    let stride = Stride::<_, 2>::from_arc_slice(data.clone());
+    assert_eq!(*stride, [1, 3, 5]);
+    assert_eq!(Arc::strong_count(&data), 2);
+    let underlying = Stride::into_arc_underlying(stride);
+    assert!(Arc::ptr_eq(&underlying, &data));
+    drop(underlying);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn stride_rc() {
+    use std::rc::Rc;
+
+    let data: Rc<[i32]> = Rc::from(vec![1, 2, 3, 4, 5]);
+    let stride = Stride::<_, 3>::from_rc_slice(data.clone());
+    assert_eq!(*stride, [1, 4]);
+    assert_eq!(Rc::strong_count(&data), 2);
+    let underlying = Stride::into_rc_underlying(stride);
+    assert!(Rc::ptr_eq(&underlying, &data));
+}
+
+#[test]
+fn stride_buf_from_iter() {
+    let buf: StrideBuf<_, 3> = (1..=3).collect();
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(buf.underlying(), &[1, 0, 0, 2, 0, 0, 3]);
+
+    let buf: StrideBuf<i32, 2> = std::iter::empty().collect();
+    assert!(buf.underlying().is_empty());
+
+    let buf = StrideBuf::<_, 2>::from_iter_with_filler("abc".chars(), ' ');
+    assert_eq!(buf.underlying(), &['a', ' ', 'b', ' ', 'c']);
+}
+
+#[test]
+fn stride_buf_extend() {
+    let mut buf = StrideBuf::<_, 3>::from_vec(vec![1, 2, 3, 4, 5]);
+    buf.extend(vec![6, 7]);
+    assert_eq!(buf, [1, 4, 6, 7]);
+    assert_eq!(buf.underlying(), &[1, 2, 3, 4, 5, 0, 6, 0, 0, 7]);
+
+    buf.extend(&[8]);
+    buf.push(9);
+    buf.push_with_filler(10, -1);
+    buf.extend_with_filler(Some(11), -2);
+    assert_eq!(buf, [1, 4, 6, 7, 8, 9, 10, 11]);
+    assert_eq!(
+        &buf.underlying()[10..],
+        &[0, 0, 8, 0, 0, 9, -1, -1, 10, -2, -2, 11]
+    );
+}
+
+#[test]
+fn stride_to_vec() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(Stride::<_, 1>::new(data).to_vec(), data.to_vec());
+    assert_eq!(Stride::<_, 3>::new(data).to_vec(), vec![1, 4, 7]);
+    assert_eq!(Stride::<_, 8>::new(data).to_vec(), vec![1]);
+    assert_eq!(Stride::<i32, 2>::new(&[]).to_vec(), Vec::<i32>::new());
+
+    let data = &["a", "b", "c"].map(String::from);
+    assert_eq!(Stride::<_, 2>::new(data).to_vec(), vec!["a", "c"]);
+    assert_eq!(Stride::<_, 2>::new(&[(); 5]).to_vec().len(), 3);
+}
+
+#[test]
+fn stride_to_vec_long() {
+    let data: Vec<u8> = (0..=255).collect();
+    let stride = Stride::<_, 2>::new(&data);
+    assert_eq!(stride.to_vec(), stride.iter().copied().collect::<Vec<_>>());
+    let stride = Stride::<_, 3>::new(&data[1..]);
+    assert_eq!(stride.to_vec(), stride.iter().copied().collect::<Vec<_>>());
+
+    let data: Vec<f64> = (0..100).map(f64::from).collect();
+    let stride = Stride::<_, 2>::new(&data);
+    assert_eq!(stride.to_vec(), stride.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn stride_concat() {
+    let a = Stride::<_, 3>::new(&[1, 2, 3, 4]);
+    let b = Stride::<_, 3>::new(&[5, 6, 7, 8, 9, 10, 11]);
+    assert_eq!(Stride::concat(&[a, b]), vec![1, 4, 5, 8, 11]);
+    assert_eq!(
+        Stride::concat(vec![b, Stride::new(&[]), a]),
+        vec![5, 8, 11, 1, 4]
+    );
+    assert_eq!(
+        Stride::<i32, 3>::concat(&[] as &[&Stride<_, 3>]),
+        Vec::<i32>::new()
+    );
+
+    let bufs: Vec<StrideBuf<_, 2>> = vec![vec![1, 2].into(), vec![3, 4, 5].into()];
+    assert_eq!(Stride::concat(&bufs), vec![1, 3, 5]);
+    let slices = [Stride::<_, 1>::new(&[1, 2]), Stride::new(&[3])];
+    assert_eq!(Stride::concat(slices.iter()), vec![1, 2, 3]);
+}
+
+#[test]
+fn stride_repeat() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3]);
+    assert_eq!(stride.repeat(0), Vec::<i32>::new());
+    assert_eq!(stride.repeat(1), vec![1, 3]);
+    assert_eq!(stride.repeat(5), vec![1, 3, 1, 3, 1, 3, 1, 3, 1, 3]);
+    assert_eq!(Stride::<i32, 2>::new(&[]).repeat(4), Vec::<i32>::new());
+    assert_eq!(Stride::<_, 3>::new(&[7]).repeat(3), vec![7, 7, 7]);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn stride_repeat_overflow() {
+    let _ = Stride::<_, 1>::new(&[1, 2]).repeat(usize::MAX);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::PodCastError;
+use stride::Stride;
+
+#[test]
+fn stride_try_cast() {
+    let data: &[u32] = &[0x0101_0101, 7, 0x0202_0202, 7, 0x0303_0303, 7];
+    let stride = Stride::<_, 2>::new(data);
+    assert_eq!(stride.try_cast::<u8, 8>().unwrap(), &[1, 2, 3]);
+    assert_eq!(
+        stride.try_cast::<[u8; 4], 2>().unwrap(),
+        &[[1; 4], [2; 4], [3; 4]]
+    );
+    assert_eq!(
+        stride.try_cast::<i32, 2>().unwrap(),
+        &[0x0101_0101, 0x0202_0202, 0x0303_0303]
+    );
+
+    let stride = Stride::<u32, 1>::new(&[]);
+    assert!(stride.try_cast::<u8, 4>().unwrap().is_empty());
+}
+
+#[test]
+fn stride_try_cast_size_mismatch() {
+    let stride = Stride::<_, 2>::new(&[1u32, 2, 3]);
+    assert_eq!(stride.try_cast::<u8, 4>(), Err(PodCastError::SizeMismatch));
+    assert_eq!(stride.try_cast::<u16, 2>(), Err(PodCastError::SizeMismatch));
+}
+
+#[test]
+fn stride_try_cast_zero_sized() {
+    let data = &mut [(); 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    assert_eq!(stride.try_cast::<[u8; 0], 2>().unwrap().len(), 3);
+    assert_eq!(
+        stride.try_cast::<[u8; 0], 1>(),
+        Err(PodCastError::SizeMismatch)
+    );
+    assert_eq!(
+        stride.try_cast::<[u8; 0], 3>(),
+        Err(PodCastError::SizeMismatch)
+    );
+    assert_eq!(
+        stride.try_cast_mut::<(), 5>().unwrap_err(),
+        PodCastError::SizeMismatch
+    );
+    assert_eq!(stride.try_cast::<u8, 2>(), Err(PodCastError::SizeMismatch));
+}
+
+#[test]
+fn stride_try_cast_slop() {
+    let data: &[u16] = &[1, 2, 3, 4, 5];
+    let stride = Stride::<_, 4>::new(data);
+    assert_eq!(stride.len(), 2);
+    assert_eq!(
+        stride.try_cast::<[u16; 2], 2>().map(|s| s.len()),
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(stride.try_cast::<u8, 8>().map(|s| s.len()), Ok(2));
+}
+
+#[test]
+fn stride_try_cast_unaligned() {
+    let data: &[u8] = &[0; 20];
+    // Start one byte after an aligned address.
+    let offset = (4 - data.as_ptr() as usize % 4) % 4 + 1;
+    let stride = Stride::<_, 4>::new(&data[offset..offset + 16]);
+    assert_eq!(
+        stride.try_cast::<u32, 1>().map(|s| s.len()),
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    );
+}
+
+#[test]
+fn stride_cast_mut() {
+    let data = &mut [0u32; 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.cast_mut::<[u8; 4], 2>().fill([0xff; 4]);
+    let halves = stride.try_cast_mut::<u16, 4>().unwrap();
+    halves.fill(0);
+    assert_eq!(halves.len(), 3);
+    assert_eq!(stride.cast::<[u16; 2], 2>(), &[[0, 0xffff]; 3]);
+    assert_eq!(data[1], 0);
+}
+
+#[test]
+#[should_panic(expected = "failed to cast strided slice: SizeMismatch")]
+fn stride_cast_fail() {
+    let stride = Stride::<_, 2>::new(&[1u32, 2, 3]);
+    stride.cast::<u8, 3>();
+}
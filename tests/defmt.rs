@@ -0,0 +1,57 @@
+#![cfg(feature = "defmt")]
+
+use std::cell::RefCell;
+
+use stride::Stride;
+
+fn assert_format<T: defmt::Format + ?Sized>(_: &T) {}
+
+#[test]
+fn stride_format() {
+    assert_format(Stride::<_, 2>::new(&[1u8, 2, 3]));
+    assert_format(Stride::<_, 1>::new(&[1u8, 2, 3]));
+    assert_format(&Stride::<_, 3>::new(&[(1u8, true)]));
+}
+
+thread_local! {
+    static ENCODED: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Collects the encoded frames of the current thread so that they can be
+/// compared, since decoding them needs the interned strings from the binary.
+#[defmt::global_logger]
+struct Logger;
+
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        ENCODED.with(|encoded| encoded.borrow_mut().extend_from_slice(bytes));
+    }
+}
+
+defmt::timestamp!("");
+
+fn encode<T: defmt::Format + ?Sized>(value: &T) -> Vec<u8> {
+    ENCODED.with(|encoded| encoded.borrow_mut().clear());
+    defmt::println!("{}", value);
+    ENCODED.with(|encoded| encoded.take())
+}
+
+#[test]
+fn stride_format_encoding() {
+    let a = encode(Stride::<_, 2>::new(&[1u8, 0, 2, 0, 3]));
+    assert_eq!(a, encode(Stride::<_, 2>::new(&[1u8, 9, 2, 9, 3])));
+    assert_ne!(a, encode(Stride::<_, 2>::new(&[1u8, 0, 2, 0, 4])));
+    assert_ne!(a, encode(Stride::<_, 2>::new(&[1u8, 0, 2])));
+
+    // Each element after the first adds the same separator.
+    let one = encode(Stride::<_, 2>::new(&[1u8])).len();
+    let two = encode(Stride::<_, 2>::new(&[1u8, 0, 2])).len();
+    let three = a.len();
+    assert_eq!(three - two, two - one);
+}
@@ -0,0 +1,184 @@
+use std::num::NonZeroUsize;
+
+use stride::{DynStride, DynStrideMut, Stride};
+
+fn nz(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+#[test]
+fn dyn_stride_len() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(DynStride::new(data, nz(1)).len(), 7);
+    assert_eq!(DynStride::new(data, nz(3)).len(), 3);
+    assert_eq!(DynStride::new(data, nz(7)).len(), 1);
+    assert_eq!(DynStride::new(data, nz(8)).len(), 1);
+    assert!(DynStride::<i32>::new(&[], nz(2)).is_empty());
+    assert!(DynStride::<i32>::default().is_empty());
+}
+
+#[test]
+fn dyn_stride_get() {
+    let stride = DynStride::new(&[1, 2, 3, 4, 5], nz(2));
+    assert_eq!(stride[0], 1);
+    assert_eq!(stride.get(2), Some(&5));
+    assert_eq!(stride.get(3), None);
+    assert_eq!(stride.get(usize::MAX), None);
+    assert_eq!(stride.first(), Some(&1));
+    assert_eq!(stride.last(), Some(&5));
+    assert_eq!(stride.raw_index(1), Some(2));
+    assert_eq!(stride.raw_index(3), None);
+    assert_eq!(DynStride::<i32>::default().last(), None);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of range for stride of length 3 (S = 2)")]
+fn dyn_stride_index_out_of_bounds() {
+    let stride = DynStride::new(&[1, 2, 3, 4, 5], nz(2));
+    let _ = stride[3];
+}
+
+#[test]
+fn dyn_stride_iter() {
+    let stride = DynStride::new(&[1, 2, 3, 4, 5, 6, 7], nz(3));
+    assert!(stride.iter().eq(&[1, 4, 7]));
+    assert!(stride.iter().rev().eq(&[7, 4, 1]));
+    assert_eq!(stride.iter().len(), 3);
+    assert_eq!(stride.into_iter().copied().sum::<i32>(), 12);
+}
+
+#[test]
+fn dyn_stride_iter_matches_stride() {
+    let data = &[1, 2, 3, 4, 5, 6, 7, 8];
+    let stride = Stride::<_, 3>::new(data);
+    let mut iter = stride.as_dyn().iter();
+    let mut expected = stride.iter();
+    assert_eq!(iter.nth_back(1), expected.nth_back(1));
+    assert_eq!(iter.as_dyn_stride(), expected.as_stride());
+    assert!(iter.clone().eq(expected.clone()));
+    assert_eq!(format!("{:?}", iter), "DynIter([1])");
+    assert_eq!(iter.nth(1), None);
+    assert_eq!(iter.as_dyn_stride().stride(), 3);
+    assert!(iter.as_dyn_stride().is_empty());
+}
+
+#[test]
+fn dyn_stride_iter_mut_into_dyn_stride() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let mut stride = DynStrideMut::new(data, nz(3));
+    let mut iter = stride.iter_mut();
+    *iter.next_back().unwrap() = 0;
+    assert_eq!(format!("{:?}", iter), "DynIterMut([1, 4])");
+    let mut rest = iter.into_dyn_stride();
+    rest[1] = 8;
+    assert_eq!(data, &[1, 2, 3, 8, 5, 6, 0]);
+}
+
+#[test]
+fn dyn_stride_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let mut stride = DynStrideMut::new(data, nz(2));
+    stride[0] = 7;
+    *stride.last_mut().unwrap() = 8;
+    for x in stride.iter_mut() {
+        *x += 1;
+    }
+    assert_eq!(stride, [8, 4, 9]);
+    assert_eq!(stride.first(), Some(&8));
+    for x in &mut stride {
+        *x *= 10;
+    }
+    assert_eq!(data, &[80, 2, 40, 4, 90, 6]);
+}
+
+#[test]
+fn dyn_stride_conversions() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new(data);
+    let dyn_stride = stride.as_dyn();
+    assert_eq!(dyn_stride.stride(), 2);
+    assert_eq!(dyn_stride, *stride);
+    assert_eq!(*stride, dyn_stride);
+    assert_eq!(dyn_stride.to_stride::<2>(), Some(stride));
+    assert_eq!(dyn_stride.to_stride::<3>(), None);
+    assert_eq!(DynStride::from(stride), dyn_stride);
+
+    let mut dyn_stride = DynStrideMut::from(Stride::<_, 3>::new_mut(data));
+    assert!(dyn_stride.as_stride_mut::<2>().is_none());
+    dyn_stride.as_stride_mut::<3>().unwrap().reverse();
+    let dyn_stride = dyn_stride.into_stride::<2>().unwrap_err();
+    let stride = dyn_stride.into_stride::<3>().unwrap();
+    assert_eq!(stride, &[4, 1]);
+    assert_eq!(data, &[4, 2, 3, 1, 5, 6]);
+}
+
+#[test]
+fn dyn_stride_partial_eq() {
+    let a = DynStride::new(&[1, 0, 3, 0, 5], nz(2));
+    let b = DynStride::new(&[1, 0, 0, 3, 0, 0, 5], nz(3));
+    assert_eq!(a, b);
+    assert_eq!(a, [1, 3, 5]);
+    assert_eq!(a, &[1, 3, 5][..]);
+    assert_ne!(a, [1, 3]);
+    assert_ne!(a, DynStride::new(&[1, 3, 6], nz(1)));
+    assert_eq!(a, Stride::<_, 1>::new(&[1, 3, 5]));
+}
+
+#[test]
+fn dyn_stride_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let data = &[1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new(data);
+    assert_eq!(hash_of(&stride.as_dyn()), hash_of(stride));
+}
+
+#[test]
+fn dyn_stride_hash_unit_stride() {
+    use std::hash::{Hash, Hasher};
+
+    /// Records each write separately, unlike streaming hashers which only
+    /// see the bytes.
+    #[derive(Default)]
+    struct WriteHasher(Vec<Vec<u8>>);
+
+    impl Hasher for WriteHasher {
+        fn finish(&self) -> u64 {
+            unimplemented!()
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.push(bytes.to_vec());
+        }
+    }
+
+    fn writes_of<T: Hash + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+        let mut hasher = WriteHasher::default();
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    let data = &mut [1u32, 2, 3, 4, 5];
+    let stride = Stride::<_, 1>::new(data);
+    assert_eq!(writes_of(&stride.as_dyn()), writes_of(stride));
+    let other = Stride::<_, 2>::new(&[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+    assert_eq!(writes_of(&other.as_dyn()), writes_of(stride));
+    let expected = writes_of(Stride::<_, 1>::new(data));
+    assert_eq!(
+        writes_of(&Stride::<_, 1>::new_mut(data).as_dyn_mut()),
+        expected
+    );
+}
+
+#[test]
+fn dyn_stride_debug() {
+    let stride = DynStride::new(&[1, 2, 3, 4, 5], nz(2));
+    assert_eq!(format!("{:?}", stride), "[1, 3, 5]");
+}
@@ -1,4 +1,11 @@
-use stride::Stride;
+use stride::{GetDisjointMutError, Stride, StrideIndex};
+
+fn get_twice<I>(stride: &Stride<i32, 2>, index: I) -> (Option<&I::Output>, &I::Output)
+where
+    I: StrideIndex<Stride<i32, 2>> + Clone,
+{
+    (stride.get(index.clone()), &stride[index])
+}
 
 #[test]
 fn stride_get_index() {
@@ -62,3 +69,243 @@ fn stride_get_range_to_inclusive() {
     assert_eq!(stride.get(..=2), Some(stride));
     assert_eq!(stride.get(..=3), None);
 }
+
+#[test]
+fn stride_get_mut_index() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    *stride.get_mut(1).unwrap() = 7;
+    assert_eq!(stride.get_mut(3), None);
+    assert_eq!(data, &[1, 2, 7, 4, 5, 6]);
+}
+
+#[test]
+fn stride_get_mut_range() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for elem in stride.get_mut(1..3).unwrap() {
+        *elem = 0;
+    }
+    assert_eq!(stride.get_mut(0..4), None);
+    assert_eq!(data, &[1, 2, 0, 4, 0, 6]);
+}
+
+#[test]
+fn stride_get_unchecked() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    unsafe {
+        assert_eq!(stride.get_unchecked(0), &1);
+        assert_eq!(stride.get_unchecked(2), &5);
+        assert_eq!(stride.get_unchecked(1..), Stride::<_, 2>::new(&[3, 4, 5]));
+    }
+}
+
+#[test]
+fn stride_get_unchecked_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    unsafe {
+        *stride.get_unchecked_mut(2) = 7;
+        stride.get_unchecked_mut(..2).swap(0, 1);
+    }
+    assert_eq!(data, &[3, 2, 1, 4, 7, 6]);
+}
+
+#[test]
+fn stride_get_range_non_multiple() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(stride.get(1..3), Some(Stride::<_, 2>::new(&[3, 4, 5])));
+    assert_eq!(stride.get(3..3), Some(Stride::<_, 2>::new(&[])));
+    assert_eq!(stride.get(3..), Some(Stride::<_, 2>::new(&[])));
+    assert_eq!(stride.get(..3), Some(stride));
+    assert_eq!(stride.get(1..=2), Some(Stride::<_, 2>::new(&[3, 4, 5])));
+    assert_eq!(stride.get(..=2), Some(stride));
+    assert_eq!(stride.get(1..4), None);
+    assert_eq!(stride.get(4..), None);
+    assert_eq!(stride.get(..4), None);
+    assert_eq!(stride.get(..=3), None);
+}
+
+#[test]
+fn stride_index_range() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(stride[1..3], [3, 5]);
+    assert_eq!(stride[1..], [3, 5]);
+    assert_eq!(stride[..2], [1, 3]);
+    assert_eq!(stride[..], [1, 3, 5]);
+    assert_eq!(stride[1..=1], [3]);
+    assert_eq!(stride[..=1], [1, 3]);
+}
+
+#[test]
+fn stride_index_mut_range() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for elem in &mut stride[1..] {
+        *elem *= 2;
+    }
+    assert_eq!(data, &[1, 2, 6, 4, 10]);
+}
+
+#[test]
+#[should_panic]
+fn stride_index_range_out_of_bounds() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let _ = &stride[1..4];
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of range for stride of length 3 (S = 2)")]
+fn stride_index_out_of_bounds_message() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let _ = stride[3];
+}
+
+#[test]
+#[should_panic(expected = "index 2 out of range for stride of length 2 (S = 3)")]
+fn stride_index_mut_out_of_bounds_message() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 3>::new_mut(data);
+    stride[2] = 0;
+}
+
+#[test]
+#[should_panic(expected = "range end index 4 out of range for stride of length 3 (S = 2)")]
+fn stride_index_range_out_of_bounds_message() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let _ = &stride[1..4];
+}
+
+#[test]
+#[should_panic(expected = "stride index starts at 4 but ends at 3 (S = 2)")]
+fn stride_index_range_from_out_of_bounds_message() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let _ = &mut stride[4..];
+}
+
+#[test]
+#[should_panic(expected = "range end index 4 out of range for stride of length 3 (S = 2)")]
+fn stride_index_range_inclusive_out_of_bounds_message() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let _ = &stride[..=3];
+}
+
+/// Returns the file that `f` panics in.
+fn panic_file(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    use std::cell::RefCell;
+    use std::panic;
+
+    thread_local! {
+        static FILE: RefCell<String> = const { RefCell::new(String::new()) };
+    }
+    panic::set_hook(Box::new(|info| {
+        let file = info.location().unwrap().file().to_owned();
+        FILE.with(|f| *f.borrow_mut() = file);
+    }));
+    let result = panic::catch_unwind(f);
+    let _ = panic::take_hook();
+    assert!(result.is_err());
+    FILE.with(|f| f.borrow().clone())
+}
+
+#[test]
+fn stride_index_panic_location() {
+    let file = panic_file(|| {
+        let _ = Stride::<_, 2>::new(&[1, 2, 3])[2];
+    });
+    assert_eq!(file, file!());
+    let file = panic_file(|| {
+        Stride::<_, 2>::new_mut(&mut [1, 2, 3])[2] = 0;
+    });
+    assert_eq!(file, file!());
+    let file = panic_file(|| {
+        let _ = Stride::<_, 2>::new(&[1, 2, 3]).reversed()[2];
+    });
+    assert_eq!(file, file!());
+    let file = panic_file(|| {
+        Stride::<_, 2>::new_mut(&mut [1, 2, 3]).reversed_mut()[2] = 0;
+    });
+    assert_eq!(file, file!());
+}
+
+#[test]
+fn stride_index_generic() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(get_twice(stride, 1), (Some(&3), &3));
+    let (got, indexed) = get_twice(stride, 1..);
+    assert_eq!(got, Some(indexed));
+    assert_eq!(indexed, &[3, 5]);
+}
+
+#[test]
+fn stride_get_disjoint_mut() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let [a, b, c] = stride.get_disjoint_mut([2, 0, 1]).unwrap();
+    *a += 10;
+    *b += 20;
+    *c += 30;
+    assert_eq!(data, &[21, 2, 33, 4, 15]);
+
+    let stride = Stride::<_, 2>::new_mut(data);
+    assert_eq!(stride.get_disjoint_mut([]), Ok([]));
+    assert_eq!(
+        stride.get_disjoint_mut([0, 3]),
+        Err(GetDisjointMutError::IndexOutOfBounds)
+    );
+    assert_eq!(
+        stride.get_disjoint_mut([2, 1, 2]),
+        Err(GetDisjointMutError::OverlappingIndices)
+    );
+}
+
+#[test]
+fn stride_get_overflow() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let index = usize::MAX / 2 + 1;
+    assert_eq!(stride.get(index), None);
+    assert_eq!(stride.get_mut(index), None);
+    assert_eq!(stride.get(index..), None);
+    assert_eq!(stride.get(..index), None);
+    assert_eq!(stride.get(..=index), None);
+    assert_eq!(stride.get(1..index), None);
+    assert_eq!(stride.get(1..=index), None);
+}
+
+#[test]
+#[should_panic(expected = "index 9223372036854775808 out of range for stride of length 3 (S = 2)")]
+#[cfg(target_pointer_width = "64")]
+fn stride_index_overflow() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let _ = stride[usize::MAX / 2 + 1];
+}
+
+#[test]
+fn stride_get_huge_len() {
+    // The logical length times the stride overflows, but ranges up to the
+    // logical length are still valid.
+    let data: &[()] =
+        unsafe { std::slice::from_raw_parts(std::ptr::NonNull::dangling().as_ptr(), usize::MAX) };
+    let stride = Stride::<_, 2>::new(data);
+    let len = stride.len();
+    assert_eq!(len, usize::MAX / 2 + 1);
+    assert_eq!(stride.get(len - 1), Some(&()));
+    assert_eq!(stride.get(len), None);
+    assert_eq!(stride.get(..len).map(Stride::len), Some(len));
+    assert_eq!(stride.get(len..).map(Stride::len), Some(0));
+    assert_eq!(stride.get(..len + 1), None);
+    assert_eq!(stride[1..].len(), len - 1);
+    let (left, right) = stride.split_at(len);
+    assert_eq!((left.len(), right.len()), (len, 0));
+}
+
+#[test]
+fn stride_raw_index() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(stride.raw_index(0), Some(0));
+    assert_eq!(stride.raw_index(2), Some(6));
+    assert_eq!(stride.raw_index(3), None);
+    assert_eq!(stride.raw_index(usize::MAX), None);
+}
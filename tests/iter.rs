@@ -17,6 +17,38 @@ fn stride_iter_mut() {
     assert_eq!(slice, &[2, 2, 6, 4, 10, 6]);
 }
 
+#[test]
+fn stride_into_iter() {
+    fn sum<'a, C>(c: &'a C) -> i32
+    where
+        &'a C: IntoIterator<Item = &'a i32>,
+        C: ?Sized,
+    {
+        c.into_iter().sum()
+    }
+
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(sum(stride), 9);
+    let mut vec = Vec::new();
+    for x in stride {
+        vec.push(*x);
+    }
+    assert_eq!(vec, [1, 3, 5]);
+}
+
+#[test]
+fn stride_into_iter_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for x in &mut *stride {
+        *x += 1;
+    }
+    for x in stride {
+        *x *= 2;
+    }
+    assert_eq!(data, &[4, 2, 8, 4, 12, 6]);
+}
+
 #[test]
 fn stride_iter_rev() {
     let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
@@ -24,6 +56,52 @@ fn stride_iter_rev() {
     assert_eq!(vec, [&5, &3, &1]);
 }
 
+#[test]
+fn stride_iter_rev_uneven() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let vec: Vec<_> = stride.iter().rev().collect();
+    assert_eq!(vec, [&5, &3, &1]);
+
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let vec: Vec<_> = stride.iter().rev().collect();
+    assert_eq!(vec, [&7, &4, &1]);
+
+    let stride = Stride::<_, 4>::new(&[1, 0, 0, 0, 2, 0, 0]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next_back(), Some(&1));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_iter_mut_rev() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for (i, elem) in stride.iter_mut().rev().enumerate() {
+        *elem = i;
+    }
+    assert_eq!(data, &[2, 2, 1, 4, 0]);
+}
+
+#[test]
+fn stride_iter_mixed_ends() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0, 0, 4]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn stride_iter_rposition() {
+    let stride = Stride::<_, 2>::new(&[0, 1, 3, 1, 2, 1, 0, 1, 0]);
+    assert_eq!(stride.iter().rposition(|&x| x != 0), Some(2));
+}
+
 #[test]
 fn stride_iter_skip_rev() {
     let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
@@ -36,3 +114,680 @@ fn stride_iter_len() {
     let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
     assert_eq!(stride.iter().len(), 3);
 }
+
+#[test]
+fn stride_iter_len_partial() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    iter.next_back();
+    assert_eq!(iter.len(), 1);
+    iter.next();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let zipped = stride.iter().zip(0..10);
+    assert_eq!(zipped.size_hint(), (3, Some(3)));
+    assert_eq!(stride.iter().enumerate().len(), 3);
+}
+
+#[test]
+fn stride_iter_mut_len() {
+    let data = &mut [1, 0, 2, 0, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.iter_mut();
+    assert_eq!(iter.len(), 3);
+    iter.next_back();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn stride_iter_fused() {
+    fn assert_fused<I: std::iter::FusedIterator>(_: &I) {}
+
+    let data = &mut [1, 0, 2];
+    let stride = Stride::<_, 2>::new(&data[..]);
+    let mut iter = stride.iter();
+    assert_fused(&iter);
+    assert_eq!(iter.by_ref().count(), 2);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.iter_mut();
+    assert_fused(&iter);
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn stride_iter_nth() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0, 0, 4, 0]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.nth(1), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.nth(1), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = stride.iter();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_iter_nth_back() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0, 0, 4, 0]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.nth_back(0), Some(&4));
+    assert_eq!(iter.nth_back(1), Some(&2));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.nth_back(0), None);
+
+    let data = &mut [1, 0, 2, 0, 3, 0, 4];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.iter_mut();
+    *iter.nth_back(2).unwrap() = 5;
+    *iter.next().unwrap() = 6;
+    assert_eq!(iter.nth_back(0), None);
+    assert_eq!(data, &[6, 0, 5, 0, 3, 0, 4]);
+}
+
+#[test]
+fn stride_iter_last_count() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0]);
+    assert_eq!(stride.iter().last(), Some(&3));
+    assert_eq!(stride.iter().count(), 3);
+    assert_eq!(stride.iter().skip(1).count(), 2);
+    assert_eq!(<&Stride<i32, 3>>::default().iter().last(), None);
+    assert_eq!(<&Stride<i32, 3>>::default().iter().count(), 0);
+}
+
+#[test]
+fn stride_iter_fold() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0]);
+    assert_eq!(stride.iter().sum::<i32>(), 6);
+    assert_eq!(stride.iter().fold(0, |acc, x| acc * 10 + x), 123);
+    assert_eq!(stride.iter().rfold(0, |acc, x| acc * 10 + x), 321);
+
+    let mut iter = stride.iter();
+    iter.next();
+    assert_eq!(iter.fold(0, |acc, x| acc * 10 + x), 23);
+    let mut iter = stride.iter();
+    iter.next_back();
+    assert_eq!(iter.rfold(0, |acc, x| acc * 10 + x), 21);
+
+    let mut vec = Vec::new();
+    stride.iter().for_each(|&x| vec.push(x));
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn stride_iter_mut_fold() {
+    let data = &mut [1, 0, 2, 0, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.iter_mut().for_each(|x| *x *= 2);
+    stride.iter_mut().rfold(0, |acc, x| {
+        *x += acc;
+        *x
+    });
+    assert_eq!(data, &[12, 0, 10, 0, 6]);
+}
+
+#[test]
+fn stride_iter_short_circuit() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 3, 0, 4, 0, 5]);
+
+    let mut iter = stride.iter();
+    assert!(!iter.all(|&x| x < 3));
+    assert_eq!(iter.next(), Some(&4));
+    assert!(iter.all(|&x| x == 5));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = stride.iter();
+    assert!(iter.any(|&x| x == 2));
+    assert_eq!(iter.next(), Some(&3));
+    assert!(!iter.any(|&x| x == 0));
+
+    let mut iter = stride.iter();
+    assert_eq!(iter.find(|&&x| x > 1), Some(&2));
+    assert_eq!(iter.position(|&x| x == 4), Some(1));
+    assert_eq!(iter.next(), Some(&5));
+
+    let mut iter = stride.iter();
+    assert_eq!(iter.rposition(|&x| x == 2), Some(1));
+    assert_eq!(iter.next_back(), Some(&1));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_chunks() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let chunks: Vec<_> = stride.chunks(2).collect();
+    assert_eq!(chunks, [&[1, 3][..], &[5, 7], &[9]]);
+    let chunks: Vec<_> = stride.chunks(5).collect();
+    assert_eq!(chunks, [&[1, 3, 5, 7, 9][..]]);
+    let chunks: Vec<_> = stride.chunks(6).collect();
+    assert_eq!(chunks, [&[1, 3, 5, 7, 9][..]]);
+    assert_eq!(<&Stride<i32, 2>>::default().chunks(2).next(), None);
+}
+
+#[test]
+fn stride_chunks_rev() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let chunks: Vec<_> = stride.chunks(2).rev().collect();
+    assert_eq!(chunks, [&[9][..], &[5, 7], &[1, 3]]);
+
+    let mut iter = stride.chunks(2);
+    assert_eq!(iter.next_back().unwrap(), &[9]);
+    assert_eq!(iter.next().unwrap(), &[1, 3]);
+    assert_eq!(iter.next_back().unwrap(), &[5, 7]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_chunks_len() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(stride.chunks(1).len(), 5);
+    assert_eq!(stride.chunks(2).len(), 3);
+    assert_eq!(stride.chunks(5).len(), 1);
+    assert_eq!(stride.chunks(2).skip(1).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn stride_chunks_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3]);
+    stride.chunks(0);
+}
+
+#[test]
+fn stride_chunks_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut chunks: Vec<_> = stride.chunks_mut(2).collect();
+    assert_eq!(chunks.len(), 3);
+    chunks[0].reverse();
+    chunks[1][1] = 0;
+    chunks[2][0] = 10;
+    assert_eq!(data, &[3, 2, 1, 4, 5, 6, 0, 8, 10]);
+}
+
+#[test]
+fn stride_chunks_mut_rev() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.chunks_mut(2);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().unwrap(), &[9]);
+    assert_eq!(iter.next().unwrap(), &[1, 3]);
+    assert_eq!(iter.next_back().unwrap(), &[5, 7]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn stride_chunks_exact() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let iter = stride.chunks_exact(2);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.remainder(), &[9]);
+    let chunks: Vec<_> = iter.collect();
+    assert_eq!(chunks, [&[1, 3][..], &[5, 7]]);
+
+    let chunks: Vec<_> = stride.chunks_exact(2).rev().collect();
+    assert_eq!(chunks, [&[5, 7][..], &[1, 3]]);
+
+    let iter = stride.chunks_exact(5);
+    assert!(iter.remainder().is_empty());
+    assert_eq!(iter.count(), 1);
+
+    let mut iter = stride.chunks_exact(6);
+    assert_eq!(iter.remainder(), &[1, 3, 5, 7, 9]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_chunks_exact_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.chunks_exact_mut(2);
+    assert_eq!(iter.len(), 2);
+    iter.next_back().unwrap().reverse();
+    iter.next().unwrap()[1] = 0;
+    assert_eq!(iter.next(), None);
+    iter.into_remainder()[0] = 10;
+    assert_eq!(data, &[1, 2, 0, 4, 7, 6, 5, 8, 10]);
+}
+
+#[test]
+fn stride_rchunks() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let chunks: Vec<_> = stride.rchunks(2).collect();
+    assert_eq!(chunks, [&[7, 9][..], &[3, 5], &[1]]);
+    let chunks: Vec<_> = stride.rchunks(2).rev().collect();
+    assert_eq!(chunks, [&[1][..], &[3, 5], &[7, 9]]);
+    assert_eq!(stride.rchunks(2).len(), 3);
+    assert_eq!(stride.rchunks(5).len(), 1);
+    assert_eq!(<&Stride<i32, 2>>::default().rchunks(2).next(), None);
+}
+
+#[test]
+fn stride_rchunks_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.rchunks_mut(2);
+    assert_eq!(iter.len(), 3);
+    iter.next().unwrap().reverse();
+    iter.next_back().unwrap()[0] = 0;
+    iter.next().unwrap()[1] = 10;
+    assert_eq!(iter.next(), None);
+    assert_eq!(data, &[0, 2, 3, 4, 10, 6, 9, 8, 7]);
+}
+
+#[test]
+fn stride_windows() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let windows: Vec<_> = stride.windows(3).collect();
+    assert_eq!(windows, [&[1, 3, 5][..], &[3, 5, 7]]);
+    let windows: Vec<_> = stride.windows(3).rev().collect();
+    assert_eq!(windows, [&[3, 5, 7][..], &[1, 3, 5]]);
+    assert_eq!(stride.windows(1).len(), 4);
+    assert_eq!(stride.windows(4).len(), 1);
+    assert_eq!(stride.windows(5).len(), 0);
+    assert_eq!(stride.windows(5).next(), None);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn stride_windows_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3]);
+    stride.windows(0);
+}
+
+#[test]
+fn stride_split() {
+    let stride = Stride::<_, 2>::new(&[0, 9, 1, 9, 0, 9, 2, 9, 3, 9, 0]);
+    let splits: Vec<_> = stride.split(|&x| x == 0).collect();
+    assert_eq!(splits, [&[][..], &[1], &[2, 3], &[]]);
+    let splits: Vec<_> = stride.split(|&x| x == 0).rev().collect();
+    assert_eq!(splits, [&[][..], &[2, 3], &[1], &[]]);
+    let splits: Vec<_> = stride.split(|&x| x == 9).collect();
+    assert_eq!(splits, [&[0, 1, 0, 2, 3, 0][..]]);
+
+    let splits: Vec<_> = <&Stride<i32, 2>>::default().split(|_| true).collect();
+    assert_eq!(splits, [&[0; 0][..]]);
+}
+
+#[test]
+fn stride_split_mixed() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 0, 9, 2, 9, 0, 9, 3]);
+    let mut iter = stride.split(|&x| x == 0);
+    assert_eq!(iter.next().unwrap(), &[1]);
+    assert_eq!(iter.next_back().unwrap(), &[3]);
+    assert_eq!(iter.next().unwrap(), &[2]);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_split_mut() {
+    let data = &mut [1, 9, 0, 9, 2, 9, 3, 9, 0];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut splits: Vec<_> = stride.split_mut(|&x| x == 0).collect();
+    assert_eq!(splits.len(), 3);
+    splits[0][0] = 4;
+    splits[1].reverse();
+    assert!(splits[2].is_empty());
+    assert_eq!(data, &[4, 9, 0, 9, 3, 9, 2, 9, 0]);
+
+    let data = &mut [1, 9, 0, 9, 2, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let splits: Vec<_> = stride.split_mut(|&x| x == 0).rev().collect();
+    assert_eq!(splits, [&[2, 3][..], &[1]]);
+}
+
+#[test]
+fn stride_splitn() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 0, 9, 2, 9, 0, 9, 3]);
+    let splits: Vec<_> = stride.splitn(0, |&x| x == 0).collect();
+    assert!(splits.is_empty());
+    let splits: Vec<_> = stride.splitn(1, |&x| x == 0).collect();
+    assert_eq!(splits, [&[1, 0, 2, 0, 3][..]]);
+    let splits: Vec<_> = stride.splitn(2, |&x| x == 0).collect();
+    assert_eq!(splits, [&[1][..], &[2, 0, 3]]);
+    let splits: Vec<_> = stride.splitn(5, |&x| x == 0).collect();
+    assert_eq!(splits, [&[1][..], &[2], &[3]]);
+}
+
+#[test]
+fn stride_splitn_mut() {
+    let data = &mut [1, 9, 0, 9, 2, 9, 0, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut splits: Vec<_> = stride.splitn_mut(2, |&x| x == 0).collect();
+    assert_eq!(splits.len(), 2);
+    splits[1].reverse();
+    assert_eq!(data, &[1, 9, 0, 9, 3, 9, 0, 9, 2]);
+}
+
+#[test]
+fn stride_rsplit() {
+    let stride = Stride::<_, 2>::new(&[0, 9, 1, 9, 0, 9, 2, 9, 3]);
+    let splits: Vec<_> = stride.rsplit(|&x| x == 0).collect();
+    assert_eq!(splits, [&[2, 3][..], &[1], &[]]);
+    let splits: Vec<_> = stride.rsplit(|&x| x == 0).rev().collect();
+    assert_eq!(splits, [&[][..], &[1], &[2, 3]]);
+}
+
+#[test]
+fn stride_rsplit_mut() {
+    let data = &mut [0, 9, 1, 9, 0, 9, 2, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.rsplit_mut(|&x| x == 0);
+    iter.next().unwrap().reverse();
+    assert_eq!(iter.next().unwrap(), &[1]);
+    assert!(iter.next().unwrap().is_empty());
+    assert_eq!(iter.next(), None);
+    assert_eq!(data, &[0, 9, 1, 9, 0, 9, 3, 9, 2]);
+}
+
+#[test]
+fn stride_rsplitn() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 0, 9, 2, 9, 0, 9, 3]);
+    let splits: Vec<_> = stride.rsplitn(1, |&x| x == 0).collect();
+    assert_eq!(splits, [&[1, 0, 2, 0, 3][..]]);
+    let splits: Vec<_> = stride.rsplitn(2, |&x| x == 0).collect();
+    assert_eq!(splits, [&[3][..], &[1, 0, 2]]);
+    let splits: Vec<_> = stride.rsplitn(5, |&x| x == 0).collect();
+    assert_eq!(splits, [&[3][..], &[2], &[1]]);
+}
+
+#[test]
+fn stride_rsplitn_mut() {
+    let data = &mut [1, 9, 0, 9, 2, 9, 0, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut splits: Vec<_> = stride.rsplitn_mut(2, |&x| x == 0).collect();
+    assert_eq!(splits.len(), 2);
+    splits[1].reverse();
+    assert_eq!(data, &[2, 9, 0, 9, 1, 9, 0, 9, 3]);
+}
+
+#[test]
+fn stride_split_inclusive() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 0, 9, 2, 9, 0, 9, 3]);
+    let splits: Vec<_> = stride.split_inclusive(|&x| x == 0).collect();
+    assert_eq!(splits, [&[1, 0][..], &[2, 0], &[3]]);
+    let splits: Vec<_> = stride.split_inclusive(|&x| x == 0).rev().collect();
+    assert_eq!(splits, [&[3][..], &[2, 0], &[1, 0]]);
+
+    let stride = Stride::<_, 2>::new(&[0, 9, 1, 9, 0]);
+    let splits: Vec<_> = stride.split_inclusive(|&x| x == 0).collect();
+    assert_eq!(splits, [&[0][..], &[1, 0]]);
+    let splits: Vec<_> = stride.split_inclusive(|&x| x == 0).rev().collect();
+    assert_eq!(splits, [&[1, 0][..], &[0]]);
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.split_inclusive(|&x| x == 0).next(), None);
+    assert_eq!(stride.split_inclusive(|&x| x == 0).next_back(), None);
+}
+
+#[test]
+fn stride_split_inclusive_mut() {
+    let data = &mut [1, 9, 0, 9, 2, 9, 0, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.split_inclusive_mut(|&x| x == 0);
+    iter.next_back().unwrap()[0] = 4;
+    iter.next().unwrap().reverse();
+    assert_eq!(iter.next().unwrap(), &[2, 0]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(data, &[0, 9, 1, 9, 2, 9, 0, 9, 4]);
+}
+
+#[test]
+fn stride_chunk_by() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 1, 9, 2, 9, 3, 9, 3]);
+    let chunks: Vec<_> = stride.chunk_by(|a, b| a == b).collect();
+    assert_eq!(chunks, [&[1, 1][..], &[2], &[3, 3]]);
+    let chunks: Vec<_> = stride.chunk_by(|a, b| a == b).rev().collect();
+    assert_eq!(chunks, [&[3, 3][..], &[2], &[1, 1]]);
+
+    let mut iter = stride.chunk_by(|a, b| a == b);
+    assert_eq!(iter.next().unwrap(), &[1, 1]);
+    assert_eq!(iter.next_back().unwrap(), &[3, 3]);
+    assert_eq!(iter.next().unwrap(), &[2]);
+    assert_eq!(iter.next_back(), None);
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.chunk_by(|a, b| a == b).next(), None);
+}
+
+#[test]
+fn stride_chunk_by_mut() {
+    let data = &mut [1, 9, 2, 9, 2, 9, 1, 9, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.chunk_by_mut(|a, b| a <= b);
+    iter.next_back().unwrap().reverse();
+    iter.next().unwrap().fill(0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(data, &[0, 9, 0, 9, 0, 9, 3, 9, 1]);
+}
+
+#[test]
+fn stride_array_chunks() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0, 0, 4, 0, 0, 5]);
+    let chunks: Vec<[&i32; 2]> = stride.array_chunks().collect();
+    assert_eq!(chunks, [[&1, &2], [&3, &4]]);
+    let chunks: Vec<[&i32; 2]> = stride.array_chunks().rev().collect();
+    assert_eq!(chunks, [[&3, &4], [&1, &2]]);
+
+    let mut iter = stride.array_chunks::<3>();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.remainder(), &[4, 5]);
+    assert_eq!(iter.next(), Some([&1, &2, &3]));
+    assert_eq!(iter.next(), None);
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.array_chunks::<2>().next(), None);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn stride_array_chunks_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 2]);
+    stride.array_chunks::<0>();
+}
+
+#[test]
+fn stride_array_chunks_mut() {
+    let data = &mut [1, 0, 2, 0, 3, 0, 4, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut iter = stride.array_chunks_mut();
+    let [a, b] = iter.next_back().unwrap();
+    *a += 10;
+    *b += 20;
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.into_remainder(), &[5]);
+    assert_eq!(data, &[1, 0, 2, 0, 13, 0, 24, 0, 5]);
+}
+
+#[test]
+fn stride_frames() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6]);
+    let mut iter = stride.frames();
+    assert_eq!(iter.len(), stride.len());
+    assert_eq!(iter.next_back(), Some(&[4, 5, 6]));
+    assert_eq!(iter.next(), Some(&[1, 2, 3]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(<&Stride<i32, 3>>::default().frames().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "slice length (5) is not a multiple of the stride (3)")]
+fn stride_frames_not_multiple() {
+    let _ = Stride::<_, 3>::new(&[1, 2, 3, 4, 5]).frames();
+}
+
+#[test]
+fn stride_frames_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for frame in stride.frames_mut() {
+        frame.reverse();
+    }
+    assert_eq!(data, &[2, 1, 4, 3, 6, 5]);
+}
+
+#[test]
+fn stride_iter_send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: T) {}
+
+    let data = &mut [1, 0, 2, 0, 3];
+    assert_send_sync(Stride::<_, 2>::new(&data[..]).iter());
+    assert_send_sync(Stride::<_, 2>::new_mut(data).iter_mut());
+}
+
+#[test]
+fn stride_iter_clone() {
+    // The element type does not need to implement `Clone`.
+    #[derive(Debug, PartialEq)]
+    struct NotClone(i32);
+
+    let data = [NotClone(1), NotClone(2), NotClone(3)];
+    let stride = Stride::<_, 2>::new(&data);
+    let mut iter = stride.iter();
+    iter.next();
+    let clone = iter.clone();
+    assert_eq!(iter.next(), Some(&NotClone(3)));
+    assert_eq!(clone.collect::<Vec<_>>(), [&NotClone(3)]);
+}
+
+#[test]
+fn stride_iter_debug() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let mut iter = stride.iter();
+    assert_eq!(format!("{:?}", iter), "Iter([1, 3, 5])");
+    iter.next_back();
+    assert_eq!(format!("{:?}", iter), "Iter([1, 3])");
+
+    let data = &mut [1, 2, 3, 4, 5];
+    let mut iter = Stride::<_, 2>::new_mut(data).iter_mut();
+    iter.next();
+    assert_eq!(format!("{:?}", iter), "IterMut([3, 5])");
+}
+
+#[test]
+fn stride_iter_default() {
+    let mut iter = stride::Iter::<i32, 2>::default();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    let mut iter = stride::IterMut::<i32, 2>::default();
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn stride_iter_as_stride() {
+    let stride = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.as_stride(), &[1, 2, 3]);
+    iter.next_back();
+    assert_eq!(iter.as_stride(), &[1, 2]);
+    assert_eq!(iter.as_ref().len(), 2);
+    iter.next();
+    iter.next();
+    assert!(iter.as_stride().is_empty());
+}
+
+#[test]
+fn stride_iter_mut_into_stride() {
+    let data = &mut [1, 0, 0, 2, 0, 0, 3, 0];
+    let stride = Stride::<_, 3>::new_mut(data);
+    let mut iter = stride.iter_mut();
+    iter.next();
+    assert_eq!(iter.as_stride(), &[2, 3]);
+    let rest = iter.into_stride();
+    rest.reverse();
+    assert_eq!(data, &[1, 0, 0, 3, 0, 0, 2, 0]);
+
+    let stride = Stride::<_, 3>::new_mut(data);
+    let mut iter = stride.iter_mut();
+    iter.nth(5);
+    assert!(iter.into_stride().is_empty());
+}
+
+#[test]
+fn stride_iter_with_raw_indices() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let pairs: Vec<_> = stride.iter_with_raw_indices().collect();
+    assert_eq!(pairs, [(0, &1), (3, &4), (6, &7)]);
+    let pairs: Vec<_> = stride.iter_with_raw_indices().rev().collect();
+    assert_eq!(pairs, [(6, &7), (3, &4), (0, &1)]);
+
+    let mut iter = stride.iter_with_raw_indices();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.nth(1), Some((3, &4)));
+    assert_eq!(iter.len(), 1);
+
+    for (raw, elem) in stride.iter_with_raw_indices() {
+        assert_eq!(stride.underlying()[raw], *elem);
+    }
+}
+
+#[test]
+fn stride_split_into() {
+    let data = &[1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0, 8, 0, 9, 0, 10];
+    let stride = Stride::<_, 2>::new(data);
+    let parts: Vec<_> = stride.split_into(3).collect();
+    assert_eq!(parts, [&[1, 2, 3, 4][..], &[5, 6, 7], &[8, 9, 10]]);
+    let parts: Vec<_> = stride.split_into(3).rev().collect();
+    assert_eq!(parts, [&[8, 9, 10][..], &[5, 6, 7], &[1, 2, 3, 4]]);
+
+    let mut iter = stride.split_into(4);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next().unwrap(), &[1, 2, 3]);
+    assert_eq!(iter.next_back().unwrap(), &[9, 10]);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next().unwrap(), &[4, 5, 6]);
+    assert_eq!(iter.next_back().unwrap(), &[7, 8]);
+    assert!(iter.next().is_none());
+
+    let stride = Stride::<_, 2>::new(&[1, 0, 2]);
+    let parts: Vec<_> = stride.split_into(4).map(|part| part.len()).collect();
+    assert_eq!(parts, [1, 1, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "number of parts must be non-zero")]
+fn stride_split_into_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 2]);
+    stride.split_into(0);
+}
+
+#[test]
+fn stride_split_into_mut() {
+    let data = &mut [0; 19];
+    let stride = Stride::<_, 2>::new_mut(data);
+    for (i, part) in stride.split_into_mut(3).enumerate() {
+        part.fill(i + 1);
+    }
+    assert_eq!(
+        data,
+        &[1, 0, 1, 0, 1, 0, 1, 0, 2, 0, 2, 0, 2, 0, 3, 0, 3, 0, 3]
+    );
+
+    let stride = Stride::<_, 2>::new_mut(data);
+    std::thread::scope(|s| {
+        for part in stride.split_into_mut(4).rev() {
+            s.spawn(move || part.iter_mut().for_each(|x| *x *= 10));
+        }
+    });
+    assert_eq!(
+        data,
+        &[10, 0, 10, 0, 10, 0, 10, 0, 20, 0, 20, 0, 20, 0, 30, 0, 30, 0, 30]
+    );
+}
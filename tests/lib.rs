@@ -1,8 +1,180 @@
+#![cfg_attr(feature = "nightly", feature(trusted_len))]
+
 mod iter;
 mod ops;
 
 use stride::Stride;
 
+#[test]
+fn stride_from_raw_parts() {
+    let data = [1, 2, 3, 4, 5];
+    let stride = unsafe { Stride::<_, 2>::from_raw_parts(data.as_ptr(), data.len()) };
+    assert_eq!(stride, &[1, 3, 5]);
+    let stride = unsafe { Stride::<_, 2>::from_raw_parts(data[1..].as_ptr(), 4) };
+    assert_eq!(stride, &[2, 4]);
+}
+
+#[test]
+fn stride_from_raw_parts_mut() {
+    let mut data = [1, 2, 3, 4, 5];
+    let stride = unsafe { Stride::<_, 2>::from_raw_parts_mut(data.as_mut_ptr(), data.len()) };
+    stride.reverse();
+    assert_eq!(data, [5, 2, 3, 4, 1]);
+}
+
+#[test]
+fn stride_as_ptr_range() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let range = data.as_mut_ptr_range();
+    let stride = Stride::<_, 3>::new_mut(data);
+    assert_eq!(stride.as_ptr(), range.start as *const _);
+    assert_eq!(
+        stride.as_ptr_range(),
+        range.start as *const _..range.end as *const _
+    );
+    assert_eq!(stride.as_mut_ptr_range(), range);
+
+    let stride = <&Stride<i32, 3>>::default();
+    let range = stride.as_ptr_range();
+    assert_eq!(range.start, range.end);
+}
+
+#[test]
+fn stride_underlying() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let stride = Stride::<_, 3>::new_mut(data);
+    assert_eq!(stride.underlying(), &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(stride[1..].underlying(), &[4, 5, 6, 7]);
+    assert_eq!(stride[..1].underlying(), &[1, 2, 3]);
+    stride[1..].underlying_mut().fill(0);
+    assert_eq!(data, &[1, 2, 3, 0, 0, 0, 0]);
+}
+
+#[test]
+fn stride_as_arrays() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 3>::new_mut(data);
+    assert_eq!(stride.as_arrays().unwrap(), &[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(stride[1..].as_arrays().unwrap(), &[[4, 5, 6]]);
+    assert_eq!(stride[..1].as_arrays().unwrap(), &[[1, 2, 3]]);
+    stride.as_arrays_mut().unwrap()[1][2] = 7;
+    assert_eq!(data, &[1, 2, 3, 4, 5, 7]);
+
+    let err = Stride::<_, 4>::new_mut(data).as_arrays_mut().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "slice length (6) is not a multiple of the stride (4)"
+    );
+    assert!(<&Stride<i32, 4>>::default().as_arrays().unwrap().is_empty());
+}
+
+#[test]
+fn stride_restride() {
+    let data: Vec<_> = (0..13).collect();
+    let stride = Stride::<_, 2>::new(&data);
+    assert_eq!(stride.restride::<2>(), stride);
+    assert_eq!(stride.restride::<4>(), &[0, 4, 8, 12]);
+    assert_eq!(stride.restride::<6>(), &[0, 6, 12]);
+    assert!(stride.restride::<6>().iter().eq(stride.iter().step_by(3)));
+    assert_eq!(stride[1..].restride::<6>(), &[2, 8]);
+    assert!(<&Stride<i32, 2>>::default().restride::<4>().is_empty());
+}
+
+#[test]
+fn stride_restride_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let stride = Stride::<_, 1>::new_mut(data);
+    stride.restride_mut::<3>().reverse();
+    assert_eq!(data, &[7, 2, 3, 4, 5, 6, 1]);
+}
+
+#[test]
+fn stride_as_cell_stride() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let cells = Stride::<_, 3>::new_mut(data).as_cell_stride();
+    assert_eq!(cells.len(), 3);
+    let first = &cells[0];
+    for cell in cells {
+        cell.set(cell.get() * 10 + first.get());
+    }
+    assert_eq!(data, &[11, 2, 3, 51, 5, 6, 81]);
+}
+
+#[test]
+fn stride_lanes() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    let [a, b, c] = Stride::<_, 3>::new(data).lanes();
+    assert_eq!(a, &[1, 4, 7]);
+    assert_eq!(b, &[2, 5]);
+    assert_eq!(c, &[3, 6]);
+
+    let [a, b, c, d] = Stride::<_, 4>::new(&data[..2]).lanes();
+    assert_eq!(a, &[1]);
+    assert_eq!(b, &[2]);
+    assert!(c.is_empty());
+    assert!(d.is_empty());
+
+    let [lane] = Stride::<_, 1>::new(data).lanes();
+    assert_eq!(lane, data);
+}
+
+#[test]
+fn stride_lanes_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let [mut a, mut b, mut c] = Stride::<_, 3>::new_mut(data).lanes_mut();
+    assert_eq!(a.len(), 3);
+    assert_eq!(b.len(), 2);
+    assert_eq!(c, [3, 6]);
+    a[0] = 10;
+    b.swap(0, 1);
+    c.fill(0);
+    for x in &mut a {
+        *x *= 2;
+    }
+    assert_eq!(a.get(3), None);
+    assert_eq!(a.iter().len(), 3);
+    assert_eq!(format!("{:?}", b), "[5, 2]");
+    assert_eq!(format!("{:?}", b.iter_mut()), "LaneIterMut([5, 2])");
+    assert_eq!(b.into_iter().rev().map(|x| *x).collect::<Vec<_>>(), [2, 5]);
+    assert_eq!(data, &[20, 5, 0, 8, 2, 0, 14]);
+}
+
+#[test]
+fn stride_lanes_mut_short() {
+    let data = &mut [1, 2];
+    let [a, b, mut c] = Stride::<_, 3>::new_mut(data).lanes_mut();
+    assert_eq!(a, [1]);
+    assert_eq!(b, [2]);
+    assert!(c.is_empty());
+    assert!(c.iter_mut().next().is_none());
+    assert_eq!(c.get_mut(0), None);
+    assert!(stride::LaneMut::<i32, 3>::default().is_empty());
+}
+
+#[test]
+fn stride_lanes_mut_threads() {
+    let mut data: Vec<u32> = (0..12).collect();
+    let lanes = Stride::<_, 3>::new_mut(&mut data).lanes_mut();
+    std::thread::scope(|s| {
+        for (k, lane) in IntoIterator::into_iter(lanes).enumerate() {
+            s.spawn(move || {
+                for x in lane {
+                    *x += 100 * k as u32;
+                }
+            });
+        }
+    });
+    assert_eq!(data, [0, 101, 202, 3, 104, 205, 6, 107, 208, 9, 110, 211]);
+}
+
+#[test]
+#[should_panic(expected = "index 2 out of range for stride of length 2 (S = 3)")]
+fn stride_lanes_mut_out_of_bounds() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let [_, mut b, _] = Stride::<_, 3>::new_mut(data).lanes_mut();
+    b[2] = 0;
+}
+
 #[test]
 fn stride_debug() {
     let stride = Stride::<_, 1>::new(&[1, 2, 3, 4, 5]);
@@ -15,6 +187,115 @@ fn stride_debug() {
     assert_eq!(format!("{:?}", stride), "[1, 4]");
 }
 
+#[test]
+fn stride_try_new() {
+    let stride = Stride::<_, 2>::try_new(&[1, 2, 3, 4]).unwrap();
+    assert_eq!(stride, &[1, 3]);
+    assert!(Stride::<i32, 2>::try_new(&[]).is_ok());
+
+    let err = Stride::<_, 2>::try_new(&[1, 2, 3]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "slice length (3) is not a multiple of the stride (2)"
+    );
+}
+
+#[test]
+fn stride_try_new_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    assert!(Stride::<_, 4>::try_new_mut(data).is_err());
+    let stride = Stride::<_, 3>::try_new_mut(data).unwrap();
+    stride.reverse();
+    assert_eq!(data, &[4, 2, 3, 1, 5, 6]);
+}
+
+#[test]
+fn stride_new_exact() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    assert_eq!(Stride::<_, 3>::new_exact(data), &[1, 4]);
+    Stride::<_, 6>::new_exact_mut(data)[0] = 7;
+    assert_eq!(data, &[7, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "slice length (5) is not a multiple of the stride (3)")]
+fn stride_new_exact_not_multiple() {
+    Stride::<_, 3>::new_exact(&[1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "slice length (5) is not a multiple of the stride (2)")]
+fn stride_new_exact_mut_not_multiple() {
+    Stride::<_, 2>::new_exact_mut(&mut [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn stride_new_from_array() {
+    let data = &mut [1, 2, 3, 4, 5];
+    assert_eq!(Stride::<_, 2>::new_from_array(data), &[1, 3, 5]);
+    assert_eq!(Stride::<_, 5>::new_exact_from_array(data), &[1]);
+    Stride::<_, 3>::new_from_array_mut(data)[1] = 7;
+    Stride::<_, 1>::new_exact_from_array_mut(data)[0] = 0;
+    assert_eq!(data, &[0, 2, 3, 7, 5]);
+
+    let stride: &Stride<_, 2> = (&[1, 2, 3]).into();
+    assert_eq!(stride, &[1, 3]);
+    let stride: &mut Stride<_, 2> = data.into();
+    stride.reverse();
+    assert_eq!(data, &[5, 2, 3, 7, 0]);
+}
+
+#[test]
+fn stride_new_with_offset() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(Stride::<_, 3>::new_with_offset(data, 0), &[1, 4, 7]);
+    assert_eq!(Stride::<_, 3>::new_with_offset(data, 1), &[2, 5]);
+    assert_eq!(Stride::<_, 3>::new_with_offset(data, 2), &[3, 6]);
+    assert!(Stride::<_, 3>::new_with_offset(data, 7).is_empty());
+}
+
+#[test]
+fn stride_new_with_offset_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    Stride::<_, 2>::new_with_offset_mut(data, 1).reverse();
+    assert_eq!(data, &[1, 6, 3, 4, 5, 2]);
+}
+
+#[test]
+#[should_panic(expected = "offset > len")]
+fn stride_new_with_offset_out_of_bounds() {
+    Stride::<_, 2>::new_with_offset(&[1, 2, 3], 4);
+}
+
+#[test]
+fn stride_const() {
+    static TABLE: [u16; 8] = [1, 10, 2, 20, 3, 30, 4, 40];
+    const EVEN: &Stride<u16, 2> = Stride::new(&TABLE);
+    const LEN: usize = EVEN.len();
+    const FIRST: Option<&u16> = EVEN.first();
+    const LAST: Option<&u16> = EVEN.last();
+    const RAW: Option<usize> = EVEN.raw_index(3);
+    const SPLIT: (&Stride<u16, 2>, &Stride<u16, 2>) = EVEN.split_at(1);
+    const EXACT: Result<&Stride<u16, 4>, stride::StrideError> = Stride::try_new(&TABLE);
+
+    assert_eq!(LEN, 4);
+    assert_eq!(FIRST, Some(&1));
+    assert_eq!(LAST, Some(&4));
+    assert_eq!(RAW, Some(6));
+    assert_eq!(SPLIT.0, &[1]);
+    assert_eq!(SPLIT.1, &[2, 3, 4]);
+    assert_eq!(EXACT.unwrap(), &[1, 3]);
+
+    const fn sum(stride: &Stride<u16, 2>) -> u16 {
+        match stride.split_first() {
+            Some((first, rest)) => *first + sum(rest),
+            None => 0,
+        }
+    }
+    const SUM: u16 = sum(Stride::new(&[1, 10, 2, 20, 3]));
+    assert_eq!(SUM, 6);
+}
+
 #[test]
 fn stride_default() {
     let stride: &Stride<i64, 3> = Default::default();
@@ -108,6 +389,15 @@ fn stride_last() {
     assert_eq!(stride.last(), Some(&1));
 }
 
+#[test]
+fn stride_last_non_multiple() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(stride.last(), Some(&4));
+
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4]);
+    assert_eq!(stride.last(), Some(&4));
+}
+
 #[test]
 fn stride_last_empty() {
     let stride = <&Stride<i32, 2>>::default();
@@ -122,6 +412,14 @@ fn stride_last_mut() {
     assert_eq!(stride.last(), Some(&5));
 }
 
+#[test]
+fn stride_last_mut_non_multiple() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 3>::new_mut(data);
+    *stride.last_mut().unwrap() = 7;
+    assert_eq!(data, &[1, 2, 3, 7, 5]);
+}
+
 #[test]
 fn stride_last_mut_empty() {
     let stride = <&mut Stride<i32, 2>>::default();
@@ -138,3 +436,544 @@ fn stride_swap() {
     stride.swap(2, 1);
     assert_eq!(stride, &[1, 3, 5]);
 }
+
+#[test]
+#[should_panic]
+fn stride_swap_out_of_bounds() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.swap(0, 3);
+}
+
+#[test]
+#[should_panic(expected = "out of range for stride of length 3 (S = 2)")]
+fn stride_swap_overflow() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.swap(0, usize::MAX / 2 + 1);
+}
+
+#[test]
+fn stride_swap_unchecked() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    unsafe {
+        stride.swap_unchecked(0, 2);
+        stride.swap_unchecked(1, 1);
+    }
+    assert_eq!(data, &[5, 2, 3, 4, 1]);
+}
+
+#[test]
+fn stride_split_first() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let (first, rest) = stride.split_first().unwrap();
+    assert_eq!(first, &1);
+    assert_eq!(rest, &[3, 5]);
+
+    let stride = Stride::<_, 2>::new(&[1]);
+    let (first, rest) = stride.split_first().unwrap();
+    assert_eq!(first, &1);
+    assert!(rest.is_empty());
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.split_first(), None);
+}
+
+#[test]
+fn stride_split_first_mut() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let (first, rest) = stride.split_first_mut().unwrap();
+    *first = 0;
+    rest[1] = 6;
+    assert_eq!(data, &[0, 2, 3, 4, 6]);
+
+    let stride = <&mut Stride<i32, 2>>::default();
+    assert_eq!(stride.split_first_mut(), None);
+}
+
+#[test]
+fn stride_split_last() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let (last, rest) = stride.split_last().unwrap();
+    assert_eq!(last, &5);
+    assert_eq!(rest, &[1, 3]);
+
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4]);
+    let (last, rest) = stride.split_last().unwrap();
+    assert_eq!(last, &4);
+    assert_eq!(rest, &[1]);
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.split_last(), None);
+}
+
+#[test]
+fn stride_split_last_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let (last, rest) = stride.split_last_mut().unwrap();
+    *last = 0;
+    rest[0] = 7;
+    assert_eq!(data, &[7, 2, 3, 4, 0, 6]);
+
+    let stride = <&mut Stride<i32, 2>>::default();
+    assert_eq!(stride.split_last_mut(), None);
+}
+
+#[test]
+fn stride_split_at() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let (left, right) = stride.split_at(0);
+    assert!(left.is_empty());
+    assert_eq!(right, &[1, 3, 5]);
+
+    let (left, right) = stride.split_at(2);
+    assert_eq!(left, &[1, 3]);
+    assert_eq!(right, &[5]);
+
+    let (left, right) = stride.split_at(3);
+    assert_eq!(left, &[1, 3, 5]);
+    assert!(right.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn stride_split_at_out_of_bounds() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    stride.split_at(4);
+}
+
+#[test]
+fn stride_split_at_mut() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let (left, right) = stride.split_at_mut(1);
+    left[0] = 0;
+    right.swap(0, 1);
+    assert_eq!(data, &[0, 2, 5, 4, 3]);
+}
+
+#[test]
+fn stride_reverse() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7, 8];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.reverse();
+    assert_eq!(data, &[7, 2, 5, 4, 3, 6, 1, 8]);
+
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.reverse();
+    assert_eq!(data, &[5, 2, 3, 4, 1]);
+
+    let stride = <&mut Stride<i32, 2>>::default();
+    stride.reverse();
+    assert!(stride.is_empty());
+}
+
+#[test]
+fn stride_fill() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.fill(0);
+    assert_eq!(data, &[0, 2, 0, 4, 0]);
+
+    let data = &mut [String::new(), String::new(), String::new()];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.fill(String::from("a"));
+    assert_eq!(data, &["a", "", "a"]);
+
+    let stride = <&mut Stride<String, 2>>::default();
+    stride.fill(String::from("a"));
+    assert!(stride.is_empty());
+}
+
+macro_rules! check_fill {
+    ($ty:ty, $s:literal) => {
+        // Lengths that aren't a multiple of `S` have a tail without padding.
+        for len in 0..=300 {
+            let mut data: Vec<$ty> = (0..len).map(|x| x as $ty).collect();
+            let mut expected = data.clone();
+            for i in (0..len).step_by($s) {
+                expected[i] = 7 as $ty;
+            }
+            Stride::<_, $s>::new_mut(&mut data).fill(7 as $ty);
+            assert_eq!(data, expected, "len {}", len);
+        }
+    };
+}
+
+#[test]
+fn stride_fill_copy() {
+    check_fill!(u8, 1);
+    check_fill!(u8, 2);
+    check_fill!(u8, 3);
+    check_fill!(i8, 4);
+    check_fill!(u16, 5);
+    check_fill!(u32, 2);
+    check_fill!(f32, 4);
+    check_fill!(u64, 2);
+    check_fill!(f64, 3);
+}
+
+#[test]
+fn stride_fill_with() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut calls = 0;
+    stride.fill_with(|| {
+        calls += 1;
+        0
+    });
+    assert_eq!(calls, 3);
+    assert_eq!(data, &[0, 2, 0, 4, 0]);
+}
+
+#[test]
+fn stride_copy_from_slice() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_from_slice(&[6, 7, 8]);
+    assert_eq!(data, &[6, 2, 7, 4, 8]);
+
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 3>::new_mut(data);
+    stride.copy_from_slice(&[7, 8]);
+    assert_eq!(data, &[7, 2, 3, 8, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "source length (2) does not match destination length (3)")]
+fn stride_copy_from_slice_len_mismatch() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_from_slice(&[6, 7]);
+}
+
+#[test]
+fn stride_clone_from_slice() {
+    let data = &mut [vec![1], vec![2], vec![3]];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.clone_from_slice(&[vec![4, 5], vec![6]]);
+    assert_eq!(data, &[vec![4, 5], vec![2], vec![6]]);
+}
+
+#[test]
+#[should_panic(expected = "source length (3) does not match destination length (2)")]
+fn stride_clone_from_slice_len_mismatch() {
+    let data = &mut [vec![1], vec![2], vec![3]];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.clone_from_slice(&[vec![4], vec![5], vec![6]]);
+}
+
+#[test]
+fn stride_copy_to_slice() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let mut dst = [0; 3];
+    stride.copy_to_slice(&mut dst);
+    assert_eq!(dst, [1, 3, 5]);
+
+    let stride = Stride::<_, 4>::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut dst = [0; 2];
+    stride.copy_to_slice(&mut dst);
+    assert_eq!(dst, [1, 5]);
+}
+
+#[test]
+#[should_panic(expected = "source length (3) does not match destination length (4)")]
+fn stride_copy_to_slice_len_mismatch() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    stride.copy_to_slice(&mut [0; 4]);
+}
+
+#[test]
+fn stride_collect_array() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(stride.collect_array(), Some([1, 3, 5]));
+    assert_eq!(stride.collect_array::<2>(), None);
+    assert_eq!(stride.collect_array::<4>(), None);
+
+    let stride = <&Stride<i32, 2>>::default();
+    assert_eq!(stride.collect_array(), Some([]));
+}
+
+#[test]
+fn stride_copy_from_stride() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_from_stride(Stride::<_, 3>::new(&[6, 0, 0, 7, 0, 0, 8]));
+    assert_eq!(data, &[6, 2, 7, 4, 8]);
+
+    let data = &mut [1, 2, 3];
+    let stride = Stride::<_, 1>::new_mut(data);
+    stride.copy_from_stride(Stride::<_, 2>::new(&[4, 0, 5, 0, 6]));
+    assert_eq!(data, &[4, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "source length (2) does not match destination length (3)")]
+fn stride_copy_from_stride_len_mismatch() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_from_stride(Stride::<_, 2>::new(&[6, 0, 7, 0]));
+}
+
+#[test]
+fn stride_swap_with_slice() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let other = &mut [6, 7, 8];
+    stride.swap_with_slice(other);
+    assert_eq!(data, &[6, 2, 7, 4, 8]);
+    assert_eq!(other, &[1, 3, 5]);
+}
+
+#[test]
+#[should_panic(expected = "source length (4) does not match destination length (3)")]
+fn stride_swap_with_slice_len_mismatch() {
+    let data = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.swap_with_slice(&mut [6, 7, 8, 9]);
+}
+
+#[test]
+fn stride_swap_with_stride() {
+    let a = &mut [1, 2, 3, 4, 5];
+    let b = &mut [6, 7, 8];
+    Stride::<_, 2>::new_mut(a).swap_with_stride(Stride::<_, 1>::new_mut(b));
+    assert_eq!(a, &[6, 2, 7, 4, 8]);
+    assert_eq!(b, &[1, 3, 5]);
+}
+
+#[test]
+#[should_panic(expected = "source length (2) does not match destination length (3)")]
+fn stride_swap_with_stride_len_mismatch() {
+    let a = &mut [1, 2, 3, 4, 5];
+    let b = &mut [6, 7, 8];
+    Stride::<_, 2>::new_mut(a).swap_with_stride(Stride::<_, 2>::new_mut(b));
+}
+
+#[test]
+fn stride_copy_within() {
+    let data = &mut [1, 0, 2, 0, 3, 0, 4, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_within(1..3, 3);
+    assert_eq!(stride, &[1, 2, 3, 2, 3]);
+    stride.copy_within(2.., 0);
+    assert_eq!(stride, &[3, 2, 3, 2, 3]);
+    stride.copy_within(..=1, 1);
+    assert_eq!(stride, &[3, 3, 2, 2, 3]);
+    stride.copy_within(4..4, 5);
+    assert_eq!(data, &[3, 0, 3, 0, 2, 0, 2, 0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "dest is out of bounds")]
+fn stride_copy_within_dest_out_of_bounds() {
+    let data = &mut [1, 0, 2, 0, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_within(1..3, 2);
+}
+
+#[test]
+#[should_panic(expected = "range end index 4 out of range for stride of length 3")]
+fn stride_copy_within_src_out_of_bounds() {
+    let data = &mut [1, 0, 2, 0, 3];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.copy_within(1..4, 0);
+}
+
+#[test]
+fn stride_rotate_left() {
+    let data = &mut [1, 0, 2, 0, 3, 0, 4, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.rotate_left(2);
+    assert_eq!(stride, &[3, 4, 5, 1, 2]);
+    stride.rotate_left(0);
+    assert_eq!(stride, &[3, 4, 5, 1, 2]);
+    stride.rotate_left(5);
+    assert_eq!(data, &[3, 0, 4, 0, 5, 0, 1, 0, 2]);
+}
+
+#[test]
+#[should_panic(expected = "mid > len")]
+fn stride_rotate_left_out_of_bounds() {
+    let data = &mut [1, 0, 2, 0, 3];
+    Stride::<_, 2>::new_mut(data).rotate_left(4);
+}
+
+#[test]
+fn stride_rotate_right() {
+    let data = &mut [1, 0, 2, 0, 3, 0, 4, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.rotate_right(2);
+    assert_eq!(stride, &[4, 5, 1, 2, 3]);
+    stride.rotate_right(5);
+    assert_eq!(data, &[4, 0, 5, 0, 1, 0, 2, 0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "k > len")]
+fn stride_rotate_right_out_of_bounds() {
+    let data = &mut [1, 0, 2, 0, 3];
+    Stride::<_, 2>::new_mut(data).rotate_right(4);
+}
+
+#[test]
+fn stride_contains() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5]);
+    assert!(stride.contains(&1));
+    assert!(stride.contains(&4));
+    assert!(!stride.contains(&2));
+    assert!(!stride.contains(&5));
+    assert!(!<&Stride<i32, 3>>::default().contains(&1));
+}
+
+#[test]
+fn stride_starts_with() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert!(stride.starts_with(&[]));
+    assert!(stride.starts_with(&[1]));
+    assert!(stride.starts_with(&[1, 3, 5]));
+    assert!(!stride.starts_with(&[1, 3, 5, 7]));
+    assert!(!stride.starts_with(&[2]));
+}
+
+#[test]
+fn stride_ends_with() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert!(stride.ends_with(&[]));
+    assert!(stride.ends_with(&[5]));
+    assert!(stride.ends_with(&[1, 3, 5]));
+    assert!(!stride.ends_with(&[0, 1, 3, 5]));
+    assert!(!stride.ends_with(&[4]));
+}
+
+#[test]
+fn stride_starts_with_stride() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert!(stride.starts_with_stride(Stride::<_, 1>::new(&[1, 3])));
+    assert!(stride.starts_with_stride(stride));
+    assert!(!stride.starts_with_stride(Stride::<_, 1>::new(&[1, 2])));
+}
+
+#[test]
+fn stride_ends_with_stride() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert!(stride.ends_with_stride(Stride::<_, 1>::new(&[3, 5])));
+    assert!(stride.ends_with_stride(stride));
+    assert!(!stride.ends_with_stride(Stride::<_, 1>::new(&[4, 5])));
+}
+
+#[test]
+fn stride_binary_search() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 2, 0, 4, 0, 8]);
+    assert_eq!(stride.binary_search(&0), Err(0));
+    assert_eq!(stride.binary_search(&1), Ok(0));
+    assert!(matches!(stride.binary_search(&2), Ok(1) | Ok(2)));
+    assert_eq!(stride.binary_search(&3), Err(3));
+    assert_eq!(stride.binary_search(&8), Ok(4));
+    assert_eq!(stride.binary_search(&9), Err(5));
+    assert_eq!(<&Stride<i32, 2>>::default().binary_search(&1), Err(0));
+}
+
+#[test]
+fn stride_binary_search_by() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 2, 0, 4, 0, 8]);
+    assert_eq!(stride.binary_search_by(|x| x.cmp(&4)), Ok(2));
+    assert_eq!(stride.binary_search_by(|x| x.cmp(&5)), Err(3));
+}
+
+#[test]
+fn stride_binary_search_by_key() {
+    let stride = Stride::<_, 2>::new(&[(1, 'a'), (0, 'z'), (4, 'b'), (0, 'z'), (8, 'c')]);
+    assert_eq!(stride.binary_search_by_key(&4, |&(k, _)| k), Ok(1));
+    assert_eq!(stride.binary_search_by_key(&0, |&(k, _)| k), Err(0));
+}
+
+#[test]
+fn stride_partition_point() {
+    let stride = Stride::<_, 2>::new(&[1, 9, 2, 0, 3, 9, 5, 0, 6]);
+    assert_eq!(stride.partition_point(|&x| x < 0), 0);
+    assert_eq!(stride.partition_point(|&x| x < 4), 3);
+    assert_eq!(stride.partition_point(|&x| x < 7), 5);
+    assert_eq!(<&Stride<i32, 2>>::default().partition_point(|_| true), 0);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn stride_iter_trusted_len() {
+    fn assert_trusted_len<I: std::iter::TrustedLen>(_: I) {}
+
+    let data = &mut [1, 0, 2, 0, 3];
+    assert_trusted_len(Stride::<_, 2>::new(&data[..]).iter());
+    assert_trusted_len(Stride::<_, 2>::new_mut(data).iter_mut());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Zst;
+
+#[test]
+fn stride_zst_len() {
+    assert_eq!(Stride::<_, 3>::new(&[Zst; 0]).len(), 0);
+    assert_eq!(Stride::<_, 3>::new(&[Zst; 1]).len(), 1);
+    assert_eq!(Stride::<_, 3>::new(&[Zst; 6]).len(), 2);
+    assert_eq!(Stride::<_, 3>::new(&[Zst; 7]).len(), 3);
+}
+
+#[test]
+fn stride_zst_iter() {
+    let data = &mut [Zst; 7];
+    let stride = Stride::<_, 3>::new(&data[..]);
+    assert_eq!(stride.iter().len(), 3);
+    assert_eq!(stride.iter().collect::<Vec<_>>(), [&Zst; 3]);
+    assert_eq!(stride.iter().rev().count(), 3);
+    assert_eq!(stride.iter().nth(2), Some(&Zst));
+    assert_eq!(stride.iter().nth(3), None);
+    assert_eq!(stride.iter().nth_back(2), Some(&Zst));
+    assert_eq!(stride.iter().fold(0, |n, _| n + 1), 3);
+
+    let mut iter = stride.iter();
+    iter.next();
+    assert_eq!(iter.as_stride().len(), 2);
+
+    let stride = Stride::<_, 3>::new_mut(data);
+    assert_eq!(stride.iter_mut().count(), 3);
+    let mut iter = stride.iter_mut();
+    iter.next_back();
+    assert_eq!(iter.into_stride().len(), 2);
+}
+
+#[test]
+fn stride_zst_slicing() {
+    let data = &mut [Zst; 7];
+    let stride = Stride::<_, 3>::new_mut(data);
+    assert_eq!(stride[1..].len(), 2);
+    assert_eq!(stride[..=1].len(), 2);
+    assert_eq!(stride.get(3), None);
+    let (left, right) = stride.split_at_mut(1);
+    assert_eq!((left.len(), right.len()), (1, 2));
+    assert_eq!(
+        stride.chunks(2).map(Stride::len).collect::<Vec<_>>(),
+        [2, 1]
+    );
+    stride.swap(0, 2);
+    stride.reverse();
+    stride.sort_unstable();
+    assert_eq!(stride, &[Zst; 3]);
+    assert!(stride.get_disjoint_mut([0, 1, 2]).is_ok());
+}
+
+#[test]
+fn stride_zst_huge() {
+    let data: &[Zst] =
+        unsafe { std::slice::from_raw_parts(std::ptr::NonNull::dangling().as_ptr(), usize::MAX) };
+    let stride = Stride::<_, 4>::new(data);
+    let len = usize::MAX / 4 + 1;
+    assert_eq!(stride.len(), len);
+    assert_eq!(stride.iter().len(), len);
+    assert_eq!(stride.iter().nth(len - 1), Some(&Zst));
+    assert_eq!(stride.iter().skip(len - 2).count(), 2);
+    assert_eq!(stride.last(), Some(&Zst));
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "math")]
+
+use stride::Stride;
+
+#[test]
+fn stride_sum() {
+    let data: Vec<i32> = (0..100).collect();
+    assert_eq!(Stride::<_, 1>::new(&data).sum(), 4950);
+    assert_eq!(
+        Stride::<_, 3>::new(&data).sum(),
+        (0..100).step_by(3).sum::<i32>()
+    );
+    assert_eq!(
+        Stride::<_, 3>::new(&data[1..]).sum(),
+        (1..100).step_by(3).sum::<i32>()
+    );
+    assert_eq!(Stride::<_, 7>::new(&data[..0]).sum(), 0);
+}
+
+#[test]
+fn stride_sum_tail() {
+    // Lengths around the number of accumulators.
+    let data: Vec<u64> = (1..=200).collect();
+    for len in 0..data.len() {
+        let expected: u64 = data[..len].iter().step_by(2).sum();
+        assert_eq!(
+            Stride::<_, 2>::new(&data[..len]).sum(),
+            expected,
+            "len {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn stride_sum_wrapping() {
+    let data = [200u8, 1, 100, 2, 10, 3];
+    assert_eq!(Stride::<_, 2>::new(&data).sum(), 54);
+    assert_eq!(Stride::<_, 2>::new(&data[1..]).sum(), 6);
+}
+
+#[test]
+fn stride_sum_float() {
+    let data: Vec<f64> = (0..1000).map(|x| x as f64 * 0.5).collect();
+    let expected: f64 = data.iter().step_by(4).sum();
+    let sum = Stride::<_, 4>::new(&data).sum();
+    assert!((sum - expected).abs() < 1e-9, "{} != {}", sum, expected);
+    assert_eq!(Stride::<f32, 2>::new(&[]).sum(), 0.0);
+}
+
+#[test]
+fn stride_min_max() {
+    let data: Vec<i16> = (0..100).map(|x| (x * 37 % 101) - 50).collect();
+    for len in 0..data.len() {
+        let stride = Stride::<_, 3>::new(&data[..len]);
+        assert_eq!(
+            stride.min_elem(),
+            stride.iter().copied().min(),
+            "len {}",
+            len
+        );
+        assert_eq!(
+            stride.max_elem(),
+            stride.iter().copied().max(),
+            "len {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn stride_min_max_float() {
+    let data: Vec<f32> = (0..50).map(|x| (x * 13 % 51) as f32 - 25.0).collect();
+    let stride = Stride::<_, 2>::new(&data);
+    assert_eq!(stride.min_elem(), stride.iter().copied().reduce(f32::min));
+    assert_eq!(stride.max_elem(), stride.iter().copied().reduce(f32::max));
+    assert_eq!(Stride::<f32, 2>::new(&[]).min_elem(), None);
+    assert_eq!(Stride::<f32, 2>::new(&[]).max_elem(), None);
+}
+
+#[test]
+fn stride_min_max_nan() {
+    let mut data = [f64::NAN; 40];
+    data[20] = 1.0;
+    data[22] = -1.0;
+    let stride = Stride::<_, 2>::new(&data);
+    assert_eq!(stride.min_elem(), Some(-1.0));
+    assert_eq!(stride.max_elem(), Some(1.0));
+    assert!(Stride::<_, 2>::new(&data[1..]).min_elem().unwrap().is_nan());
+    assert!(Stride::<_, 2>::new(&data[1..]).max_elem().unwrap().is_nan());
+}
+
+#[test]
+fn stride_sum_does_not_read_between() {
+    let data = [1u32, u32::MAX, 2, u32::MAX, 3];
+    assert_eq!(Stride::<_, 2>::new(&data).sum(), 6);
+    assert_eq!(Stride::<_, 2>::new(&data).max_elem(), Some(3));
+}
+
+#[test]
+fn stride_ord_max_not_shadowed() {
+    let a = Stride::<_, 2>::new(&[1, 5, 2]);
+    let b = Stride::<_, 2>::new(&[1, 0, 3]);
+    assert_eq!(a.max(b), b);
+    assert_eq!(a.min(b), a);
+    assert_eq!(Ord::max(a, b), b);
+}
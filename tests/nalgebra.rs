@@ -0,0 +1,55 @@
+#![cfg(feature = "nalgebra")]
+
+use core::num::NonZeroUsize;
+
+use nalgebra::{Const, DVectorView, DVectorViewMut, Dyn, Matrix2x3};
+use stride::{DynStride, DynStrideMut, Stride};
+
+#[test]
+fn stride_to_vector_view() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    let view = DVectorView::<_, Const<3>, Dyn>::from(Stride::<_, 3>::new(data));
+    assert_eq!(view.len(), 3);
+    assert_eq!(view.strides(), (3, 9));
+    assert_eq!(view.iter().copied().collect::<Vec<_>>(), [1, 4, 7]);
+
+    let view = DVectorView::<i32, Const<4>, Dyn>::from(Stride::<_, 4>::new(&[]));
+    assert!(view.is_empty());
+}
+
+#[test]
+fn stride_to_vector_view_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let mut view = DVectorViewMut::<_, Const<2>, Dyn>::from(Stride::<_, 2>::new_mut(data));
+    view.neg_mut();
+    assert_eq!(data, &[-1, 2, -3, 4, -5, 6, -7]);
+}
+
+#[test]
+fn stride_matrix_row() {
+    let mut m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let row = Stride::<_, 2>::new(&m.as_slice()[1..]);
+    let view = DVectorView::<_, Const<2>, Dyn>::from(row);
+    assert!(view.iter().eq(m.row(1).iter()));
+
+    let row = Stride::<_, 2>::new_mut(m.as_mut_slice());
+    DVectorViewMut::<_, Const<2>, Dyn>::from(row).fill(0);
+    assert_eq!(m, Matrix2x3::new(0, 0, 0, 4, 5, 6));
+}
+
+#[test]
+fn dyn_stride_to_vector_view() {
+    let data = &[1, 2, 3, 4, 5, 6];
+    let stride = DynStride::new(data, NonZeroUsize::new(4).unwrap());
+    let view = DVectorView::<_, Dyn, Dyn>::from(stride);
+    assert_eq!(view.strides(), (4, 8));
+    assert_eq!(view.iter().copied().collect::<Vec<_>>(), [1, 5]);
+}
+
+#[test]
+fn dyn_stride_to_vector_view_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = DynStrideMut::new(data, NonZeroUsize::new(5).unwrap());
+    DVectorViewMut::<_, Dyn, Dyn>::from(stride).fill(0);
+    assert_eq!(data, &[0, 2, 3, 4, 5, 0]);
+}
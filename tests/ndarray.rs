@@ -0,0 +1,47 @@
+#![cfg(feature = "ndarray")]
+
+use core::num::NonZeroUsize;
+
+use ndarray::{ArrayView1, ArrayViewMut1};
+use stride::{DynStride, DynStrideMut, Stride};
+
+#[test]
+fn stride_to_array_view() {
+    let data = &[1, 2, 3, 4, 5, 6, 7];
+    let view = Stride::<_, 3>::new(data).as_array_view();
+    assert_eq!(view.len(), 3);
+    assert_eq!(view.strides(), &[3]);
+    assert_eq!(view.to_vec(), [1, 4, 7]);
+
+    let view = Stride::<_, 1>::new(data).as_array_view();
+    assert_eq!(view.as_slice(), Some(&data[..]));
+
+    let view = Stride::<i32, 4>::new(&[]).as_array_view();
+    assert!(view.is_empty());
+}
+
+#[test]
+fn stride_to_array_view_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6, 7];
+    let mut view = Stride::<_, 2>::new_mut(data).as_array_view_mut();
+    view.map_inplace(|x| *x = -*x);
+    assert_eq!(data, &[-1, 2, -3, 4, -5, 6, -7]);
+}
+
+#[test]
+fn dyn_stride_to_array_view() {
+    let data = &[1, 2, 3, 4, 5, 6];
+    let stride = DynStride::new(data, NonZeroUsize::new(4).unwrap());
+    let view = ArrayView1::from(stride);
+    assert_eq!(view.strides(), &[4]);
+    assert_eq!(view.to_vec(), [1, 5]);
+}
+
+#[test]
+fn dyn_stride_to_array_view_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = DynStrideMut::new(data, NonZeroUsize::new(5).unwrap());
+    let mut view = ArrayViewMut1::from(stride);
+    view.fill(0);
+    assert_eq!(data, &[0, 2, 3, 4, 5, 0]);
+}
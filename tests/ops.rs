@@ -1,3 +1,5 @@
+use std::convert::TryFrom;
+
 use stride::Stride;
 
 #[test]
@@ -76,6 +78,62 @@ fn stride_hash() {
     assert!(map.is_empty());
 }
 
+fn hash_of<T: std::hash::Hash + ?Sized>(value: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn stride_hash_different_strides() {
+    let a = Stride::<_, 2>::new(&[1, 0, 2, 0, 3]);
+    let b = Stride::<_, 3>::new(&[1, 0, 0, 2, 0, 0, 3, 0]);
+    assert_eq!(a, b);
+    assert_eq!(hash_of(a), hash_of(b));
+}
+
+/// Records each write separately, unlike streaming hashers which only see the
+/// bytes.
+#[derive(Default)]
+struct WriteHasher(Vec<Vec<u8>>);
+
+impl std::hash::Hasher for WriteHasher {
+    fn finish(&self) -> u64 {
+        unimplemented!()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.push(bytes.to_vec());
+    }
+}
+
+fn writes_of<T: std::hash::Hash + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+    let mut hasher = WriteHasher::default();
+    value.hash(&mut hasher);
+    hasher.0
+}
+
+#[test]
+fn stride_hash_unit_stride() {
+    let a = Stride::<u32, 1>::new(&[1, 2]);
+    let b = Stride::<u32, 2>::new(&[1, 0, 2]);
+    let c = Stride::<u32, 3>::new(&[1, 0, 0, 2, 0]);
+    assert_eq!(a, b);
+    assert_eq!(writes_of(a), writes_of(b));
+    assert_eq!(writes_of(a), writes_of(c));
+}
+
+#[test]
+fn stride_hash_length_prefix() {
+    let a = Stride::<_, 1>::new(&[1, 2]);
+    let b = Stride::<_, 1>::new(&[3]);
+    let c = Stride::<_, 1>::new(&[1]);
+    let d = Stride::<_, 1>::new(&[2, 3]);
+    assert_ne!(hash_of(&(a, b)), hash_of(&(c, d)));
+    assert_ne!(hash_of(<&Stride<i32, 1>>::default()), hash_of(&()));
+}
+
 #[test]
 fn stride_partial_ord() {
     let a = Stride::<_, 3>::new(&[1, 0, 0, 3, 0, 0]);
@@ -106,3 +164,170 @@ fn stride_index_mut() {
     assert_eq!(stride[0], 7);
     assert_eq!(stride[1], 8);
 }
+
+#[test]
+fn stride_try_from_array() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(<[i32; 3]>::try_from(stride).unwrap(), [1, 3, 5]);
+    assert!(<[i32; 2]>::try_from(stride).is_err());
+
+    let data = &mut [1, 2, 3, 4];
+    let stride = Stride::<_, 2>::new_mut(data);
+    assert_eq!(<[i32; 2]>::try_from(stride).unwrap(), [1, 3]);
+}
+
+#[test]
+fn stride_partial_eq_array_by_ref() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert_eq!(stride, [1, 3, 5]);
+    assert_ne!(stride, [1, 3]);
+
+    let data = &mut [1, 0, 3, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    assert_eq!(stride, [1, 3, 5]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_partial_eq_vec() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert_eq!(stride, vec![1, 3, 5]);
+    assert_eq!(*stride, vec![1, 3, 5]);
+    assert_ne!(stride, vec![1, 3, 5, 7]);
+
+    let data = &mut [1, 0, 3, 0, 5];
+    let stride = Stride::<_, 2>::new_mut(data);
+    assert_eq!(stride, vec![1, 3, 5]);
+}
+
+#[test]
+fn stride_partial_ord_slice() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert!(*stride < [1, 4]);
+    assert!(*stride > [1, 3]);
+    assert!(*stride <= [1, 3, 5]);
+    assert_eq!(
+        stride.partial_cmp(&&[0, 9, 9][..]),
+        Some(std::cmp::Ordering::Greater)
+    );
+    assert_eq!(
+        stride.partial_cmp(&&[1, 3, 5, 0]),
+        Some(std::cmp::Ordering::Less)
+    );
+    assert_eq!(
+        stride.partial_cmp(&[1, 3, 5][..]),
+        Some(std::cmp::Ordering::Equal)
+    );
+
+    let stride = Stride::<_, 1>::new(&[1.0, f64::NAN]);
+    assert_eq!(stride.partial_cmp(&[1.0, 2.0]), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_partial_ord_vec() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert!(*stride < vec![2]);
+    assert!(*stride > vec![1, 3]);
+}
+
+#[test]
+fn stride_partial_eq_reverse() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert_eq!([1, 3, 5], *stride);
+    assert_eq!(&[1, 3, 5], *stride);
+    assert_eq!([1, 3, 5][..], *stride);
+    assert_eq!(&[1, 3, 5][..], *stride);
+    assert_eq!(&mut [1, 3, 5][..], *stride);
+    assert_ne!([1, 3], *stride);
+    assert_ne!(&[1, 3, 6][..], *stride);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_partial_eq_reverse_vec() {
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert_eq!(vec![1, 3, 5], *stride);
+    assert_ne!(vec![1, 3], *stride);
+}
+
+#[test]
+fn stride_partial_eq_generic() {
+    fn both_ways<A, B>(a: &A, b: &B) -> bool
+    where
+        A: PartialEq<B> + ?Sized,
+        B: PartialEq<A> + ?Sized,
+    {
+        a.eq(b) && b.eq(a)
+    }
+
+    let stride = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    assert!(both_ways(stride, &[1, 3, 5][..]));
+    assert!(both_ways(stride, &[1, 3, 5]));
+}
+
+#[test]
+fn stride_partial_eq_unit_stride() {
+    let a = Stride::<_, 1>::new(&[1u8, 2, 3]);
+    let b = Stride::<_, 1>::new(&[1u8, 2, 3]);
+    let c = Stride::<_, 1>::new(&[1u8, 2, 4]);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a, &[1, 2, 3][..]);
+    assert_ne!(a, &[1, 2][..]);
+    assert_eq!([1, 2, 3][..], *a);
+    assert_ne!([1, 2, 4][..], *a);
+
+    // Mixed strides still compare element by element.
+    let d = Stride::<_, 2>::new(&[1u8, 0, 2, 0, 3]);
+    assert_eq!(a, d);
+    assert_eq!(d, a);
+}
+
+#[test]
+fn stride_ord() {
+    let a = Stride::<_, 2>::new(&[1, 0, 3, 0, 5]);
+    let b = Stride::<_, 2>::new(&[1, 9, 3, 9, 6]);
+    let c = Stride::<_, 2>::new(&[1, 9, 3]);
+    assert_eq!(a.cmp(b), std::cmp::Ordering::Less);
+    assert_eq!(b.cmp(a), std::cmp::Ordering::Greater);
+    assert_eq!(a.cmp(a), std::cmp::Ordering::Equal);
+    assert_eq!(a.cmp(c), std::cmp::Ordering::Greater);
+
+    let a = Stride::<_, 1>::new(&[1u8, 2, 3]);
+    let b = Stride::<_, 1>::new(&[1u8, 2]);
+    assert_eq!(a.cmp(b), std::cmp::Ordering::Greater);
+    assert_eq!(b.cmp(a), std::cmp::Ordering::Less);
+}
+
+#[test]
+fn stride_unit_as_ref() {
+    fn sum<A: AsRef<[i32]> + ?Sized>(a: &A) -> i32 {
+        a.as_ref().iter().sum()
+    }
+    fn len<A: AsRef<Stride<i32, 1>> + ?Sized>(a: &A) -> usize {
+        a.as_ref().len()
+    }
+
+    let data = &mut [1, 2, 3];
+    let stride = Stride::<_, 1>::new_mut(data);
+    assert_eq!(sum(stride), 6);
+    assert_eq!(len(&[1, 2][..]), 2);
+    AsMut::<[i32]>::as_mut(stride)[0] = 4;
+    AsMut::<Stride<i32, 1>>::as_mut(&mut data[..])[1] = 5;
+    assert_eq!(data, &[4, 5, 3]);
+}
+
+#[test]
+fn stride_unit_from_slice() {
+    let data = &mut [1, 2, 3];
+    let stride: &Stride<_, 1> = (&data[..]).into();
+    assert_eq!(stride, &[1, 2, 3]);
+    let slice: &[i32] = stride.into();
+    assert_eq!(slice, &[1, 2, 3]);
+
+    let stride: &mut Stride<_, 1> = (&mut data[..]).into();
+    let slice: &mut [i32] = stride.into();
+    slice[0] = 7;
+    assert_eq!(data, &[7, 2, 3]);
+}
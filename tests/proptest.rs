@@ -0,0 +1,23 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use stride::proptest::stride_buf;
+use stride::StrideBuf;
+
+proptest! {
+    #[test]
+    fn stride_buf_len(buf in stride_buf::<_, 4>(any::<i32>(), 3..=5)) {
+        prop_assert!((3..=5).contains(&buf.len()));
+        prop_assert_eq!(buf.len(), buf.underlying().len().div_ceil(4));
+    }
+
+    #[test]
+    fn stride_buf_empty(buf in stride_buf::<_, 4>(any::<i32>(), 0)) {
+        prop_assert!(buf.underlying().is_empty());
+    }
+
+    #[test]
+    fn stride_buf_any(buf in any::<StrideBuf<u8, 3>>()) {
+        prop_assert_eq!(buf.to_vec(), buf.iter().copied().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+use stride::Stride;
+
+#[test]
+fn stride_par_iter() {
+    let data: Vec<u64> = (0..10_001).collect();
+    let stride = Stride::<_, 3>::new(&data);
+    assert_eq!(stride.par_iter().len(), stride.len());
+    assert_eq!(stride.par_iter().sum::<u64>(), stride.iter().sum::<u64>());
+    let collected: Vec<_> = stride.par_iter().copied().collect();
+    assert!(collected.iter().eq(stride));
+    let rev: Vec<_> = stride.par_iter().rev().copied().collect();
+    assert!(rev.iter().eq(stride.iter().rev()));
+    assert_eq!(Stride::<u8, 2>::new(&[]).par_iter().count(), 0);
+}
+
+#[test]
+fn stride_par_iter_mut() {
+    let mut data: Vec<u64> = (0..10_000).collect();
+    let stride = Stride::<_, 4>::new_mut(&mut data);
+    stride
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(i, x)| *x = i as u64);
+    assert!(stride.iter().copied().eq(0..2500));
+    for (i, x) in data.iter().enumerate() {
+        if i % 4 != 0 {
+            assert_eq!(*x, i as u64);
+        }
+    }
+}
+
+#[test]
+fn stride_par_iter_zip() {
+    let a = &[1, 0, 2, 0, 3, 0];
+    let b = &mut [0; 9];
+    Stride::<_, 3>::new_mut(b)
+        .par_iter_mut()
+        .zip(Stride::<_, 2>::new(a))
+        .with_min_len(1)
+        .for_each(|(b, a)| *b = *a);
+    assert_eq!(b, &[1, 0, 0, 2, 0, 0, 3, 0, 0]);
+}
+
+/// Returns `len` pseudo-random numbers below `max`.
+fn random(len: usize, max: u32) -> Vec<u32> {
+    let mut x = 0x2545_f491u32;
+    (0..len)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x % max
+        })
+        .collect()
+}
+
+#[test]
+fn stride_par_sort_unstable() {
+    for &(len, max) in &[(0, 1), (100, 10), (50_000, 1000), (50_000, u32::MAX)] {
+        let mut data = random(len, max);
+        let mut expected = data.clone();
+        Stride::<_, 3>::new_mut(&mut expected).sort_unstable();
+        Stride::<_, 3>::new_mut(&mut data).par_sort_unstable();
+        assert_eq!(data, expected);
+
+        Stride::<_, 3>::new_mut(&mut data).par_sort_unstable_by(|a, b| b.cmp(a));
+        assert!(Stride::<_, 3>::new(&data).iter().rev().is_sorted());
+        Stride::<_, 3>::new_mut(&mut data).par_sort_unstable_by_key(|&x| x % 7);
+        assert!(Stride::<_, 3>::new(&data).is_sorted_by_key(|&x| x % 7));
+    }
+}
+
+#[test]
+fn stride_par_sort() {
+    let mut data: Vec<_> = random(20_000, 100).into_iter().zip(0..).collect();
+    let mut expected = data.clone();
+    Stride::<_, 2>::new_mut(&mut expected).sort_by_key(|&(k, _)| k);
+    Stride::<_, 2>::new_mut(&mut data).par_sort_by_key(|&(k, _)| k);
+    assert_eq!(data, expected);
+
+    Stride::<_, 2>::new_mut(&mut data).par_sort_by(|a, b| b.cmp(a));
+    assert!(Stride::<_, 2>::new(&data).iter().rev().is_sorted());
+    Stride::<_, 2>::new_mut(&mut data).par_sort();
+    assert!(Stride::<_, 2>::new(&data).is_sorted());
+}
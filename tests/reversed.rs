@@ -0,0 +1,136 @@
+use stride::{Reversed, Stride, StrideIndex};
+
+#[test]
+fn reversed_index() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let rev = stride.reversed();
+    assert_eq!(rev.len(), 4);
+    assert_eq!(rev[0], 7);
+    assert_eq!(rev[3], 1);
+    assert_eq!(rev.get(4), None);
+    assert_eq!(rev.get(usize::MAX), None);
+    assert_eq!(rev.first(), Some(&7));
+    assert_eq!(rev.last(), Some(&1));
+}
+
+#[test]
+fn reversed_index_range() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let rev = stride.reversed();
+    assert_eq!(rev[..], &[7, 5, 3, 1]);
+    assert_eq!(rev[1..], &[5, 3, 1]);
+    assert_eq!(rev[..1], &[7]);
+    assert_eq!(rev[1..3], &[5, 3]);
+    assert_eq!(rev[1..=3], &[5, 3, 1]);
+    assert_eq!(rev[..=0], &[7]);
+    assert_eq!(rev[4..], <&[i32]>::default());
+    assert_eq!(rev[1..3][1], 3);
+    assert_eq!(rev.get(2..5), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 3..2;
+    assert_eq!(rev.get(empty), None);
+    assert_eq!(rev.get(..=usize::MAX), None);
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of range for stride of length 4 (S = 2)")]
+fn reversed_index_out_of_bounds() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let _ = stride.reversed()[4];
+}
+
+#[test]
+#[should_panic(expected = "range end index 5 out of range for stride of length 4 (S = 2)")]
+fn reversed_index_range_out_of_bounds() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let _ = &stride.reversed()[1..5];
+}
+
+#[test]
+fn reversed_unchecked() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let rev = stride.reversed();
+    unsafe {
+        assert_eq!(*StrideIndex::get_unchecked(1, rev), 4);
+        assert_eq!(*StrideIndex::get_unchecked(0..2, rev), [7, 4]);
+    }
+}
+
+#[test]
+fn reversed_mut() {
+    let data = &mut [1, 2, 3, 4, 5, 6];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let rev = stride.reversed_mut();
+    rev[0] = 50;
+    rev[1..][1] = 10;
+    for x in rev.iter_mut() {
+        *x += 1;
+    }
+    *rev.last_mut().unwrap() += 1;
+    assert_eq!(data, &[12, 2, 4, 4, 51, 6]);
+}
+
+#[test]
+fn reversed_iter() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let rev = stride.reversed();
+    assert!(rev.iter().eq(&[7, 4, 1]));
+    assert!(rev.iter().rev().eq(stride));
+    assert_eq!(rev.into_iter().len(), 3);
+}
+
+#[test]
+fn reversed_roundtrip() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(stride.reversed().reversed(), stride);
+    assert!(<&Reversed<i32, 2>>::default().is_empty());
+}
+
+#[test]
+fn reversed_partial_eq() {
+    let a = Stride::<_, 2>::new(&[1, 0, 2, 0, 3]);
+    let b = Stride::<_, 1>::new(&[3, 2, 1]);
+    assert_eq!(*a.reversed(), *b);
+    assert_eq!(*b, *a.reversed());
+    assert_eq!(a.reversed(), Stride::<_, 1>::new(&[1, 2, 3]).reversed());
+    assert_eq!(*a.reversed(), [3, 2, 1]);
+    assert_eq!(*a.reversed(), &[3, 2, 1][..]);
+    assert_ne!(*a.reversed(), [1, 2, 3]);
+}
+
+/// Records each write separately, unlike streaming hashers which only see the
+/// bytes.
+#[derive(Default)]
+struct WriteHasher(Vec<Vec<u8>>);
+
+impl std::hash::Hasher for WriteHasher {
+    fn finish(&self) -> u64 {
+        unimplemented!()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.push(bytes.to_vec());
+    }
+}
+
+fn writes_of<T: std::hash::Hash + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+    let mut hasher = WriteHasher::default();
+    value.hash(&mut hasher);
+    hasher.0
+}
+
+#[test]
+fn reversed_hash() {
+    let a = Stride::<u32, 1>::new(&[1, 2]);
+    let b = Stride::<u32, 1>::new(&[2, 1]);
+    assert_eq!(*a.reversed(), *b);
+    assert_eq!(writes_of(a.reversed()), writes_of(b));
+    let c = Stride::<u32, 3>::new(&[1, 0, 0, 2]);
+    assert_eq!(writes_of(c.reversed()), writes_of(b));
+}
+
+#[test]
+fn reversed_debug() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(format!("{:?}", stride.reversed()), "[5, 3, 1]");
+}
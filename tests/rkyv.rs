@@ -0,0 +1,37 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use stride::{ArchivedStrideBuf, Stride, StrideBuf};
+
+#[test]
+fn stride_buf_archive() {
+    let buf = StrideBuf::<u32, 3>::from_vec((1..=7).collect());
+    let bytes = rkyv::to_bytes::<Error>(&buf).unwrap();
+    let archived =
+        rkyv::access::<ArchivedStrideBuf<rkyv::Archived<u32>, 3>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 3);
+    assert!(archived.iter().map(|x| x.to_native()).eq([1u32, 4, 7]));
+    assert_eq!(format!("{:?}", archived), "[1, 4, 7]");
+
+    let de: StrideBuf<u32, 3> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(de, buf);
+    assert_eq!(de.underlying(), buf.underlying());
+}
+
+#[test]
+fn stride_buf_archive_empty() {
+    let buf = StrideBuf::<u8, 2>::new();
+    let bytes = rkyv::to_bytes::<Error>(&buf).unwrap();
+    let archived = rkyv::access::<ArchivedStrideBuf<u8, 2>, Error>(&bytes).unwrap();
+    assert_eq!(archived.as_stride(), Stride::<u8, 2>::new(&[]));
+}
+
+#[test]
+fn stride_buf_archive_invalid() {
+    let buf = StrideBuf::<u8, 2>::from_vec(vec![1, 2, 3]);
+    let mut bytes = rkyv::to_bytes::<Error>(&buf).unwrap();
+    let len = bytes.len();
+    // Make the length of the underlying vec point past the end of the bytes.
+    bytes[len - 4..].copy_from_slice(&100u32.to_le_bytes());
+    assert!(rkyv::access::<ArchivedStrideBuf<u8, 2>, Error>(&bytes).is_err());
+}
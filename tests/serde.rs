@@ -0,0 +1,25 @@
+#![cfg(feature = "serde")]
+
+use stride::Stride;
+
+#[test]
+fn stride_serialize() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    assert_eq!(serde_json::to_string(stride).unwrap(), "[1,3,5]");
+    let stride = Stride::<u8, 3>::new(&[]);
+    assert_eq!(serde_json::to_string(stride).unwrap(), "[]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_buf_round_trip() {
+    use stride::StrideBuf;
+
+    let buf = StrideBuf::<_, 3>::from_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+    let json = serde_json::to_string(&buf).unwrap();
+    assert_eq!(json, "[1,4,7]");
+    let de: StrideBuf<i32, 3> = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, buf);
+    assert_eq!(de.underlying(), &[1, 0, 0, 4, 0, 0, 7]);
+    assert!(serde_json::from_str::<StrideBuf<i32, 3>>("{}").is_err());
+}
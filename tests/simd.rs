@@ -0,0 +1,82 @@
+#![cfg(feature = "portable_simd")]
+#![feature(portable_simd)]
+
+use core::simd::Simd;
+
+use stride::Stride;
+
+#[test]
+fn stride_load_simd() {
+    let data: Vec<u32> = (0..20).collect();
+    let stride = Stride::<_, 3>::new(&data);
+    assert_eq!(stride.load_simd::<4>(), Simd::from_array([0, 3, 6, 9]));
+    assert_eq!(stride.load_simd::<7>().to_array(), [0, 3, 6, 9, 12, 15, 18]);
+    assert_eq!(stride[5..].load_simd::<2>(), Simd::from_array([15, 18]));
+}
+
+#[test]
+#[should_panic(expected = "strided slice of length 7 is too short for 8 lanes")]
+fn stride_load_simd_too_short() {
+    let data: Vec<u32> = (0..20).collect();
+    Stride::<_, 3>::new(&data).load_simd::<8>();
+}
+
+#[test]
+fn stride_store_simd() {
+    let data = &mut [0.0f64; 9];
+    let stride = Stride::<_, 4>::new_mut(data);
+    stride.store_simd(Simd::from_array([1.0, 2.0]));
+    assert_eq!(data, &[1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic(expected = "strided slice of length 2 is too short for 3 lanes")]
+fn stride_store_simd_too_short() {
+    let data = &mut [0u8; 4];
+    Stride::<_, 2>::new_mut(data).store_simd(Simd::from_array([1, 2, 3]));
+}
+
+macro_rules! check_copy {
+    ($ty:ty, $s:literal) => {
+        let data: Vec<$ty> = (0..300).map(|x| x as $ty).collect();
+        for len in 0..=data.len() {
+            let stride = Stride::<_, $s>::new(&data[..len]);
+            let mut dst = vec![0 as $ty; stride.len()];
+            stride.copy_to_slice(&mut dst);
+            assert!(dst.iter().eq(stride), "len {}", len);
+
+            let mut data = data[..len].to_vec();
+            let mut expected = data.clone();
+            let stride = Stride::<_, $s>::new_mut(&mut data);
+            let src: Vec<$ty> = (0..stride.len()).map(|x| (x + 1) as $ty).collect();
+            stride.copy_from_slice(&src);
+            for (i, &elem) in src.iter().enumerate() {
+                expected[i * $s] = elem;
+            }
+            assert_eq!(data, expected, "len {}", len);
+        }
+    };
+}
+
+#[test]
+fn stride_copy_simd() {
+    check_copy!(u8, 1);
+    check_copy!(u8, 2);
+    check_copy!(u8, 3);
+    check_copy!(u8, 4);
+    check_copy!(u8, 5);
+    check_copy!(i16, 3);
+    check_copy!(u32, 2);
+    check_copy!(f32, 4);
+    check_copy!(i64, 3);
+    check_copy!(f64, 7);
+}
+
+#[test]
+fn stride_copy_non_primitive() {
+    let data: Vec<(u8, u8)> = (0..50).map(|x| (x, x)).collect();
+    let stride = Stride::<_, 3>::new(&data);
+    let mut dst = vec![(0, 0); stride.len()];
+    stride.copy_to_slice(&mut dst);
+    assert!(dst.iter().eq(stride));
+}
@@ -0,0 +1,232 @@
+use stride::Stride;
+
+/// A simple deterministic pseudo-random number generator.
+fn random(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *seed >> 33
+}
+
+fn random_vec(len: usize, modulo: u64, seed: &mut u64) -> Vec<u64> {
+    (0..len).map(|_| random(seed) % modulo).collect()
+}
+
+#[test]
+fn stride_sort_unstable() {
+    let mut seed = 42;
+    for &len in &[0, 1, 2, 3, 10, 21, 50, 100, 1000] {
+        for &modulo in &[1, 2, 10, 1000, u64::MAX] {
+            let mut data = random_vec(len, modulo, &mut seed);
+            let original = data.clone();
+
+            let stride = Stride::<_, 3>::new_mut(&mut data);
+            stride.sort_unstable();
+
+            let mut expected: Vec<_> = original.iter().step_by(3).copied().collect();
+            expected.sort_unstable();
+            assert_eq!(stride, expected.as_slice());
+            for i in (0..len).filter(|i| i % 3 != 0) {
+                assert_eq!(data[i], original[i]);
+            }
+        }
+    }
+}
+
+#[test]
+fn stride_sort_unstable_sorted_and_reversed() {
+    let mut data: Vec<_> = (0..500).collect();
+    Stride::<_, 2>::new_mut(&mut data).sort_unstable();
+    assert_eq!(data, (0..500).collect::<Vec<_>>());
+
+    let mut data: Vec<_> = (0..500).rev().collect();
+    let stride = Stride::<_, 2>::new_mut(&mut data);
+    stride.sort_unstable();
+    let expected: Vec<_> = (0..500).rev().step_by(2).rev().collect();
+    assert_eq!(stride, expected.as_slice());
+}
+
+#[test]
+fn stride_sort_unstable_by() {
+    let mut seed = 7;
+    let mut data = random_vec(300, 100, &mut seed);
+    let stride = Stride::<_, 2>::new_mut(&mut data);
+    stride.sort_unstable_by(|a, b| b.cmp(a));
+    assert!(stride
+        .iter()
+        .zip(stride.iter().skip(1))
+        .all(|(a, b)| a >= b));
+}
+
+#[test]
+fn stride_sort_unstable_by_key() {
+    let mut seed = 13;
+    let mut data: Vec<_> = random_vec(300, 100, &mut seed)
+        .into_iter()
+        .map(|x| x as i64 - 50)
+        .collect();
+    let stride = Stride::<_, 2>::new_mut(&mut data);
+    stride.sort_unstable_by_key(|x| x.abs());
+    assert!(stride
+        .iter()
+        .zip(stride.iter().skip(1))
+        .all(|(a, b)| a.abs() <= b.abs()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_sort() {
+    let mut seed = 42;
+    for &len in &[0, 1, 2, 3, 10, 21, 50, 100, 1000] {
+        for &modulo in &[1, 2, 10, 1000] {
+            let mut data: Vec<_> = random_vec(len, modulo, &mut seed)
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| (x, i))
+                .collect();
+            let original = data.clone();
+
+            let stride = Stride::<_, 3>::new_mut(&mut data);
+            stride.sort_by_key(|&(x, _)| x);
+
+            let mut expected: Vec<_> = original.iter().step_by(3).copied().collect();
+            expected.sort_by_key(|&(x, _)| x);
+            assert_eq!(stride, expected.as_slice());
+            for i in (0..len).filter(|i| i % 3 != 0) {
+                assert_eq!(data[i], original[i]);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_sort_by() {
+    let data = &mut [
+        (1, 'a'),
+        (0, 'x'),
+        (0, 'b'),
+        (0, 'y'),
+        (1, 'c'),
+        (0, 'z'),
+        (0, 'd'),
+    ];
+    let stride = Stride::<_, 2>::new_mut(data);
+    stride.sort_by(|a, b| b.0.cmp(&a.0));
+    assert_eq!(stride, &[(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_sort_non_copy() {
+    let mut data: Vec<_> = ["d", "-", "c", "-", "a", "-", "b"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    Stride::<_, 2>::new_mut(&mut data).sort();
+    assert_eq!(data, ["a", "-", "b", "-", "c", "-", "d"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stride_sort_by_cached_key() {
+    let data = &mut [
+        (1, 'a'),
+        (0, 'x'),
+        (0, 'b'),
+        (0, 'y'),
+        (1, 'c'),
+        (0, 'z'),
+        (0, 'd'),
+    ];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let mut calls = 0;
+    stride.sort_by_cached_key(|&(k, _)| {
+        calls += 1;
+        k
+    });
+    assert_eq!(calls, 4);
+    assert_eq!(stride, &[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    assert_eq!(data[1], (0, 'x'));
+}
+
+#[test]
+fn stride_select_nth_unstable() {
+    let mut seed = 3;
+    for &len in &[1, 2, 3, 10, 21, 50, 100, 1000] {
+        for &modulo in &[1, 2, 10, 1000, u64::MAX] {
+            let original = random_vec(len, modulo, &mut seed);
+            let mut expected: Vec<_> = original.iter().step_by(2).copied().collect();
+            expected.sort_unstable();
+            for index in (0..expected.len()).step_by(len / 10 + 1) {
+                let mut data = original.clone();
+                let stride = Stride::<_, 2>::new_mut(&mut data);
+                let (left, nth, right) = stride.select_nth_unstable(index);
+                assert_eq!(*nth, expected[index]);
+                assert_eq!(left.len(), index);
+                assert!(left.iter().all(|x| x <= nth));
+                assert!(right.iter().all(|x| x >= nth));
+                for i in (1..len).step_by(2) {
+                    assert_eq!(data[i], original[i]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "partition index 3 greater than length of stride 3")]
+fn stride_select_nth_unstable_out_of_bounds() {
+    let data = &mut [5, 0, 4, 0, 1];
+    Stride::<_, 2>::new_mut(data).select_nth_unstable(3);
+}
+
+#[test]
+fn stride_select_nth_unstable_by() {
+    let data = &mut [5, 0, 4, 0, 1, 0, 3, 0, 2];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let (left, nth, right) = stride.select_nth_unstable_by(3, |a, b| b.cmp(a));
+    assert_eq!(*nth, 2);
+    assert!(left.iter().all(|&x| x > 2));
+    assert_eq!(right, &[1]);
+}
+
+#[test]
+fn stride_select_nth_unstable_by_key() {
+    let data = &mut [-5i32, 0, 4, 0, 1, 0, -3, 0, 2];
+    let stride = Stride::<_, 2>::new_mut(data);
+    let (_, nth, _) = stride.select_nth_unstable_by_key(4, |x| x.abs());
+    assert_eq!(*nth, -5);
+}
+
+#[test]
+fn stride_is_sorted() {
+    assert!(<&Stride<i32, 2>>::default().is_sorted());
+    assert!(Stride::<_, 2>::new(&[1]).is_sorted());
+    assert!(Stride::<_, 2>::new(&[1, 0, 1, 0, 2]).is_sorted());
+    assert!(!Stride::<_, 2>::new(&[1, 0, 1, 0, 0]).is_sorted());
+    assert!(!Stride::<_, 1>::new(&[0.0, f64::NAN]).is_sorted());
+}
+
+#[test]
+fn stride_is_sorted_by() {
+    let stride = Stride::<_, 2>::new(&[3, 0, 2, 0, 2]);
+    assert!(stride.is_sorted_by(|a, b| a >= b));
+    assert!(!stride.is_sorted_by(|a, b| a > b));
+}
+
+#[test]
+fn stride_is_sorted_by_key() {
+    let stride = Stride::<_, 2>::new(&["a", "", "bb", "", "cc"]);
+    assert!(stride.is_sorted_by_key(|s| s.len()));
+    assert!(!stride.is_sorted_by_key(|s| std::cmp::Reverse(s.len())));
+    assert!(<&Stride<&str, 2>>::default().is_sorted_by_key(|s| s.len()));
+}
+
+#[test]
+fn stride_is_sorted_by_key_nan() {
+    let stride = Stride::<_, 1>::new(&[0.0, f64::NAN]);
+    assert!(!stride.is_sorted_by_key(|&x| x));
+    assert!(!Stride::<_, 2>::new(&[f64::NAN, 0.0, 1.0]).is_sorted_by_key(|&x| x));
+    assert!(Stride::<_, 1>::new(&[f64::NAN]).is_sorted_by_key(|&x| x));
+}
@@ -0,0 +1,46 @@
+use core::mem::MaybeUninit;
+
+use stride::Stride;
+
+#[test]
+fn stride_write_filled() {
+    let mut buf = [MaybeUninit::uninit(); 7];
+    for offset in 0..3 {
+        Stride::<_, 3>::new_with_offset_mut(&mut buf, offset).write_filled(offset);
+    }
+    let stride = unsafe { Stride::<_, 3>::new_mut(&mut buf).assume_init() };
+    assert_eq!(stride.underlying(), &[0, 1, 2, 0, 1, 2, 0]);
+    stride.reverse();
+    assert_eq!(stride.underlying(), &[0, 1, 2, 0, 1, 2, 0]);
+}
+
+#[test]
+fn stride_write_filled_strings() {
+    let mut buf: [MaybeUninit<String>; 4] = [const { MaybeUninit::uninit() }; 4];
+    Stride::<_, 2>::new_mut(&mut buf).write_filled(String::from("a"));
+    Stride::<_, 2>::new_with_offset_mut(&mut buf, 1).write_filled(String::from("b"));
+    let stride = unsafe { Stride::<_, 1>::new_mut(&mut buf).assume_init() };
+    assert_eq!(stride, &["a", "b", "a", "b"]);
+    for elem in &mut buf {
+        unsafe { elem.assume_init_drop() };
+    }
+}
+
+#[test]
+fn stride_write_iter() {
+    let mut buf = [MaybeUninit::uninit(); 5];
+    let stride = Stride::<_, 2>::new_mut(&mut buf);
+    assert_eq!(stride.write_iter(Vec::<u8>::new()), 0);
+    assert_eq!(stride.write_iter([1, 2]), 2);
+    assert_eq!(stride.write_iter(10..), 3);
+    let mut iter = 20..;
+    assert_eq!(
+        Stride::<_, 2>::new_with_offset_mut(&mut buf, 1).write_iter(&mut iter),
+        2
+    );
+    assert_eq!(iter.next(), Some(22));
+
+    let stride = unsafe { Stride::<_, 2>::new(&buf).assume_init_ref() };
+    assert_eq!(stride, &[10, 11, 12]);
+    assert_eq!(stride.underlying(), &[10, 20, 11, 21, 12]);
+}
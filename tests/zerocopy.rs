@@ -0,0 +1,35 @@
+#![cfg(feature = "zerocopy")]
+
+use stride::Stride;
+use zerocopy::byteorder::{BigEndian, U32};
+
+#[test]
+fn stride_from_bytes() {
+    let bytes = &[0, 0, 0, 1, 9, 9, 9, 9, 0, 0, 0, 2, 9, 9, 9, 9, 0, 0, 0, 3];
+    let stride = Stride::<U32<BigEndian>, 2>::from_bytes(bytes).unwrap();
+    assert_eq!(stride.len(), 3);
+    assert_eq!(stride, &[1, 2, 3]);
+
+    let stride = Stride::<U32<BigEndian>, 2>::from_bytes(&[]).unwrap();
+    assert!(stride.is_empty());
+
+    assert!(Stride::<U32<BigEndian>, 2>::from_bytes(&bytes[1..]).is_err());
+}
+
+#[test]
+fn stride_from_bytes_unaligned() {
+    let bytes = &[0u8; 9];
+    // Start one byte after an aligned address.
+    let offset = 1 - bytes.as_ptr() as usize % 2;
+    let result = Stride::<u16, 2>::from_bytes(&bytes[offset..offset + 8]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn stride_from_bytes_mut() {
+    let bytes = &mut [0; 12];
+    let stride = Stride::<U32<BigEndian>, 3>::from_bytes_mut(&mut bytes[4..]).unwrap();
+    assert_eq!(stride.len(), 1);
+    stride[0] = U32::new(0x0a0b_0c0d);
+    assert_eq!(bytes, &[0, 0, 0, 0, 10, 11, 12, 13, 0, 0, 0, 0]);
+}